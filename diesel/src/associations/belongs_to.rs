@@ -1,9 +1,11 @@
 use super::{HasTable, Identifiable};
-use crate::dsl::{Eq, EqAny, Filter, FindBy};
+use crate::dsl::{Eq, EqAny, Filter, FindBy, InnerJoin, Select};
 use crate::expression::array_comparison::AsInExpression;
 use crate::expression::AsExpression;
 use crate::prelude::*;
-use crate::query_dsl::methods::FilterDsl;
+use crate::query_dsl::methods::{FilterDsl, SelectDsl};
+use crate::query_dsl::{BelongingToMany, JoinWithImplicitOnClause};
+use crate::query_source::joins::Inner;
 use crate::sql_types::SqlType;
 
 use std::borrow::Borrow;
@@ -133,6 +135,145 @@ where
     }
 }
 
+/// Groups records loaded through a junction table by their parent.
+///
+/// This is the many-to-many counterpart of [`grouped_by`](GroupedBy). Since
+/// a child may belong to several parents, the association is stored in the
+/// junction rows rather than in the children themselves. The junction model
+/// needs to belong to both the parent and the child via
+/// [`BelongsTo`], and children are cloned into the group of every parent
+/// they are associated with. Junction rows referencing a missing parent or
+/// child are skipped.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::associations::grouped_by_many;
+/// # use diesel::query_dsl::BelongingToMany;
+/// # use schema::users;
+/// #
+/// # #[derive(Identifiable, Queryable, PartialEq, Debug)]
+/// # pub struct User {
+/// #     id: i32,
+/// #     name: String,
+/// # }
+/// #
+/// # table! {
+/// #     groups {
+/// #         id -> Integer,
+/// #         name -> Text,
+/// #     }
+/// # }
+/// #
+/// # table! {
+/// #     memberships {
+/// #         id -> Integer,
+/// #         user_id -> Integer,
+/// #         group_id -> Integer,
+/// #     }
+/// # }
+/// #
+/// # joinable!(memberships -> groups (group_id));
+/// # allow_tables_to_appear_in_same_query!(groups, memberships);
+/// #
+/// # #[derive(Identifiable, Queryable, PartialEq, Debug, Clone)]
+/// # pub struct Group {
+/// #     id: i32,
+/// #     name: String,
+/// # }
+/// #
+/// # #[derive(Identifiable, Queryable, Associations)]
+/// # #[belongs_to(User)]
+/// # #[belongs_to(Group)]
+/// # pub struct Membership {
+/// #     id: i32,
+/// #     user_id: i32,
+/// #     group_id: i32,
+/// # }
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// #     diesel::sql_query(
+/// #         "CREATE TABLE groups (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"
+/// #     ).execute(connection)?;
+/// #     diesel::sql_query(
+/// #         "CREATE TABLE memberships (id INTEGER PRIMARY KEY, \
+/// #          user_id INTEGER NOT NULL, group_id INTEGER NOT NULL)"
+/// #     ).execute(connection)?;
+/// #     diesel::sql_query(
+/// #         "INSERT INTO groups (id, name) VALUES (1, 'Admins'), (2, 'Devs')"
+/// #     ).execute(connection)?;
+/// #     diesel::sql_query(
+/// #         "INSERT INTO memberships (id, user_id, group_id) \
+/// #          VALUES (1, 1, 1), (2, 1, 2), (3, 2, 2)"
+/// #     ).execute(connection)?;
+/// let users = users::table.load::<User>(connection)?;
+/// let memberships = Membership::belonging_to(&users)
+///     .load::<Membership>(connection)?;
+/// let groups = groups::table.load::<Group>(connection)?;
+///
+/// let data = grouped_by_many(users, &memberships, &groups);
+///
+/// let expected_data = vec![
+///     (
+///         User { id: 1, name: "Sean".into() },
+///         vec![
+///             Group { id: 1, name: "Admins".into() },
+///             Group { id: 2, name: "Devs".into() },
+///         ],
+///     ),
+///     (
+///         User { id: 2, name: "Tess".into() },
+///         vec![
+///             Group { id: 2, name: "Devs".into() },
+///         ],
+///     ),
+/// ];
+///
+/// assert_eq!(expected_data, data);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn grouped_by_many<'a, Parent, Through, Child>(
+    parents: Vec<Parent>,
+    junction_rows: &'a [Through],
+    children: &'a [Child],
+) -> Vec<(Parent, Vec<Child>)>
+where
+    Through: BelongsTo<Parent> + BelongsTo<Child>,
+    Child: Clone,
+    &'a Child: Identifiable<Id = &'a <Through as BelongsTo<Child>>::ForeignKey>,
+    for<'b> &'b Parent: Identifiable<Id = &'b <Through as BelongsTo<Parent>>::ForeignKey>,
+{
+    use std::collections::HashMap;
+
+    let mut result = parents.iter().map(|_| Vec::new()).collect::<Vec<_>>();
+    {
+        let parent_indices: HashMap<_, _> = parents
+            .iter()
+            .enumerate()
+            .map(|(i, parent)| (parent.id(), i))
+            .collect();
+        let children_by_id: HashMap<_, _> =
+            children.iter().map(|child| (child.id(), child)).collect();
+        for junction in junction_rows {
+            let parent_index =
+                BelongsTo::<Parent>::foreign_key(junction).and_then(|fk| parent_indices.get(fk));
+            let child =
+                BelongsTo::<Child>::foreign_key(junction).and_then(|fk| children_by_id.get(fk));
+            if let (Some(&parent_index), Some(child)) = (parent_index, child) {
+                result[parent_index].push((*child).clone());
+            }
+        }
+    }
+    parents.into_iter().zip(result).collect()
+}
+
 impl<'a, Parent, Child> BelongingToDsl<&'a Parent> for Child
 where
     &'a Parent: Identifiable,
@@ -176,3 +317,80 @@ where
         Self::belonging_to(&**parents)
     }
 }
+
+impl<'a, Parent, Child, Through> BelongingToMany<&'a Parent, Through> for Child
+where
+    &'a Parent: Identifiable,
+    Child: HasTable,
+    Through: HasTable + BelongsTo<Parent>,
+    Id<&'a Parent>: AsExpression<<Through::ForeignKeyColumn as Expression>::SqlType>,
+    Child::Table: JoinWithImplicitOnClause<Through::Table, Inner>,
+    InnerJoin<Child::Table, Through::Table>:
+        FilterDsl<Eq<Through::ForeignKeyColumn, Id<&'a Parent>>>,
+    FindBy<InnerJoin<Child::Table, Through::Table>, Through::ForeignKeyColumn, Id<&'a Parent>>:
+        SelectDsl<<Child::Table as Table>::AllColumns>,
+    Through::ForeignKeyColumn: ExpressionMethods,
+    <Through::ForeignKeyColumn as Expression>::SqlType: SqlType,
+{
+    type Output = Select<
+        FindBy<InnerJoin<Child::Table, Through::Table>, Through::ForeignKeyColumn, Id<&'a Parent>>,
+        <Child::Table as Table>::AllColumns,
+    >;
+
+    fn belonging_to_many(parent: &'a Parent) -> Self::Output {
+        SelectDsl::select(
+            FilterDsl::filter(
+                Child::table().join_with_implicit_on_clause(Through::table(), Inner),
+                Through::foreign_key_column().eq(parent.id()),
+            ),
+            Child::Table::all_columns(),
+        )
+    }
+}
+
+impl<'a, Parent, Child, Through> BelongingToMany<&'a [Parent], Through> for Child
+where
+    &'a Parent: Identifiable,
+    Child: HasTable,
+    Through: HasTable + BelongsTo<Parent>,
+    Vec<Id<&'a Parent>>: AsInExpression<<Through::ForeignKeyColumn as Expression>::SqlType>,
+    Child::Table: JoinWithImplicitOnClause<Through::Table, Inner>,
+    InnerJoin<Child::Table, Through::Table>:
+        FilterDsl<EqAny<Through::ForeignKeyColumn, Vec<Id<&'a Parent>>>>,
+    Filter<
+        InnerJoin<Child::Table, Through::Table>,
+        EqAny<Through::ForeignKeyColumn, Vec<Id<&'a Parent>>>,
+    >: SelectDsl<<Child::Table as Table>::AllColumns>,
+    Through::ForeignKeyColumn: ExpressionMethods,
+    <Through::ForeignKeyColumn as Expression>::SqlType: SqlType,
+{
+    type Output = Select<
+        Filter<
+            InnerJoin<Child::Table, Through::Table>,
+            EqAny<Through::ForeignKeyColumn, Vec<Id<&'a Parent>>>,
+        >,
+        <Child::Table as Table>::AllColumns,
+    >;
+
+    fn belonging_to_many(parents: &'a [Parent]) -> Self::Output {
+        let ids = parents.iter().map(Identifiable::id).collect::<Vec<_>>();
+        SelectDsl::select(
+            FilterDsl::filter(
+                Child::table().join_with_implicit_on_clause(Through::table(), Inner),
+                Through::foreign_key_column().eq_any(ids),
+            ),
+            Child::Table::all_columns(),
+        )
+    }
+}
+
+impl<'a, Parent, Child, Through> BelongingToMany<&'a Vec<Parent>, Through> for Child
+where
+    Child: BelongingToMany<&'a [Parent], Through>,
+{
+    type Output = Child::Output;
+
+    fn belonging_to_many(parents: &'a Vec<Parent>) -> Self::Output {
+        Self::belonging_to_many(&**parents)
+    }
+}
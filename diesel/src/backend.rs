@@ -89,3 +89,10 @@ pub trait SupportsOnConflictTargetDecorations {}
 pub trait SupportsDefaultKeyword {}
 /// Does this backend use the standard `SAVEPOINT` syntax?
 pub trait UsesAnsiSavepointSyntax {}
+/// Does this backend treat `||` as string concatenation?
+///
+/// Backends implementing this trait get the `QueryFragment` impl for
+/// [`Concat`](crate::expression::operators::Concat) for free. MySQL
+/// notably does not, as it only treats `||` as concatenation when the
+/// `PIPES_AS_CONCAT` SQL mode is enabled.
+pub trait UsesAnsiConcatSyntax {}
@@ -0,0 +1,208 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use super::{Connection, SimpleConnection, TransactionManager};
+use crate::backend::Backend;
+use crate::deserialize::FromSqlRow;
+use crate::expression::QueryMetadata;
+use crate::query_builder::{debug_query, AsQuery, QueryFragment, QueryId};
+use crate::query_dsl::load_dsl::CompatibleType;
+use crate::result::{ConnectionError, ConnectionResult, QueryResult};
+
+/// Callbacks invoked by [`ConnectionMiddleware`] around every query
+///
+/// All methods default to doing nothing, so implementations only need to
+/// provide the hooks they are interested in.
+pub trait SqlLogger: Send {
+    /// Called with the SQL of a query before it is executed
+    fn before_sql(&mut self, _sql: &str) {}
+
+    /// Called with the SQL of a query after it was executed, along with the
+    /// time execution took
+    fn after_sql(&mut self, _sql: &str, _duration: Duration) {}
+}
+
+/// Wraps a connection, intercepting all SQL going through it
+///
+/// The wrapper implements [`Connection`] by delegating to the inner
+/// connection, calling the [`SqlLogger`] hooks around every query. This can
+/// be used to log SQL, collect timing information or inject assertions in
+/// tests without modifying the code building the queries.
+///
+/// Note that the SQL passed to the hooks is rendered with
+/// [`debug_query`](crate::debug_query()) and may therefore differ slightly
+/// from the SQL sent to the database.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use std::time::Duration;
+/// # use diesel::connection::{ConnectionMiddleware, SqlLogger};
+/// #
+/// #[derive(Default)]
+/// struct QueryLog {
+///     queries: Vec<String>,
+/// }
+///
+/// impl SqlLogger for QueryLog {
+///     fn after_sql(&mut self, sql: &str, _duration: Duration) {
+///         self.queries.push(sql.to_owned());
+///     }
+/// }
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::users::dsl::*;
+/// let connection = &mut ConnectionMiddleware::new(
+///     establish_connection(),
+///     QueryLog::default(),
+/// );
+///
+/// let data = users.select(name).load::<String>(connection)?;
+/// assert_eq!(vec!["Sean", "Tess"], data);
+///
+/// assert_eq!(1, connection.logger().queries.len());
+/// assert!(connection.logger().queries[0].contains("SELECT"));
+/// #     Ok(())
+/// # }
+/// ```
+pub struct ConnectionMiddleware<Inner, L> {
+    inner: Inner,
+    logger: L,
+}
+
+impl<Inner, L> fmt::Debug for ConnectionMiddleware<Inner, L>
+where
+    L: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionMiddleware")
+            .field("logger", &self.logger)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<Inner, L> ConnectionMiddleware<Inner, L> {
+    /// Wraps the given connection, reporting all SQL to `logger`
+    pub fn new(inner: Inner, logger: L) -> Self {
+        ConnectionMiddleware { inner, logger }
+    }
+
+    /// Returns a reference to the logger
+    pub fn logger(&self) -> &L {
+        &self.logger
+    }
+
+    /// Returns a mutable reference to the logger
+    pub fn logger_mut(&mut self) -> &mut L {
+        &mut self.logger
+    }
+
+    /// Unwraps the middleware, returning the inner connection
+    pub fn into_inner(self) -> Inner {
+        self.inner
+    }
+
+    fn time<T>(&mut self, sql: &str, f: impl FnOnce(&mut Inner) -> T) -> T
+    where
+        L: SqlLogger,
+    {
+        self.logger.before_sql(sql);
+        let start = Instant::now();
+        let result = f(&mut self.inner);
+        self.logger.after_sql(sql, start.elapsed());
+        result
+    }
+}
+
+impl<Inner, L> SimpleConnection for ConnectionMiddleware<Inner, L>
+where
+    Inner: SimpleConnection,
+    L: SqlLogger,
+{
+    fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
+        self.time(query, |inner| inner.batch_execute(query))
+    }
+}
+
+impl<Inner, L> Connection for ConnectionMiddleware<Inner, L>
+where
+    Inner: Connection,
+    <Inner::Backend as Backend>::QueryBuilder: Default,
+    L: SqlLogger + 'static,
+{
+    type Backend = Inner::Backend;
+    type TransactionManager = MiddlewareTransactionManager<Inner::TransactionManager>;
+
+    fn establish(_: &str) -> ConnectionResult<Self> {
+        Err(ConnectionError::BadConnection(String::from(
+            "Cannot directly establish a middleware connection, \
+             use `ConnectionMiddleware::new` instead",
+        )))
+    }
+
+    fn execute(&mut self, query: &str) -> QueryResult<usize> {
+        self.time(query, |inner| inner.execute(query))
+    }
+
+    fn load<T, U, ST>(&mut self, source: T) -> QueryResult<Vec<U>>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+        T::SqlType: CompatibleType<U, Self::Backend, SqlType = ST>,
+        U: FromSqlRow<ST, Self::Backend>,
+        Self::Backend: QueryMetadata<T::SqlType>,
+    {
+        let query = source.as_query();
+        let sql = debug_query::<Self::Backend, _>(&query).to_string();
+        self.time(&sql, |inner| inner.load(query))
+    }
+
+    fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        let sql = debug_query::<Self::Backend, _>(source).to_string();
+        self.time(&sql, |inner| inner.execute_returning_count(source))
+    }
+
+    fn transaction_state(
+        &mut self,
+    ) -> &mut <Self::TransactionManager as TransactionManager<Self>>::TransactionStateData {
+        self.inner.transaction_state()
+    }
+}
+
+#[doc(hidden)]
+#[allow(missing_debug_implementations)]
+pub struct MiddlewareTransactionManager<T>(std::marker::PhantomData<T>);
+
+impl<Inner, L, T> TransactionManager<ConnectionMiddleware<Inner, L>>
+    for MiddlewareTransactionManager<T>
+where
+    Inner: Connection<TransactionManager = T>,
+    ConnectionMiddleware<Inner, L>: Connection,
+    T: TransactionManager<Inner>,
+{
+    type TransactionStateData = T::TransactionStateData;
+
+    fn begin_transaction(conn: &mut ConnectionMiddleware<Inner, L>) -> QueryResult<()> {
+        T::begin_transaction(&mut conn.inner)
+    }
+
+    fn rollback_transaction(conn: &mut ConnectionMiddleware<Inner, L>) -> QueryResult<()> {
+        T::rollback_transaction(&mut conn.inner)
+    }
+
+    fn commit_transaction(conn: &mut ConnectionMiddleware<Inner, L>) -> QueryResult<()> {
+        T::commit_transaction(&mut conn.inner)
+    }
+
+    fn get_transaction_depth(conn: &mut ConnectionMiddleware<Inner, L>) -> u32 {
+        T::get_transaction_depth(&mut conn.inner)
+    }
+}
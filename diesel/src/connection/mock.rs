@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+
+use super::{Connection, SimpleConnection};
+use crate::backend::Backend;
+use crate::connection::AnsiTransactionManager;
+use crate::deserialize::FromSqlRow;
+use crate::expression::QueryMetadata;
+use crate::query_builder::debug_query::DebugBinds;
+use crate::query_builder::{AsQuery, QueryBuilder, QueryFragment, QueryId};
+use crate::query_dsl::load_dsl::CompatibleType;
+use crate::result::{ConnectionResult, QueryResult};
+use crate::testing::normalize;
+
+#[derive(Debug)]
+struct Expectation {
+    sql: String,
+    binds: String,
+    affected_rows: usize,
+}
+
+/// A connection which checks executed statements against registered
+/// expectations instead of talking to a database
+///
+/// Expectations are registered via
+/// [`expect_query`](MockConnection::expect_query()) and consumed in
+/// order; a statement that does not match the next expectation panics
+/// with a message showing both. Dropping a connection with unconsumed
+/// expectations panics as well, like function level mocking libraries
+/// do.
+///
+/// SQL is compared with whitespace and keyword casing normalized, bind
+/// parameters by their `Debug` representation. Statements issued by
+/// Diesel itself, such as `BEGIN` for a transaction, need expectations
+/// too.
+///
+/// Since rows of a real backend cannot be fabricated, only statements
+/// executed via [`execute`](crate::query_dsl::RunQueryDsl::execute())
+/// are supported; calling `load` or `get_result` panics. Tests that load
+/// data should run against a real database, e.g. an in memory SQLite
+/// one.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::connection::MockConnection;
+/// # use schema::users;
+/// #
+/// # #[cfg(feature = "sqlite")]
+/// # fn main() {
+/// let conn = &mut MockConnection::<diesel::sqlite::Sqlite>::new();
+/// conn.expect_query(
+///     "UPDATE `users` SET `name` = ? WHERE (`users`.`id` = ?)",
+///     &["\"Jim\"", "1"],
+///     1,
+/// );
+/// let renamed = diesel::update(users::table.find(1))
+///     .set(users::name.eq("Jim"))
+///     .execute(conn);
+/// assert_eq!(Ok(1), renamed);
+/// # }
+/// # #[cfg(not(feature = "sqlite"))]
+/// # fn main() {}
+/// ```
+pub struct MockConnection<DB> {
+    expectations: VecDeque<Expectation>,
+    transaction_manager: AnsiTransactionManager,
+    _marker: PhantomData<DB>,
+}
+
+impl<DB> fmt::Debug for MockConnection<DB> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockConnection")
+            .field("expectations", &self.expectations)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<DB> MockConnection<DB> {
+    /// Creates a connection without any expectations
+    pub fn new() -> Self {
+        MockConnection {
+            expectations: VecDeque::new(),
+            transaction_manager: AnsiTransactionManager::default(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Registers the next expected statement
+    ///
+    /// The binds are given as the `Debug` representations of the expected
+    /// values, in order, e.g. `&["\"Sean\"", "1"]` for a string and an
+    /// integer. `affected_rows` is returned as the result of executing
+    /// the statement.
+    pub fn expect_query(&mut self, sql: &str, binds: &[&str], affected_rows: usize) {
+        self.expectations.push_back(Expectation {
+            sql: normalize(sql),
+            binds: format!("[{}]", binds.join(", ")),
+            affected_rows,
+        });
+    }
+
+    /// Panics if not all registered expectations have been consumed
+    ///
+    /// This also happens on drop, but calling it explicitly gives a
+    /// better panic location.
+    pub fn verify(&mut self) {
+        let remaining = std::mem::take(&mut self.expectations);
+        if !remaining.is_empty() {
+            panic!("unconsumed query expectations: {:?}", remaining);
+        }
+    }
+
+    fn check(&mut self, sql: &str, binds: &str) -> QueryResult<usize> {
+        let sql = normalize(sql);
+        let expectation = self
+            .expectations
+            .pop_front()
+            .unwrap_or_else(|| panic!("unexpected query, no expectations left:\n  query: {}", sql));
+        if expectation.sql != sql || expectation.binds != binds {
+            panic!(
+                "query does not match the next expectation:\n  expected: {} -- binds: {}\n       got: {} -- binds: {}",
+                expectation.sql, expectation.binds, sql, binds,
+            );
+        }
+        Ok(expectation.affected_rows)
+    }
+}
+
+impl<DB> Default for MockConnection<DB> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<DB> Drop for MockConnection<DB> {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.verify();
+        }
+    }
+}
+
+impl<DB> SimpleConnection for MockConnection<DB>
+where
+    DB: Backend,
+    MockConnection<DB>: Connection,
+{
+    fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
+        self.check(query, "[]").map(|_| ())
+    }
+}
+
+impl<DB> Connection for MockConnection<DB>
+where
+    DB: Backend + crate::backend::UsesAnsiSavepointSyntax + 'static,
+    DB::QueryBuilder: Default,
+    Self: Send,
+{
+    type Backend = DB;
+    type TransactionManager = AnsiTransactionManager;
+
+    fn establish(_database_url: &str) -> ConnectionResult<Self> {
+        Ok(Self::new())
+    }
+
+    fn execute(&mut self, query: &str) -> QueryResult<usize> {
+        self.check(query, "[]")
+    }
+
+    fn load<T, U, ST>(&mut self, _source: T) -> QueryResult<Vec<U>>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+        T::SqlType: CompatibleType<U, Self::Backend, SqlType = ST>,
+        U: FromSqlRow<ST, Self::Backend>,
+        Self::Backend: QueryMetadata<T::SqlType>,
+    {
+        panic!(
+            "`MockConnection` cannot return rows, only statements executed \
+             via `execute` are supported"
+        );
+    }
+
+    fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        let mut query_builder = DB::QueryBuilder::default();
+        source.to_sql(&mut query_builder)?;
+        let binds = format!("{:?}", DebugBinds::<_, DB>::new(source));
+        self.check(&query_builder.finish(), &binds)
+    }
+
+    fn transaction_state(&mut self) -> &mut AnsiTransactionManager {
+        &mut self.transaction_manager
+    }
+}
@@ -1,20 +1,34 @@
 //! Types related to database connections
 
+mod middleware;
+mod mock;
+#[cfg(feature = "serde_json")]
+mod replay;
+mod script;
 mod statement_cache;
 mod transaction_manager;
+mod url;
 
 use std::fmt::Debug;
 
 use crate::backend::Backend;
 use crate::deserialize::FromSqlRow;
 use crate::expression::QueryMetadata;
-use crate::query_builder::{AsQuery, QueryFragment, QueryId};
+use crate::query_builder::{AsQuery, AstPass, QueryFragment, QueryId};
 use crate::query_dsl::load_dsl::CompatibleType;
 use crate::result::*;
 
+pub use self::middleware::{ConnectionMiddleware, SqlLogger};
+pub use self::mock::MockConnection;
+#[cfg(feature = "serde_json")]
+pub use self::replay::ReplayConnection;
+pub use self::script::StatementResult;
 #[doc(hidden)]
 pub use self::statement_cache::{MaybeCached, StatementCache, StatementCacheKey};
 pub use self::transaction_manager::{AnsiTransactionManager, TransactionManager};
+pub use self::url::{
+    parse_connection_url, ConnectionSpec, DatabaseUrl, DatabaseUrlBuilder, InvalidUrl, UrlScheme,
+};
 
 /// Perform simple operations on a backend.
 ///
@@ -27,6 +41,51 @@ pub trait SimpleConnection {
     fn batch_execute(&mut self, query: &str) -> QueryResult<()>;
 }
 
+/// A bind parameter for
+/// [`batch_execute_with_params`](Connection::batch_execute_with_params()),
+/// with its SQL type erased
+///
+/// This trait is implemented by the return type of
+/// [`into_sql`](crate::IntoSql::into_sql()), which is the usual way to
+/// construct a bind parameter:
+///
+/// ```rust
+/// # use diesel::connection::BindValue;
+/// # use diesel::sqlite::Sqlite;
+/// use diesel::sql_types::Integer;
+/// use diesel::IntoSql;
+///
+/// let bind: &dyn BindValue<Sqlite> = &1.into_sql::<Integer>();
+/// ```
+pub trait BindValue<DB: Backend> {
+    /// Adds this value to the query's bind parameters
+    fn push_bind(&self, out: AstPass<DB>) -> QueryResult<()>;
+}
+
+/// A single statement executed by
+/// [`batch_execute_with_params`](Connection::batch_execute_with_params())
+struct ParameterizedStatement<'a, DB: Backend> {
+    sql: &'a str,
+    binds: &'a [&'a dyn BindValue<DB>],
+}
+
+impl<'a, DB: Backend> QueryId for ParameterizedStatement<'a, DB> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<'a, DB: Backend> QueryFragment<DB> for ParameterizedStatement<'a, DB> {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql(self.sql);
+        for bind in self.binds {
+            bind.push_bind(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
 /// A connection to a database
 pub trait Connection: SimpleConnection + Sized + Send {
     /// The backend this type connects to
@@ -173,6 +232,104 @@ pub trait Connection: SimpleConnection + Sized + Send {
         user_result.expect("Transaction did not succeed")
     }
 
+    /// Executes multiple parameterised SQL statements in order
+    ///
+    /// In contrast to [`batch_execute`](SimpleConnection::batch_execute()),
+    /// each statement is given separately and may have bind parameters.
+    /// Placeholders are written in the backend's syntax (`?` on SQLite and
+    /// MySQL, `$1` on PostgreSQL), and the binds of a statement are applied
+    /// in the order they are given. Bind parameters are constructed with
+    /// [`into_sql`](crate::IntoSql::into_sql()).
+    ///
+    /// This is useful for things like session setup, which need several
+    /// statements with dynamic values to run on the same connection.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::connection::BindValue;
+    /// # use diesel::sql_types::Integer;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// connection.batch_execute_with_params(&[
+    ///     (
+    ///         "UPDATE users SET name = 'Jim' WHERE id = ?",
+    ///         &[&1.into_sql::<Integer>() as &dyn BindValue<_>],
+    ///     ),
+    ///     (
+    ///         "DELETE FROM users WHERE id = ?",
+    ///         &[&2.into_sql::<Integer>() as &dyn BindValue<_>],
+    ///     ),
+    /// ])?;
+    ///
+    /// let data = users.select(name).load::<String>(connection)?;
+    /// assert_eq!(vec![String::from("Jim")], data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn batch_execute_with_params(
+        &mut self,
+        statements: &[(&str, &[&dyn BindValue<Self::Backend>])],
+    ) -> QueryResult<()> {
+        for &(sql, binds) in statements {
+            self.execute_returning_count(&ParameterizedStatement { sql, binds })?;
+        }
+        Ok(())
+    }
+
+    /// Executes every statement of a multi-statement SQL script
+    ///
+    /// The script is split into statements on `;`, respecting string
+    /// literals, quoted identifiers, dollar-quoting and comments, and
+    /// each statement is executed in sequence. Execution stops at the
+    /// first failing statement, so the last returned
+    /// [`StatementResult`] identifies which statement failed and why —
+    /// in contrast to [`batch_execute`](SimpleConnection::batch_execute()),
+    /// which hands the whole string to the backend and cannot say where
+    /// an error originated. This is useful for running seed files or
+    /// schema dumps.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::connection::StatementResult;
+    /// #
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// let results = connection.execute_script(
+    ///     "UPDATE users SET name = 'Jim' WHERE id = 1;\n\
+    ///      DELETE FROM users WHERE id = 2;",
+    /// );
+    ///
+    /// assert_eq!(2, results.len());
+    /// assert!(results.iter().all(StatementResult::is_ok));
+    /// assert_eq!("DELETE FROM users WHERE id = 2", results[1].sql);
+    /// # }
+    /// ```
+    fn execute_script(&mut self, script: &str) -> Vec<StatementResult> {
+        let mut results = Vec::new();
+        for statement in self::script::split_statements(script) {
+            let result = self.execute(statement);
+            let failed = result.is_err();
+            results.push(StatementResult {
+                sql: statement.to_owned(),
+                result,
+            });
+            if failed {
+                break;
+            }
+        }
+        results
+    }
+
     #[doc(hidden)]
     fn execute(&mut self, query: &str) -> QueryResult<usize>;
 
@@ -196,6 +353,41 @@ pub trait Connection: SimpleConnection + Sized + Send {
     ) -> &mut <Self::TransactionManager as TransactionManager<Self>>::TransactionStateData;
 }
 
+/// Connections which can limit the execution time of their statements
+///
+/// Long running queries block a connection, and with it a slot of any
+/// connection pool it belongs to. Backends with server side statement
+/// timeouts can abort such queries after a given duration, so the
+/// connection becomes usable again.
+pub trait StatementTimeout: Connection {
+    /// Limits the execution time of statements on this connection
+    ///
+    /// Statements running longer than the given duration are aborted by
+    /// the server and return an error. The timeout applies to the whole
+    /// session; inside a transaction it is reverted at the end of the
+    /// transaction on backends with transactional `SET` (PostgreSQL).
+    ///
+    /// The timeout is rounded down to the backend's precision, on both
+    /// supported backends milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::connection::StatementTimeout;
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     use std::time::Duration;
+    /// #     let conn = &mut establish_connection();
+    /// conn.set_statement_timeout(Duration::from_secs(5)).unwrap();
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    fn set_statement_timeout(&mut self, timeout: std::time::Duration) -> QueryResult<()>;
+}
+
 /// A variant of the [`Connection`](trait.Connection.html) trait that is
 /// usable with dynamic dispatch
 ///
@@ -218,7 +410,7 @@ where
     }
 }
 
-impl<DB: Backend> dyn BoxableConnection<DB> {
+impl<DB: Backend + 'static> dyn BoxableConnection<DB> {
     /// Downcast the current connection to a specific connection
     /// type.
     ///
@@ -0,0 +1,304 @@
+extern crate serde_json;
+
+use std::collections::VecDeque;
+use std::fs;
+use std::path::PathBuf;
+
+use self::serde_json::{json, Value};
+use super::{Connection, SimpleConnection};
+use crate::backend::Backend;
+use crate::connection::AnsiTransactionManager;
+use crate::deserialize::FromSqlRow;
+use crate::expression::QueryMetadata;
+use crate::query_builder::debug_query::DebugBinds;
+use crate::query_builder::{AsQuery, QueryBuilder, QueryFragment, QueryId};
+use crate::query_dsl::load_dsl::CompatibleType;
+use crate::result::ConnectionError::BadConnection;
+use crate::result::Error::{DeserializationError, SerializationError};
+use crate::result::{ConnectionResult, QueryResult};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Interaction {
+    sql: String,
+    binds: String,
+    affected_rows: usize,
+}
+
+/// A connection that records SQL interactions to a file, replaying them
+/// on later runs without a database
+///
+/// In recording mode, created via
+/// [`record`](ReplayConnection::record()), every statement is forwarded
+/// to the wrapped connection and appended to a recording. The recording
+/// is written by [`save`](ReplayConnection::save()), or on drop as a
+/// fallback, as one JSON object per line, so the file is human-readable
+/// and diffs line by line.
+///
+/// In replay mode, created via [`replay`](ReplayConnection::replay()),
+/// no database is needed: each executed statement is compared against
+/// the next recorded interaction and returns the recorded affected row
+/// count, panicking on any mismatch like
+/// [`MockConnection`](super::MockConnection) does.
+///
+/// Queries that return rows are recorded, but cannot be replayed, since
+/// rows of a real backend cannot be fabricated from a recording;
+/// replaying a load panics. Sessions intended for replay should only
+/// contain `execute` style statements.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../doctest_setup.rs");
+/// # use diesel::connection::ReplayConnection;
+/// # use schema::users;
+/// #
+/// # #[cfg(feature = "sqlite")]
+/// # fn main() {
+/// use diesel::sqlite::SqliteConnection;
+///
+/// // First run: record against a real database
+/// let inner = establish_connection();
+/// let conn = &mut ReplayConnection::record(inner, "/tmp/session.jsonl");
+/// diesel::update(users::table.find(1))
+///     .set(users::name.eq("Jim"))
+///     .execute(conn)
+///     .unwrap();
+/// conn.save().unwrap();
+///
+/// // Later runs: replay without a database
+/// let conn = &mut ReplayConnection::<SqliteConnection>::replay("/tmp/session.jsonl").unwrap();
+/// let renamed = diesel::update(users::table.find(1))
+///     .set(users::name.eq("Jim"))
+///     .execute(conn);
+/// assert_eq!(Ok(1), renamed);
+/// # }
+/// # #[cfg(not(feature = "sqlite"))]
+/// # fn main() {}
+/// ```
+pub struct ReplayConnection<Inner> {
+    inner: Option<Inner>,
+    path: PathBuf,
+    recorded: Vec<Interaction>,
+    remaining: VecDeque<Interaction>,
+    saved: bool,
+    transaction_manager: AnsiTransactionManager,
+}
+
+impl<Inner> std::fmt::Debug for ReplayConnection<Inner> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ReplayConnection")
+            .field("path", &self.path)
+            .field("recorded", &self.recorded)
+            .field("remaining", &self.remaining)
+            .finish()
+    }
+}
+
+impl<Inner> ReplayConnection<Inner> {
+    /// Wraps a real connection, recording all SQL interactions for a
+    /// later [`replay`](ReplayConnection::replay())
+    pub fn record(inner: Inner, path: impl Into<PathBuf>) -> Self {
+        ReplayConnection {
+            inner: Some(inner),
+            path: path.into(),
+            recorded: Vec::new(),
+            remaining: VecDeque::new(),
+            saved: false,
+            transaction_manager: AnsiTransactionManager::default(),
+        }
+    }
+
+    /// Creates a connection replaying the recording at the given path
+    /// instead of talking to a database
+    pub fn replay(path: impl Into<PathBuf>) -> QueryResult<Self> {
+        let path = path.into();
+        let contents = fs::read_to_string(&path).map_err(|e| DeserializationError(Box::new(e)))?;
+        let mut remaining = VecDeque::new();
+        for line in contents.lines().filter(|l| !l.trim().is_empty()) {
+            let value: Value =
+                serde_json::from_str(line).map_err(|e| DeserializationError(Box::new(e)))?;
+            let field = |name: &str| {
+                value
+                    .get(name)
+                    .and_then(Value::as_str)
+                    .map(str::to_owned)
+                    .ok_or_else(|| {
+                        DeserializationError(
+                            format!("recorded interaction misses `{}`", name).into(),
+                        )
+                    })
+            };
+            remaining.push_back(Interaction {
+                sql: field("sql")?,
+                binds: field("binds")?,
+                affected_rows: value
+                    .get("affected_rows")
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| {
+                        DeserializationError(
+                            "recorded interaction misses `affected_rows`"
+                                .to_owned()
+                                .into(),
+                        )
+                    })? as usize,
+            });
+        }
+        Ok(ReplayConnection {
+            inner: None,
+            path,
+            recorded: Vec::new(),
+            remaining,
+            saved: true,
+            transaction_manager: AnsiTransactionManager::default(),
+        })
+    }
+
+    /// Writes the recorded interactions to the recording file
+    ///
+    /// This also happens on drop as a fallback, but calling it
+    /// explicitly surfaces write errors.
+    pub fn save(&mut self) -> QueryResult<()> {
+        if self.inner.is_none() {
+            return Ok(());
+        }
+        let mut contents = String::new();
+        for interaction in &self.recorded {
+            let line = json!({
+                "sql": interaction.sql,
+                "binds": interaction.binds,
+                "affected_rows": interaction.affected_rows,
+            });
+            contents.push_str(&line.to_string());
+            contents.push('\n');
+        }
+        fs::write(&self.path, contents).map_err(|e| SerializationError(Box::new(e)))?;
+        self.saved = true;
+        Ok(())
+    }
+
+    fn record_interaction(&mut self, sql: &str, binds: &str, affected_rows: usize) {
+        self.recorded.push(Interaction {
+            sql: sql.to_owned(),
+            binds: binds.to_owned(),
+            affected_rows,
+        });
+        self.saved = false;
+    }
+
+    fn next_replayed(&mut self, sql: &str, binds: &str) -> QueryResult<usize> {
+        let interaction = self.remaining.pop_front().unwrap_or_else(|| {
+            panic!(
+                "unexpected query, the recording has no interactions left:\n  query: {}",
+                sql,
+            )
+        });
+        if interaction.sql != sql || interaction.binds != binds {
+            panic!(
+                "query does not match the recording:\n  recorded: {} -- binds: {}\n       got: {} -- binds: {}",
+                interaction.sql, interaction.binds, sql, binds,
+            );
+        }
+        Ok(interaction.affected_rows)
+    }
+}
+
+impl<Inner> Drop for ReplayConnection<Inner> {
+    fn drop(&mut self) {
+        if !self.saved && !std::thread::panicking() {
+            let _ = self.save();
+        }
+    }
+}
+
+impl<Inner> SimpleConnection for ReplayConnection<Inner>
+where
+    Inner: Connection,
+{
+    fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
+        match self.inner {
+            Some(ref mut inner) => {
+                inner.batch_execute(query)?;
+                self.record_interaction(query, "[]", 0);
+                Ok(())
+            }
+            None => self.next_replayed(query, "[]").map(|_| ()),
+        }
+    }
+}
+
+impl<Inner> Connection for ReplayConnection<Inner>
+where
+    Inner: Connection<TransactionManager = AnsiTransactionManager>,
+    Inner::Backend: crate::backend::UsesAnsiSavepointSyntax,
+    <Inner::Backend as Backend>::QueryBuilder: Default,
+{
+    type Backend = Inner::Backend;
+    type TransactionManager = AnsiTransactionManager;
+
+    fn establish(_database_url: &str) -> ConnectionResult<Self> {
+        Err(BadConnection(
+            "`ReplayConnection` must be created via `record` or `replay`".into(),
+        ))
+    }
+
+    fn execute(&mut self, query: &str) -> QueryResult<usize> {
+        match self.inner {
+            Some(ref mut inner) => {
+                let count = inner.execute(query)?;
+                self.record_interaction(query, "[]", count);
+                Ok(count)
+            }
+            None => self.next_replayed(query, "[]"),
+        }
+    }
+
+    fn load<T, U, ST>(&mut self, source: T) -> QueryResult<Vec<U>>
+    where
+        T: AsQuery,
+        T::Query: QueryFragment<Self::Backend> + QueryId,
+        T::SqlType: CompatibleType<U, Self::Backend, SqlType = ST>,
+        U: FromSqlRow<ST, Self::Backend>,
+        Self::Backend: QueryMetadata<T::SqlType>,
+    {
+        match self.inner {
+            Some(ref mut inner) => {
+                let query = source.as_query();
+                let mut query_builder = <Self::Backend as Backend>::QueryBuilder::default();
+                query.to_sql(&mut query_builder)?;
+                let binds = format!("{:?}", DebugBinds::<_, Self::Backend>::new(&query));
+                let rows = inner.load(query)?;
+                self.record_interaction(&query_builder.finish(), &binds, rows.len());
+                Ok(rows)
+            }
+            None => panic!(
+                "`ReplayConnection` cannot replay queries returning rows, \
+                 only statements executed via `execute` are supported"
+            ),
+        }
+    }
+
+    fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        let mut query_builder = <Self::Backend as Backend>::QueryBuilder::default();
+        source.to_sql(&mut query_builder)?;
+        let sql = query_builder.finish();
+        let binds = format!("{:?}", DebugBinds::<_, Self::Backend>::new(source));
+        match self.inner {
+            Some(ref mut inner) => {
+                let count = inner.execute_returning_count(source)?;
+                self.record_interaction(&sql, &binds, count);
+                Ok(count)
+            }
+            None => self.next_replayed(&sql, &binds),
+        }
+    }
+
+    fn transaction_state(&mut self) -> &mut AnsiTransactionManager {
+        match self.inner {
+            Some(ref mut inner) => inner.transaction_state(),
+            None => &mut self.transaction_manager,
+        }
+    }
+}
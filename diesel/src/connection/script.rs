@@ -0,0 +1,179 @@
+//! Splitting SQL scripts into individual statements
+//!
+//! Used by [`execute_script`](super::Connection::execute_script()) to
+//! run seed files and schema dumps statement by statement, so a failure
+//! can be attributed to the statement which caused it.
+
+use crate::result::QueryResult;
+
+/// The outcome of one statement of a script run by
+/// [`execute_script`](super::Connection::execute_script())
+#[derive(Debug)]
+pub struct StatementResult {
+    /// The statement that was executed, without its trailing `;`
+    pub sql: String,
+    /// The number of rows the statement affected, or the error it
+    /// failed with
+    pub result: QueryResult<usize>,
+}
+
+impl StatementResult {
+    /// Returns `true` if the statement executed successfully
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+}
+
+/// Splits a script on `;`, respecting string literals, quoted
+/// identifiers, dollar-quoting and comments. Empty statements are
+/// dropped.
+pub(crate) fn split_statements(script: &str) -> Vec<&str> {
+    let bytes = script.as_bytes();
+    let mut statements = Vec::new();
+    let mut statement_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b';' => {
+                let statement = script[statement_start..i].trim();
+                if !statement.is_empty() {
+                    statements.push(statement);
+                }
+                statement_start = i + 1;
+                i += 1;
+            }
+            b'\'' | b'"' | b'`' => {
+                let quote = bytes[i];
+                i += 1;
+                while i < bytes.len() {
+                    if bytes[i] == quote {
+                        // A doubled quote is an escaped quote, not the
+                        // end of the literal
+                        if bytes.get(i + 1) == Some(&quote) {
+                            i += 2;
+                        } else {
+                            i += 1;
+                            break;
+                        }
+                    } else {
+                        i += 1;
+                    }
+                }
+            }
+            b'$' => match dollar_tag_end(bytes, i) {
+                Some(tag_end) => {
+                    let tag = &bytes[i..tag_end];
+                    i = tag_end;
+                    while i < bytes.len() {
+                        if bytes[i] == b'$' && bytes[i..].starts_with(tag) {
+                            i += tag.len();
+                            break;
+                        }
+                        i += 1;
+                    }
+                }
+                None => i += 1,
+            },
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                // Block comments nest on PostgreSQL
+                let mut depth = 1;
+                i += 2;
+                while i < bytes.len() && depth > 0 {
+                    if bytes[i] == b'/' && bytes.get(i + 1) == Some(&b'*') {
+                        depth += 1;
+                        i += 2;
+                    } else if bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/') {
+                        depth -= 1;
+                        i += 2;
+                    } else {
+                        i += 1;
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    let statement = script[statement_start..].trim();
+    if !statement.is_empty() {
+        statements.push(statement);
+    }
+    statements
+}
+
+/// Returns the end of the dollar-quote tag starting at `start`, if the
+/// `$` actually opens one (`$$` or `$tag$`)
+fn dollar_tag_end(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'$' => return Some(i + 1),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'_' => i += 1,
+            _ => return None,
+        }
+    }
+    None
+}
+
+#[test]
+fn statements_are_split_on_semicolons() {
+    assert_eq!(
+        vec!["SELECT 1", "SELECT 2"],
+        split_statements("SELECT 1;\nSELECT 2;\n"),
+    );
+    assert_eq!(
+        vec!["SELECT 1", "SELECT 2"],
+        split_statements("SELECT 1; ;; SELECT 2"),
+    );
+}
+
+#[test]
+fn quoted_semicolons_do_not_split() {
+    assert_eq!(
+        vec!["SELECT 'a;b'", r#"SELECT ";" FROM "t;1""#],
+        split_statements(r#"SELECT 'a;b'; SELECT ";" FROM "t;1""#),
+    );
+    assert_eq!(
+        vec!["SELECT 'it''s; fine'"],
+        split_statements("SELECT 'it''s; fine';"),
+    );
+}
+
+#[test]
+fn dollar_quoted_semicolons_do_not_split() {
+    let function = "CREATE FUNCTION f() RETURNS int AS $$\n\
+                    BEGIN RETURN 1; END;\n\
+                    $$ LANGUAGE plpgsql";
+    assert_eq!(
+        vec![function, "SELECT 1"],
+        split_statements(&format!("{};\nSELECT 1;", function)),
+    );
+
+    let tagged = "SELECT $tag$one; two$tag$";
+    assert_eq!(
+        vec![tagged, "SELECT 2"],
+        split_statements(&format!("{}; SELECT 2", tagged)),
+    );
+
+    // `$1` is a bind parameter, not a dollar-quote
+    assert_eq!(
+        vec!["SELECT $1", "SELECT $2"],
+        split_statements("SELECT $1; SELECT $2"),
+    );
+}
+
+#[test]
+fn commented_semicolons_do_not_split() {
+    assert_eq!(
+        vec!["SELECT 1 -- one; two"],
+        split_statements("SELECT 1 -- one; two\n;"),
+    );
+    assert_eq!(
+        vec!["SELECT 1 /* one; /* nested; */ two */"],
+        split_statements("SELECT 1 /* one; /* nested; */ two */;"),
+    );
+}
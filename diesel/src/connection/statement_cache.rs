@@ -83,6 +83,11 @@
 //! the only type which is safe to cache as a prepared statement but does not
 //! have a static query ID is something which has been boxed.
 //!
+//! Boxed queries additionally memoize a hash of the SQL they render
+//! (`QueryId::dynamic_query_id`), which is used to speed up the cache lookup.
+//! The full SQL string remains part of the cache key and is compared on a
+//! hit, so a hash collision can never select the wrong prepared statement.
+//!
 //! One potential optimization that we don't perform is storing the queries
 //! which are cached by type ID in a separate map. Since a type ID is a u64,
 //! this would allow us to use a specialized map which knows that there will
@@ -93,8 +98,9 @@
 
 use std::any::TypeId;
 use std::borrow::Cow;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
 
 use crate::backend::Backend;
@@ -107,7 +113,7 @@ pub struct StatementCache<DB: Backend, Statement> {
     pub cache: HashMap<StatementCacheKey<DB>, Statement>,
 }
 
-#[allow(clippy::len_without_is_empty, clippy::new_without_default)]
+#[allow(clippy::new_without_default)]
 impl<DB, Statement> StatementCache<DB, Statement>
 where
     DB: Backend,
@@ -125,6 +131,10 @@ where
         self.cache.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
     pub fn cached_statement<T, F>(
         &mut self,
         source: &T,
@@ -192,6 +202,11 @@ impl<'a, T> DerefMut for MaybeCached<'a, T> {
 #[derive(Hash, PartialEq, Eq)]
 pub enum StatementCacheKey<DB: Backend> {
     Type(TypeId),
+    QueryHash {
+        hash: u64,
+        sql: String,
+        bind_types: Vec<DB::TypeMetadata>,
+    },
     Sql {
         sql: String,
         bind_types: Vec<DB::TypeMetadata>,
@@ -210,6 +225,24 @@ where
     {
         match T::query_id() {
             Some(id) => Ok(StatementCacheKey::Type(id)),
+            None if T::HAS_DYNAMIC_QUERY_ID => {
+                let sql = Self::construct_sql(source)?;
+                let hash = match source.dynamic_query_id() {
+                    Some(hash) => hash,
+                    None => {
+                        let mut hasher = DefaultHasher::new();
+                        sql.hash(&mut hasher);
+                        let hash = hasher.finish();
+                        source.set_dynamic_query_id(hash);
+                        hash
+                    }
+                };
+                Ok(StatementCacheKey::QueryHash {
+                    hash,
+                    sql,
+                    bind_types: bind_types.into(),
+                })
+            }
             None => {
                 let sql = Self::construct_sql(source)?;
                 Ok(StatementCacheKey::Sql {
@@ -223,7 +256,8 @@ where
     pub fn sql<T: QueryFragment<DB>>(&self, source: &T) -> QueryResult<Cow<str>> {
         match *self {
             StatementCacheKey::Type(_) => Self::construct_sql(source).map(Cow::Owned),
-            StatementCacheKey::Sql { ref sql, .. } => Ok(Cow::Borrowed(sql)),
+            StatementCacheKey::QueryHash { ref sql, .. }
+            | StatementCacheKey::Sql { ref sql, .. } => Ok(Cow::Borrowed(sql)),
         }
     }
 
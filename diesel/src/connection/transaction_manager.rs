@@ -48,6 +48,32 @@ pub struct AnsiTransactionManager {
 }
 
 impl AnsiTransactionManager {
+    /// The number of transactions and savepoints currently open
+    ///
+    /// This is 0 outside of a transaction, 1 inside of a top level
+    /// transaction and increases by one for each nested transaction.
+    /// Connection pool managers and middleware can use this, for example to
+    /// detect connections which are handed back mid-transaction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// assert_eq!(0, connection.transaction_state().transaction_depth());
+    /// connection.transaction::<_, diesel::result::Error, _>(|connection| {
+    ///     assert_eq!(1, connection.transaction_state().transaction_depth());
+    ///     Ok(())
+    /// }).unwrap();
+    /// assert_eq!(0, connection.transaction_state().transaction_depth());
+    /// # }
+    /// ```
+    pub fn transaction_depth(&self) -> u32 {
+        self.transaction_depth as u32
+    }
+
     fn change_transaction_depth(&mut self, by: i32, query: QueryResult<()>) -> QueryResult<()> {
         if query.is_ok() {
             self.transaction_depth += by;
@@ -76,6 +102,27 @@ impl AnsiTransactionManager {
             Err(AlreadyInTransaction)
         }
     }
+
+    /// Finish the current top level transaction with custom SQL
+    ///
+    /// This is used by connections to implement transaction APIs which end
+    /// a transaction with something other than `COMMIT`, such as
+    /// `PREPARE TRANSACTION` on PostgreSQL.
+    /// Returns an error if inside of a nested transaction.
+    pub fn commit_transaction_sql<Conn>(conn: &mut Conn, sql: &str) -> QueryResult<()>
+    where
+        Conn: Connection<TransactionManager = Self>,
+        Conn::Backend: UsesAnsiSavepointSyntax,
+    {
+        use crate::result::Error::AlreadyInTransaction;
+
+        if conn.transaction_state().transaction_depth == 1 {
+            let r = conn.batch_execute(sql);
+            conn.transaction_state().change_transaction_depth(-1, r)
+        } else {
+            Err(AlreadyInTransaction)
+        }
+    }
 }
 
 impl<Conn> TransactionManager<Conn> for AnsiTransactionManager
@@ -355,6 +355,112 @@ where
 #[doc(inline)]
 pub use diesel_derives::QueryableByName;
 
+/// A record type appearing as one branch of a heterogeneous `UNION ALL`
+/// query
+///
+/// Each branch of such a query selects a string literal identifying the
+/// record type alongside its columns. Implementing this trait on the
+/// [`QueryableByName`] struct of a branch declares that discriminator, so
+/// [`UnionResult`] can decide which variant to deserialize a row into.
+pub trait RecordType {
+    /// The name of the column holding the discriminator value
+    ///
+    /// All branches of a union must use the same column name.
+    const DISCRIMINATOR_COLUMN: &'static str = "record_type";
+
+    /// The discriminator value marking a row as this record type
+    const DISCRIMINATOR: &'static str;
+}
+
+/// The result of a `UNION ALL` query over two different record types
+///
+/// Each row is deserialized into either variant depending on the value of
+/// the discriminator column declared via [`RecordType`].
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// #
+/// # use diesel::deserialize::{RecordType, UnionResult};
+/// # use schema::{posts, users};
+/// #
+/// #[derive(QueryableByName, Debug, PartialEq)]
+/// #[table_name = "users"]
+/// struct User {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// impl RecordType for User {
+///     const DISCRIMINATOR: &'static str = "user";
+/// }
+///
+/// #[derive(QueryableByName, Debug, PartialEq)]
+/// #[table_name = "posts"]
+/// struct Post {
+///     id: i32,
+///     title: String,
+/// }
+///
+/// impl RecordType for Post {
+///     const DISCRIMINATOR: &'static str = "post";
+/// }
+///
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// let rows: Vec<UnionResult<User, Post>> = diesel::sql_query(
+///     "SELECT 'user' AS record_type, id, name, NULL AS title FROM users \
+///      UNION ALL \
+///      SELECT 'post' AS record_type, id, NULL AS name, title FROM posts \
+///      ORDER BY record_type DESC, id",
+/// )
+/// .load(connection)
+/// .unwrap();
+/// assert_eq!(5, rows.len());
+/// assert_eq!(
+///     UnionResult::First(User { id: 1, name: "Sean".into() }),
+///     rows[0],
+/// );
+/// assert_eq!(
+///     UnionResult::Second(Post { id: 1, title: "My first post".into() }),
+///     rows[2],
+/// );
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnionResult<A, B> {
+    /// A row whose discriminator matched `A::DISCRIMINATOR`
+    First(A),
+    /// A row whose discriminator matched `B::DISCRIMINATOR`
+    Second(B),
+}
+
+impl<DB, A, B> QueryableByName<DB> for UnionResult<A, B>
+where
+    DB: Backend,
+    A: QueryableByName<DB> + RecordType,
+    B: QueryableByName<DB> + RecordType,
+    String: FromSql<crate::sql_types::Text, DB>,
+{
+    fn build<'a>(row: &impl NamedRow<'a, DB>) -> Result<Self> {
+        let discriminator: String =
+            NamedRow::get::<crate::sql_types::Text, _>(row, A::DISCRIMINATOR_COLUMN)?;
+        if discriminator == A::DISCRIMINATOR {
+            A::build(row).map(UnionResult::First)
+        } else if discriminator == B::DISCRIMINATOR {
+            B::build(row).map(UnionResult::Second)
+        } else {
+            Err(format!(
+                "Unexpected value `{}` in discriminator column `{}`",
+                discriminator,
+                A::DISCRIMINATOR_COLUMN,
+            )
+            .into())
+        }
+    }
+}
+
 /// Deserialize a single field of a given SQL type.
 ///
 /// When possible, implementations of this trait should prefer to use an
@@ -549,3 +655,109 @@ where
 {
     const FIELD_COUNT: usize = <ST as crate::util::TupleSize>::SIZE;
 }
+
+/// The result of deserializing one row of a `LEFT OUTER JOIN`
+///
+/// Left joins are usually deserialized into a tuple `(Left, Option<Right>)`,
+/// where the right side is `None` if no row of the right-hand table matched.
+/// This type makes that structure explicit. The right side is constructed
+/// automatically: if all columns of the right-hand table are `NULL` the row
+/// is deserialized as `right: None`, otherwise as `right: Some(..)`.
+///
+/// Compared to the plain tuple, this type also produces a more descriptive
+/// error message if an unexpected `NULL` is encountered, which typically
+/// indicates that a field of the right-hand struct needs to be an `Option`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// # use diesel::deserialize::LeftJoinResult;
+/// # use schema::{posts, users};
+/// #
+/// # #[derive(Identifiable, Queryable, PartialEq, Debug)]
+/// # pub struct User {
+/// #     id: i32,
+/// #     name: String,
+/// # }
+/// #
+/// # #[derive(Identifiable, Queryable, PartialEq, Debug)]
+/// # pub struct Post {
+/// #     id: i32,
+/// #     user_id: i32,
+/// #     title: String,
+/// # }
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// #     diesel::sql_query("INSERT INTO users (id, name) VALUES (3, 'Ruby')")
+/// #         .execute(connection)?;
+/// let rows = users::table
+///     .left_outer_join(posts::table)
+///     .order((users::id, posts::id))
+///     .load::<LeftJoinResult<User, Post>>(connection)?;
+///
+/// let ruby = rows.last().unwrap();
+/// assert_eq!(User { id: 3, name: "Ruby".into() }, ruby.left);
+/// assert_eq!(None, ruby.right);
+///
+/// let sean = rows.first().unwrap();
+/// assert_eq!(User { id: 1, name: "Sean".into() }, sean.left);
+/// assert_eq!(
+///     Some(Post { id: 1, user_id: 1, title: "My first post".into() }),
+///     sean.right,
+/// );
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeftJoinResult<L, R> {
+    /// The row of the left-hand table
+    pub left: L,
+    /// The matching row of the right-hand table, if any
+    pub right: Option<R>,
+}
+
+impl<L, R> LeftJoinResult<L, R> {
+    /// Converts this result into the equivalent `(Left, Option<Right>)` tuple
+    pub fn into_tuple(self) -> (L, Option<R>) {
+        (self.left, self.right)
+    }
+}
+
+impl<L, R, SL, SR, DB> FromStaticSqlRow<(SL, SR), DB> for LeftJoinResult<L, R>
+where
+    DB: Backend,
+    (L, Option<R>): FromStaticSqlRow<(SL, SR), DB>,
+{
+    fn build_from_row<'a>(row: &impl Row<'a, DB>) -> Result<Self> {
+        match <(L, Option<R>)>::build_from_row(row) {
+            Ok((left, right)) => Ok(LeftJoinResult { left, right }),
+            Err(e) if e.is::<crate::result::UnexpectedNullError>() => Err(format!(
+                "Unexpected `NULL` while deserializing a left join: {}. All columns \
+                 of the right-hand table are nullable in a `left_outer_join`, so \
+                 fields of the right-hand struct which can be `NULL` in the \
+                 database need to be an `Option`",
+                e
+            )
+            .into()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<L, R, SL, SR, DB> Queryable<(SL, SR), DB> for LeftJoinResult<L, R>
+where
+    DB: Backend,
+    Self: FromStaticSqlRow<(SL, SR), DB>,
+{
+    type Row = Self;
+
+    fn build(row: Self::Row) -> Result<Self> {
+        Ok(row)
+    }
+}
@@ -40,6 +40,16 @@ where
     }
 }
 
+impl<T, U, DB> crate::connection::BindValue<DB> for Bound<T, U>
+where
+    DB: Backend + HasSqlType<T>,
+    U: ToSql<T, DB>,
+{
+    fn push_bind(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_bind_param_value_only(&self.item)
+    }
+}
+
 impl<T: QueryId, U> QueryId for Bound<T, U> {
     type QueryId = Bound<T::QueryId, ()>;
 
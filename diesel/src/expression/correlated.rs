@@ -0,0 +1,79 @@
+use crate::backend::Backend;
+use crate::expression::{
+    is_aggregate, AppearsOnTable, Expression, SelectableExpression, ValidGrouping,
+};
+use crate::query_builder::*;
+use crate::result::QueryResult;
+
+/// Marks a column of the outer query for use in a correlated subquery.
+///
+/// Expressions in a subquery are normally only allowed to reference columns
+/// of the subquery's own `FROM` clause. Wrapping a column of the outer query
+/// in `correlated` makes it usable anywhere, so the subquery can be built
+/// through the normal query builder methods. The column is written to SQL
+/// with its qualified name, which refers to the outer query's table when the
+/// subquery is embedded, for example via
+/// [`single_value`](crate::QueryDsl::single_value()) or
+/// [`exists`](crate::dsl::exists()).
+///
+/// Note that whether the referenced table is actually in scope at the point
+/// the subquery is embedded is not checked at compile time.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::{correlated, exists};
+/// # use schema::{posts, users};
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// #     diesel::sql_query("INSERT INTO users (id, name) VALUES (3, 'Ruby')")
+/// #         .execute(connection)?;
+/// // Load all users which have at least one post
+/// let data = users::table
+///     .filter(exists(
+///         posts::table.filter(posts::user_id.eq(correlated(users::id))),
+///     ))
+///     .select(users::name)
+///     .load::<String>(connection)?;
+/// assert_eq!(vec!["Sean", "Tess"], data);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn correlated<C>(outer_column: C) -> Correlated<C> {
+    Correlated(outer_column)
+}
+
+/// The return type of [`correlated(outer_column)`](correlated())
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Correlated<C>(C);
+
+impl<C> Expression for Correlated<C>
+where
+    C: Expression,
+{
+    type SqlType = C::SqlType;
+}
+
+impl<C, QS> SelectableExpression<QS> for Correlated<C> where Self: Expression {}
+
+impl<C, QS> AppearsOnTable<QS> for Correlated<C> where Self: Expression {}
+
+impl<C, GB> ValidGrouping<GB> for Correlated<C> {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<C, DB> QueryFragment<DB> for Correlated<C>
+where
+    DB: Backend,
+    C: QueryFragment<DB>,
+{
+    fn walk_ast(&self, out: AstPass<DB>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
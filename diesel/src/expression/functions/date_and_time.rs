@@ -100,3 +100,107 @@ impl AsExpression<Nullable<Date>> for today {
         Coerce::new(self)
     }
 }
+
+/// The timestamp component extracted by
+/// [`extract()`](crate::dsl::extract()).
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateField {
+    /// The number of seconds since 1970-01-01 00:00:00 UTC
+    /// (PostgreSQL only)
+    Epoch,
+    /// The year
+    Year,
+    /// The month of the year (1--12)
+    Month,
+    /// The day of the month (1--31)
+    Day,
+    /// The hour of the day (0--23)
+    Hour,
+    /// The minute of the hour (0--59)
+    Minute,
+    /// The second of the minute
+    Second,
+    /// The day of the week (0--6, Sunday is 0; PostgreSQL only)
+    DayOfWeek,
+    /// The day of the year (1--365/366; PostgreSQL only)
+    DayOfYear,
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+impl DateField {
+    fn as_sql(self) -> &'static str {
+        match self {
+            DateField::Epoch => "EPOCH",
+            DateField::Year => "YEAR",
+            DateField::Month => "MONTH",
+            DateField::Day => "DAY",
+            DateField::Hour => "HOUR",
+            DateField::Minute => "MINUTE",
+            DateField::Second => "SECOND",
+            DateField::DayOfWeek => "DOW",
+            DateField::DayOfYear => "DOY",
+        }
+    }
+}
+
+/// Represents the SQL `EXTRACT` expression. Returns the given component
+/// of the timestamp expression as a double precision number, e.g.
+/// `extract(DateField::Epoch, created_at)` emits
+/// `EXTRACT(EPOCH FROM created_at)`.
+///
+/// This expression is available on PostgreSQL and MySQL. Note that MySQL
+/// does not support the `Epoch`, `DayOfWeek` and `DayOfYear` fields.
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+pub fn extract<E>(field: DateField, expr: E) -> Extract<E::Expression>
+where
+    E: AsExpression<Timestamp>,
+{
+    Extract {
+        field,
+        expr: expr.as_expression(),
+    }
+}
+
+/// The return type of [`extract(field, expr)`](extract())
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+#[derive(Debug, Clone, Copy, ValidGrouping)]
+pub struct Extract<E> {
+    field: DateField,
+    expr: E,
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+impl<E> QueryId for Extract<E> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+impl<E> Expression for Extract<E>
+where
+    E: Expression,
+{
+    type SqlType = Double;
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+impl_selectable_expression!(Extract<E>);
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+impl<E, DB> QueryFragment<DB> for Extract<E>
+where
+    E: QueryFragment<DB>,
+    DB: Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("EXTRACT(");
+        out.push_sql(self.field.as_sql());
+        out.push_sql(" FROM ");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
@@ -97,3 +97,5 @@ pub mod aggregate_ordering;
 pub mod date_and_time;
 #[doc(hidden)]
 pub mod helper_types;
+pub mod text;
+pub mod window;
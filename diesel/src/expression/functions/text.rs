@@ -0,0 +1,355 @@
+use crate::expression::functions::sql_function;
+use crate::expression::{AsExpression, Expression, ValidGrouping};
+use crate::query_builder::*;
+use crate::result::QueryResult;
+use crate::sql_types::*;
+
+sql_function! {
+    /// Represents the SQL `LOWER` function. Folds the given text
+    /// expression to lower case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let lowered = users.select(lower(name)).load::<String>(connection);
+    /// assert_eq!(Ok(vec![String::from("sean"), String::from("tess")]), lowered);
+    /// # }
+    /// ```
+    fn lower(expr: Text) -> Text;
+}
+
+sql_function! {
+    /// Represents the SQL `UPPER` function. Folds the given text
+    /// expression to upper case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let uppered = users.select(upper(name)).load::<String>(connection);
+    /// assert_eq!(Ok(vec![String::from("SEAN"), String::from("TESS")]), uppered);
+    /// # }
+    /// ```
+    fn upper(expr: Text) -> Text;
+}
+
+sql_function! {
+    /// Represents the SQL `LENGTH` function. Returns the length of the
+    /// given text expression in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let lengths = users.select(length(name)).load::<i32>(connection);
+    /// assert_eq!(Ok(vec![4, 4]), lengths);
+    /// # }
+    /// ```
+    fn length(expr: Text) -> Integer;
+}
+
+sql_function! {
+    /// Represents the SQL `CHAR_LENGTH` function. Returns the number of
+    /// characters in the given text expression, which may be smaller than
+    /// [`length`](crate::dsl::length()) for multi-byte encodings.
+    ///
+    /// This function is not available on SQLite, where
+    /// [`length`](crate::dsl::length()) already counts characters.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let lengths = users.select(char_length(name)).load::<i32>(connection);
+    /// assert_eq!(Ok(vec![4, 4]), lengths);
+    /// # }
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn main() {}
+    /// ```
+    fn char_length(expr: Text) -> Integer;
+}
+
+sql_function! {
+    /// Represents the SQL `TRIM` function. Removes leading and trailing
+    /// whitespace from the given text expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use diesel::select;
+    /// #     let connection = &mut establish_connection();
+    /// let trimmed = select(trim("  Sean  ")).get_result::<String>(connection);
+    /// assert_eq!(Ok(String::from("Sean")), trimmed);
+    /// # }
+    /// ```
+    fn trim(expr: Text) -> Text;
+}
+
+sql_function! {
+    /// Represents the SQL `LTRIM` function. Removes leading whitespace
+    /// from the given text expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use diesel::select;
+    /// #     let connection = &mut establish_connection();
+    /// let trimmed = select(ltrim("  Sean  ")).get_result::<String>(connection);
+    /// assert_eq!(Ok(String::from("Sean  ")), trimmed);
+    /// # }
+    /// ```
+    fn ltrim(expr: Text) -> Text;
+}
+
+sql_function! {
+    /// Represents the SQL `RTRIM` function. Removes trailing whitespace
+    /// from the given text expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use diesel::select;
+    /// #     let connection = &mut establish_connection();
+    /// let trimmed = select(rtrim("  Sean  ")).get_result::<String>(connection);
+    /// assert_eq!(Ok(String::from("  Sean")), trimmed);
+    /// # }
+    /// ```
+    fn rtrim(expr: Text) -> Text;
+}
+
+macro_rules! trim_chars_function {
+    (
+        $(#[doc = $doc:expr])*
+        fn $fn_name:ident -> $struct_name:ident,
+        position = $position:expr,
+        sqlite_function = $sqlite_function:expr,
+    ) => {
+        $(#[doc = $doc])*
+        pub fn $fn_name<E, C>(expr: E, chars: C) -> $struct_name<E::Expression, C::Expression>
+        where
+            E: AsExpression<Text>,
+            C: AsExpression<Text>,
+        {
+            $struct_name {
+                expr: expr.as_expression(),
+                chars: chars.as_expression(),
+            }
+        }
+
+        #[doc(hidden)]
+        #[derive(Debug, Clone, Copy, QueryId, ValidGrouping)]
+        pub struct $struct_name<E, C> {
+            expr: E,
+            chars: C,
+        }
+
+        impl<E, C> Expression for $struct_name<E, C>
+        where
+            E: Expression,
+            C: Expression,
+        {
+            type SqlType = Text;
+        }
+
+        impl_selectable_expression!($struct_name<E, C>);
+
+        // SQLite does not support the `TRIM(BOTH chars FROM expr)`
+        // syntax, but accepts the characters to remove as a second
+        // function argument instead.
+        #[cfg(feature = "sqlite")]
+        impl<E, C> QueryFragment<crate::sqlite::Sqlite> for $struct_name<E, C>
+        where
+            E: QueryFragment<crate::sqlite::Sqlite>,
+            C: QueryFragment<crate::sqlite::Sqlite>,
+        {
+            fn walk_ast(&self, mut out: AstPass<crate::sqlite::Sqlite>) -> QueryResult<()> {
+                out.push_sql(concat!($sqlite_function, "("));
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql(", ");
+                self.chars.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "postgres")]
+        standard_trim_fragment!($struct_name, crate::pg::Pg, $position);
+
+        #[cfg(feature = "mysql")]
+        standard_trim_fragment!($struct_name, crate::mysql::Mysql, $position);
+    };
+}
+
+macro_rules! standard_trim_fragment {
+    ($struct_name:ident, $backend:ty, $position:expr) => {
+        impl<E, C> QueryFragment<$backend> for $struct_name<E, C>
+        where
+            E: QueryFragment<$backend>,
+            C: QueryFragment<$backend>,
+        {
+            fn walk_ast(&self, mut out: AstPass<$backend>) -> QueryResult<()> {
+                out.push_sql(concat!("TRIM(", $position, " "));
+                self.chars.walk_ast(out.reborrow())?;
+                out.push_sql(" FROM ");
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+    };
+}
+
+trim_chars_function! {
+    /// Represents the SQL `TRIM(BOTH chars FROM expr)` expression.
+    /// Removes the given characters from both ends of the text
+    /// expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let trimmed = users.select(trim_chars(name, "Ss")).load::<String>(connection);
+    /// assert_eq!(Ok(vec![String::from("ean"), String::from("Te")]), trimmed);
+    /// # }
+    /// ```
+    fn trim_chars -> TrimChars,
+    position = "BOTH",
+    sqlite_function = "TRIM",
+}
+
+trim_chars_function! {
+    /// Represents the SQL `TRIM(LEADING chars FROM expr)` expression.
+    /// Removes the given characters from the start of the text
+    /// expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let trimmed = users.select(ltrim_chars(name, "S")).load::<String>(connection);
+    /// assert_eq!(Ok(vec![String::from("ean"), String::from("Tess")]), trimmed);
+    /// # }
+    /// ```
+    fn ltrim_chars -> LtrimChars,
+    position = "LEADING",
+    sqlite_function = "LTRIM",
+}
+
+trim_chars_function! {
+    /// Represents the SQL `TRIM(TRAILING chars FROM expr)` expression.
+    /// Removes the given characters from the end of the text
+    /// expression.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let trimmed = users.select(rtrim_chars(name, "s")).load::<String>(connection);
+    /// assert_eq!(Ok(vec![String::from("Sean"), String::from("Te")]), trimmed);
+    /// # }
+    /// ```
+    fn rtrim_chars -> RtrimChars,
+    position = "TRAILING",
+    sqlite_function = "RTRIM",
+}
+
+sql_function! {
+    /// Represents the SQL `SUBSTR` function. Returns `len` characters of
+    /// the given text expression, starting at the 1-based position `pos`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let substrings = users.select(substring(name, 2, 2)).load::<String>(connection);
+    /// assert_eq!(Ok(vec![String::from("ea"), String::from("es")]), substrings);
+    /// # }
+    /// ```
+    #[sql_name = "SUBSTR"]
+    fn substring(expr: Text, pos: Integer, len: Integer) -> Text;
+}
+
+sql_function! {
+    /// Represents the SQL `REPLACE` function. Replaces all occurrences of
+    /// `from` in the given text expression with `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let replaced = users.select(replace(name, "e", "3")).load::<String>(connection);
+    /// assert_eq!(Ok(vec![String::from("S3an"), String::from("T3ss")]), replaced);
+    /// # }
+    /// ```
+    fn replace(expr: Text, from: Text, to: Text) -> Text;
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+sql_function! {
+    /// Represents the SQL `REGEXP_REPLACE` function. Replaces substrings
+    /// of the given text expression that match the POSIX regular
+    /// expression `pattern` with `replacement`.
+    ///
+    /// This function is available on PostgreSQL and MySQL 8+, but not on
+    /// SQLite.
+    fn regexp_replace(expr: Text, pattern: Text, replacement: Text) -> Text;
+}
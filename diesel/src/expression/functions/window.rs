@@ -0,0 +1,216 @@
+//! Window function support
+//!
+//! This module provides the [`Window`] specification builder and the
+//! [`WindowFunction`] expression produced by calling
+//! [`over`](OverDsl::over()) on one of the supported window function types.
+
+use crate::backend::Backend;
+use crate::expression::{is_aggregate, Expression, ValidGrouping};
+use crate::query_builder::order_clause::{NoOrderClause, OrderClause};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::BigInt;
+use crate::{AppearsOnTable, SelectableExpression};
+
+/// Creates a SQL `ROW_NUMBER()` window function expression
+///
+/// This expression is only usable in combination with a window
+/// specification. See [`over`](OverDsl::over()) for details.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # fn main() {
+/// #     use schema::users::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let numbered = users
+///     .select((name, row_number().over(Window::new().order_by(name))))
+///     .load::<(String, i64)>(connection)
+///     .unwrap();
+/// assert_eq!(
+///     vec![("Sean".to_owned(), 1), ("Tess".to_owned(), 2)],
+///     numbered
+/// );
+/// # }
+/// ```
+pub fn row_number() -> RowNumber {
+    RowNumber
+}
+
+/// Creates a SQL `RANK()` window function expression
+///
+/// This expression is only usable in combination with a window
+/// specification. See [`over`](OverDsl::over()) for details.
+pub fn rank() -> Rank {
+    Rank
+}
+
+/// Creates a SQL `DENSE_RANK()` window function expression
+///
+/// This expression is only usable in combination with a window
+/// specification. See [`over`](OverDsl::over()) for details.
+pub fn dense_rank() -> DenseRank {
+    DenseRank
+}
+
+macro_rules! no_arg_window_function {
+    ($type_name:ident, $sql:expr) => {
+        #[doc(hidden)]
+        #[derive(Debug, Clone, Copy, QueryId, ValidGrouping)]
+        pub struct $type_name;
+
+        impl Expression for $type_name {
+            type SqlType = BigInt;
+        }
+
+        impl<DB: Backend> QueryFragment<DB> for $type_name {
+            fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+                out.push_sql($sql);
+                Ok(())
+            }
+        }
+
+        impl OverDsl for $type_name {}
+    };
+}
+
+no_arg_window_function!(RowNumber, "ROW_NUMBER()");
+no_arg_window_function!(Rank, "RANK()");
+no_arg_window_function!(DenseRank, "DENSE_RANK()");
+
+/// A SQL window specification, as used inside `OVER (...)`
+///
+/// Constructed via [`Window::new`], refined via
+/// [`partition_by`](Window::partition_by()) and
+/// [`order_by`](Window::order_by()), and consumed by
+/// [`over`](OverDsl::over()).
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Window<Partition = NoPartitionClause, Order = NoOrderClause> {
+    partition: Partition,
+    order: Order,
+}
+
+impl Window {
+    /// Creates an empty window specification, representing `OVER ()`
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Window {
+            partition: NoPartitionClause,
+            order: NoOrderClause,
+        }
+    }
+}
+
+impl<Partition, Order> Window<Partition, Order> {
+    /// Adds a `PARTITION BY` clause to this window specification
+    pub fn partition_by<E>(self, expr: E) -> Window<PartitionByClause<E>, Order>
+    where
+        E: Expression,
+    {
+        Window {
+            partition: PartitionByClause(expr),
+            order: self.order,
+        }
+    }
+
+    /// Adds an `ORDER BY` clause to this window specification
+    pub fn order_by<E>(self, expr: E) -> Window<Partition, OrderClause<E>>
+    where
+        E: Expression,
+    {
+        Window {
+            partition: self.partition,
+            order: OrderClause(expr),
+        }
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Default, QueryId)]
+pub struct NoPartitionClause;
+
+impl<DB: Backend> QueryFragment<DB> for NoPartitionClause {
+    fn walk_ast(&self, _: AstPass<DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct PartitionByClause<E>(E);
+
+impl<E, DB> QueryFragment<DB> for PartitionByClause<E>
+where
+    DB: Backend,
+    E: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("PARTITION BY ");
+        self.0.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// Attaches a window specification to a window function expression
+pub trait OverDsl: Sized {
+    /// Creates a `function OVER (window)` expression
+    fn over<Partition, Order>(
+        self,
+        window: Window<Partition, Order>,
+    ) -> WindowFunction<Self, Partition, Order> {
+        WindowFunction {
+            function: self,
+            partition: window.partition,
+            order: window.order,
+        }
+    }
+}
+
+/// A window function expression, `function OVER (window)`
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct WindowFunction<F, Partition = NoPartitionClause, Order = NoOrderClause> {
+    function: F,
+    partition: Partition,
+    order: Order,
+}
+
+impl<F, Partition, Order> Expression for WindowFunction<F, Partition, Order>
+where
+    F: Expression,
+{
+    type SqlType = F::SqlType;
+}
+
+impl<F, Partition, Order, GB> ValidGrouping<GB> for WindowFunction<F, Partition, Order> {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<F, Partition, Order, QS> SelectableExpression<QS> for WindowFunction<F, Partition, Order> where
+    Self: AppearsOnTable<QS>
+{
+}
+
+impl<F, Partition, Order, QS> AppearsOnTable<QS> for WindowFunction<F, Partition, Order> where
+    Self: Expression
+{
+}
+
+impl<F, Partition, Order, DB> QueryFragment<DB> for WindowFunction<F, Partition, Order>
+where
+    DB: Backend,
+    F: QueryFragment<DB>,
+    Partition: QueryFragment<DB>,
+    Order: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.function.walk_ast(out.reborrow())?;
+        out.push_sql(" OVER (");
+        self.partition.walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+//! `GROUP BY` modifiers for OLAP queries
+//!
+//! This module provides the [`rollup`], [`cube`] and [`grouping_sets`]
+//! functions, which wrap a group of expressions for use in the
+//! [`group_by`](crate::query_dsl::QueryDsl::group_by()) position of a
+//! select statement.
+
+use crate::backend::Backend;
+use crate::expression::{Expression, IsContainedInGroupBy, ValidGrouping};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::{AppearsOnTable, SelectableExpression};
+
+/// Creates a SQL `ROLLUP (...)` group by modifier
+///
+/// The argument should be a tuple of expressions to group by. The resulting
+/// grouping is equivalent to grouping by every prefix of the given
+/// expressions, including the empty grand total group.
+pub fn rollup<T>(exprs: T) -> Rollup<T>
+where
+    T: Expression,
+{
+    Rollup(exprs)
+}
+
+/// Creates a SQL `CUBE (...)` group by modifier
+///
+/// The argument should be a tuple of expressions to group by. The resulting
+/// grouping is equivalent to grouping by every possible subset of the given
+/// expressions.
+pub fn cube<T>(exprs: T) -> Cube<T>
+where
+    T: Expression,
+{
+    Cube(exprs)
+}
+
+/// Creates a SQL `GROUPING SETS (...)` group by modifier
+///
+/// The argument should be a tuple of tuples of expressions. Each inner tuple
+/// describes one grouping set to aggregate over.
+pub fn grouping_sets<T>(sets: T) -> GroupingSets<T>
+where
+    T: Expression,
+{
+    GroupingSets(sets)
+}
+
+macro_rules! group_by_modifier {
+    ($type_name:ident) => {
+        /// The return type of the corresponding group by modifier function
+        #[derive(Debug, Clone, Copy, QueryId)]
+        pub struct $type_name<T>(T);
+
+        impl<T> Expression for $type_name<T>
+        where
+            T: Expression,
+        {
+            type SqlType = T::SqlType;
+        }
+
+        impl<T, GB> ValidGrouping<GB> for $type_name<T>
+        where
+            T: ValidGrouping<GB>,
+        {
+            type IsAggregate = T::IsAggregate;
+        }
+
+        impl<T, Col> IsContainedInGroupBy<Col> for $type_name<T>
+        where
+            Col: crate::query_source::Column,
+            T: IsContainedInGroupBy<Col>,
+        {
+            type Output = T::Output;
+        }
+
+        impl<T, QS> SelectableExpression<QS> for $type_name<T>
+        where
+            Self: AppearsOnTable<QS>,
+            T: SelectableExpression<QS>,
+        {
+        }
+
+        impl<T, QS> AppearsOnTable<QS> for $type_name<T>
+        where
+            Self: Expression,
+            T: AppearsOnTable<QS>,
+        {
+        }
+    };
+}
+
+group_by_modifier!(Rollup);
+group_by_modifier!(Cube);
+group_by_modifier!(GroupingSets);
+
+impl<T, DB> QueryFragment<DB> for Rollup<T>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("ROLLUP (");
+        self.0.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T, DB> QueryFragment<DB> for Cube<T>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("CUBE (");
+        self.0.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T, DB> QueryFragment<DB> for GroupingSets<T>
+where
+    DB: Backend,
+    T: GroupingSetCollection<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("GROUPING SETS (");
+        self.0.walk_sets(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// A collection of grouping sets, walked as a parenthesized,
+/// comma separated list
+///
+/// This trait is implemented for tuples of tuples of expressions.
+#[doc(hidden)]
+pub trait GroupingSetCollection<DB: Backend> {
+    fn walk_sets(&self, out: AstPass<DB>) -> QueryResult<()>;
+}
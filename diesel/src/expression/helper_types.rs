@@ -40,6 +40,26 @@ pub type IsNull<Expr> = Grouped<super::operators::IsNull<Expr>>;
 /// [`expr.is_not_null()`](crate::expression_methods::ExpressionMethods::is_not_null())
 pub type IsNotNull<Expr> = Grouped<super::operators::IsNotNull<Expr>>;
 
+/// The return type of
+/// [`expr.is_true()`](crate::expression_methods::BoolExpressionMethods::is_true())
+pub type IsTrue<Expr> = Grouped<super::operators::IsTrue<Expr>>;
+
+/// The return type of
+/// [`expr.is_not_true()`](crate::expression_methods::BoolExpressionMethods::is_not_true())
+pub type IsNotTrue<Expr> = Grouped<super::operators::IsNotTrue<Expr>>;
+
+/// The return type of
+/// [`expr.is_false()`](crate::expression_methods::BoolExpressionMethods::is_false())
+pub type IsFalse<Expr> = Grouped<super::operators::IsFalse<Expr>>;
+
+/// The return type of
+/// [`expr.is_not_false()`](crate::expression_methods::BoolExpressionMethods::is_not_false())
+pub type IsNotFalse<Expr> = Grouped<super::operators::IsNotFalse<Expr>>;
+
+/// The return type of
+/// [`expr.is_unknown()`](crate::expression_methods::BoolExpressionMethods::is_unknown())
+pub type IsUnknown<Expr> = Grouped<super::operators::IsUnknown<Expr>>;
+
 /// The return type of
 /// [`lhs.gt(rhs)`](crate::expression_methods::ExpressionMethods::gt())
 pub type Gt<Lhs, Rhs> = Grouped<super::operators::Gt<Lhs, AsExpr<Rhs, Lhs>>>;
@@ -0,0 +1,74 @@
+use crate::backend::Backend;
+use crate::expression::{AppearsOnTable, Expression, SelectableExpression, ValidGrouping};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::BoolOrNullableBool;
+
+/// A predicate which is only applied when a runtime condition holds,
+/// used by [`filter_if`](crate::QueryDsl::filter_if())
+///
+/// When the condition is false, the wrapped predicate is replaced by the
+/// always-true expression `1=1` so that the query type does not depend on
+/// the condition.
+#[derive(Debug, Clone, Copy)]
+pub struct MaybePredicate<P> {
+    condition: bool,
+    predicate: P,
+}
+
+impl<P> MaybePredicate<P> {
+    pub(crate) fn new(condition: bool, predicate: P) -> Self {
+        MaybePredicate {
+            condition,
+            predicate,
+        }
+    }
+}
+
+impl<P> Expression for MaybePredicate<P>
+where
+    P: Expression,
+    P::SqlType: BoolOrNullableBool,
+{
+    type SqlType = P::SqlType;
+}
+
+impl<P, QS> SelectableExpression<QS> for MaybePredicate<P> where
+    Self: Expression + AppearsOnTable<QS>
+{
+}
+
+impl<P, QS> AppearsOnTable<QS> for MaybePredicate<P>
+where
+    Self: Expression,
+    P: AppearsOnTable<QS>,
+{
+}
+
+impl<P, GB> ValidGrouping<GB> for MaybePredicate<P>
+where
+    P: ValidGrouping<GB>,
+{
+    type IsAggregate = P::IsAggregate;
+}
+
+impl<P> QueryId for MaybePredicate<P> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<P, DB> QueryFragment<DB> for MaybePredicate<P>
+where
+    DB: Backend,
+    P: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        if self.condition {
+            self.predicate.walk_ast(out)
+        } else {
+            out.push_sql("1=1");
+            Ok(())
+        }
+    }
+}
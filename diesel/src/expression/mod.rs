@@ -28,17 +28,24 @@ pub mod bound;
 #[doc(hidden)]
 pub mod coerce;
 #[doc(hidden)]
+pub mod correlated;
+#[doc(hidden)]
 pub mod count;
 #[doc(hidden)]
 pub mod exists;
 #[doc(hidden)]
 pub mod grouped;
+pub mod grouping_sets;
 #[doc(hidden)]
 pub mod helper_types;
+#[doc(hidden)]
+pub mod maybe_predicate;
 mod not;
 #[doc(hidden)]
 pub mod nullable;
 #[doc(hidden)]
+pub mod random;
+#[doc(hidden)]
 #[macro_use]
 pub mod operators;
 #[doc(hidden)]
@@ -53,6 +60,8 @@ pub mod subselect;
 pub mod dsl {
     use crate::dsl::SqlTypeOf;
 
+    #[doc(inline)]
+    pub use super::correlated::{correlated, Correlated};
     #[doc(inline)]
     pub use super::count::*;
     #[doc(inline)]
@@ -64,13 +73,26 @@ pub mod dsl {
     #[doc(inline)]
     pub use super::functions::date_and_time::*;
     #[doc(inline)]
+    pub use super::functions::text::*;
+    #[doc(inline)]
+    pub use super::functions::window::{
+        dense_rank, rank, row_number, OverDsl, Window, WindowFunction,
+    };
+    #[doc(inline)]
+    pub use super::grouping_sets::{cube, grouping_sets, rollup};
+    #[doc(inline)]
     pub use super::not::not;
     #[doc(inline)]
+    pub use super::random::{random, Random};
+    #[doc(inline)]
     pub use super::sql_literal::sql;
 
     #[cfg(feature = "postgres")]
     pub use crate::pg::expression::dsl::*;
 
+    #[cfg(feature = "mysql")]
+    pub use crate::mysql::expression::dsl::*;
+
     /// The return type of [`count(expr)`](crate::dsl::count())
     pub type count<Expr> = super::count::count::HelperType<SqlTypeOf<Expr>, Expr>;
 
@@ -82,6 +104,58 @@ pub mod dsl {
 
     /// The return type of [`date(expr)`](crate::dsl::date())
     pub type date<Expr> = super::functions::date_and_time::date::HelperType<Expr>;
+
+    /// The return type of [`lower(expr)`](crate::dsl::lower())
+    pub type lower<Expr> = super::functions::text::lower::HelperType<Expr>;
+
+    /// The return type of [`upper(expr)`](crate::dsl::upper())
+    pub type upper<Expr> = super::functions::text::upper::HelperType<Expr>;
+
+    /// The return type of [`length(expr)`](crate::dsl::length())
+    pub type length<Expr> = super::functions::text::length::HelperType<Expr>;
+
+    /// The return type of [`char_length(expr)`](crate::dsl::char_length())
+    pub type char_length<Expr> = super::functions::text::char_length::HelperType<Expr>;
+
+    /// The return type of [`trim(expr)`](crate::dsl::trim())
+    pub type trim<Expr> = super::functions::text::trim::HelperType<Expr>;
+
+    /// The return type of [`ltrim(expr)`](crate::dsl::ltrim())
+    pub type ltrim<Expr> = super::functions::text::ltrim::HelperType<Expr>;
+
+    /// The return type of [`rtrim(expr)`](crate::dsl::rtrim())
+    pub type rtrim<Expr> = super::functions::text::rtrim::HelperType<Expr>;
+
+    /// The return type of [`trim_chars(expr, chars)`](crate::dsl::trim_chars())
+    pub type trim_chars<Expr, Chars> = super::functions::text::TrimChars<
+        crate::dsl::AsExprOf<Expr, crate::sql_types::Text>,
+        crate::dsl::AsExprOf<Chars, crate::sql_types::Text>,
+    >;
+
+    /// The return type of [`ltrim_chars(expr, chars)`](crate::dsl::ltrim_chars())
+    pub type ltrim_chars<Expr, Chars> = super::functions::text::LtrimChars<
+        crate::dsl::AsExprOf<Expr, crate::sql_types::Text>,
+        crate::dsl::AsExprOf<Chars, crate::sql_types::Text>,
+    >;
+
+    /// The return type of [`rtrim_chars(expr, chars)`](crate::dsl::rtrim_chars())
+    pub type rtrim_chars<Expr, Chars> = super::functions::text::RtrimChars<
+        crate::dsl::AsExprOf<Expr, crate::sql_types::Text>,
+        crate::dsl::AsExprOf<Chars, crate::sql_types::Text>,
+    >;
+
+    /// The return type of [`substring(expr, pos, len)`](crate::dsl::substring())
+    pub type substring<Expr, Pos, Len> =
+        super::functions::text::substring::HelperType<Expr, Pos, Len>;
+
+    /// The return type of [`replace(expr, from, to)`](crate::dsl::replace())
+    pub type replace<Expr, From, To> = super::functions::text::replace::HelperType<Expr, From, To>;
+
+    /// The return type of
+    /// [`regexp_replace(expr, pattern, replacement)`](crate::dsl::regexp_replace())
+    #[cfg(any(feature = "postgres", feature = "mysql"))]
+    pub type regexp_replace<Expr, Pattern, Replacement> =
+        super::functions::text::regexp_replace::HelperType<Expr, Pattern, Replacement>;
 }
 
 #[doc(inline)]
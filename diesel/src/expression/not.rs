@@ -23,6 +23,28 @@ use crate::sql_types::BoolOrNullableBool;
 /// assert_eq!(Ok(2), users_not_with_name.first(connection));
 /// # }
 /// ```
+///
+/// Any boolean expression can be negated. The argument is wrapped in
+/// parentheses, so complex expressions are negated as a whole without
+/// manually applying De Morgan's laws:
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     use schema::users::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::not;
+///
+/// // `NOT (name = 'Sean' AND id > 0)`
+/// let names = users
+///     .select(name)
+///     .filter(not(name.eq("Sean").and(id.gt(0))))
+///     .load::<String>(connection);
+///
+/// assert_eq!(Ok(vec!["Tess".to_string()]), names);
+/// # }
+/// ```
 pub fn not<T>(expr: T) -> not<T>
 where
     T: Expression,
@@ -512,6 +512,11 @@ infix_operator!(NotBetween, " NOT BETWEEN ");
 
 postfix_operator!(IsNull, " IS NULL");
 postfix_operator!(IsNotNull, " IS NOT NULL");
+postfix_operator!(IsTrue, " IS TRUE");
+postfix_operator!(IsNotTrue, " IS NOT TRUE");
+postfix_operator!(IsFalse, " IS FALSE");
+postfix_operator!(IsNotFalse, " IS NOT FALSE");
+postfix_operator!(IsUnknown, " IS UNKNOWN");
 postfix_operator!(
     Asc,
     " ASC ",
@@ -525,7 +530,6 @@ postfix_operator!(
 
 prefix_operator!(Not, " NOT ");
 
-use crate::backend::Backend;
 use crate::expression::{TypedExpressionType, ValidGrouping};
 use crate::insertable::{ColumnInsertValue, Insertable};
 use crate::query_builder::{QueryFragment, QueryId, ValuesClause};
@@ -581,16 +585,14 @@ impl_selectable_expression!(Concat<L, R>);
 
 impl<L, R, DB> QueryFragment<DB> for Concat<L, R>
 where
+    DB: crate::backend::Backend + crate::backend::UsesAnsiConcatSyntax,
     L: QueryFragment<DB>,
     R: QueryFragment<DB>,
-    DB: Backend,
 {
     fn walk_ast(
         &self,
         mut out: crate::query_builder::AstPass<DB>,
     ) -> crate::result::QueryResult<()> {
-        // Those brackets are required because mysql is broken
-        // https://github.com/diesel-rs/diesel/issues/2133#issuecomment-517432317
         out.push_sql("(");
         self.left.walk_ast(out.reborrow())?;
         out.push_sql(" || ");
@@ -599,3 +601,25 @@ where
         Ok(())
     }
 }
+
+// MySQL only treats `||` as string concatenation when the
+// `PIPES_AS_CONCAT` SQL mode is enabled, so use `CONCAT()` there
+// https://github.com/diesel-rs/diesel/issues/2133#issuecomment-517432317
+#[cfg(feature = "mysql")]
+impl<L, R> QueryFragment<crate::mysql::Mysql> for Concat<L, R>
+where
+    L: QueryFragment<crate::mysql::Mysql>,
+    R: QueryFragment<crate::mysql::Mysql>,
+{
+    fn walk_ast(
+        &self,
+        mut out: crate::query_builder::AstPass<crate::mysql::Mysql>,
+    ) -> crate::result::QueryResult<()> {
+        out.push_sql("CONCAT(");
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.right.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::expression::{is_aggregate, Expression, ValidGrouping};
+use crate::query_builder::QueryId;
+use crate::sql_types::Double;
+use crate::{AppearsOnTable, SelectableExpression};
+
+#[cfg(any(feature = "postgres", feature = "sqlite", feature = "mysql"))]
+use crate::query_builder::{AstPass, QueryFragment};
+#[cfg(any(feature = "postgres", feature = "sqlite", feature = "mysql"))]
+use crate::result::QueryResult;
+
+/// Creates a SQL random number expression
+///
+/// This emits the random number function of the backend the query is
+/// executed against, i.e. `RANDOM()` on PostgreSQL and SQLite and `RAND()`
+/// on MySQL. It is primarily intended for randomly ordering a query via
+/// `.order(random())`.
+///
+/// As with most bare functions, this is not exported by default. You can
+/// import it specifically as `diesel::dsl::random`, or glob import
+/// `diesel::dsl::*`
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::*;
+/// #
+/// # fn main() {
+/// #     use schema::users::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let mut ids = users
+///     .select(id)
+///     .order(random())
+///     .load::<i32>(connection)
+///     .unwrap();
+/// ids.sort_unstable();
+/// assert_eq!(vec![1, 2], ids);
+/// # }
+/// ```
+pub fn random() -> Random {
+    Random
+}
+
+/// The return type of [`random()`](random())
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Random;
+
+impl Expression for Random {
+    type SqlType = Double;
+}
+
+impl<QS> SelectableExpression<QS> for Random {}
+
+impl<QS> AppearsOnTable<QS> for Random {}
+
+impl<GB> ValidGrouping<GB> for Random {
+    type IsAggregate = is_aggregate::No;
+}
+
+#[cfg(feature = "postgres")]
+impl QueryFragment<crate::pg::Pg> for Random {
+    fn walk_ast(&self, mut out: AstPass<crate::pg::Pg>) -> QueryResult<()> {
+        out.push_sql("RANDOM()");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl QueryFragment<crate::sqlite::Sqlite> for Random {
+    fn walk_ast(&self, mut out: AstPass<crate::sqlite::Sqlite>) -> QueryResult<()> {
+        out.push_sql("RANDOM()");
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl QueryFragment<crate::mysql::Mysql> for Random {
+    fn walk_ast(&self, mut out: AstPass<crate::mysql::Mysql>) -> QueryResult<()> {
+        out.push_sql("RAND()");
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use crate::dsl;
 use crate::expression::grouped::Grouped;
-use crate::expression::operators::{And, Or};
+use crate::expression::operators::{And, IsFalse, IsNotFalse, IsNotTrue, IsTrue, IsUnknown, Or};
 use crate::expression::{AsExpression, Expression, TypedExpressionType};
 use crate::sql_types::{BoolOrNullableBool, SqlType};
 
@@ -95,6 +95,122 @@ pub trait BoolExpressionMethods: Expression + Sized {
     {
         Grouped(Or::new(self, other.as_expression()))
     }
+
+    /// Creates a SQL `IS TRUE` expression.
+    ///
+    /// Unlike `.eq(true)`, this returns `false` instead of `NULL` when
+    /// called on a `NULL` value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::{Bool, Nullable};
+    /// #
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// let res = diesel::select(sql::<Nullable<Bool>>("NULL").is_true())
+    ///     .get_result::<bool>(connection);
+    /// assert_eq!(Ok(false), res);
+    /// # }
+    /// ```
+    fn is_true(self) -> dsl::IsTrue<Self> {
+        Grouped(IsTrue::new(self))
+    }
+
+    /// Creates a SQL `IS NOT TRUE` expression.
+    ///
+    /// Returns `true` for both `false` and `NULL` values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::{Bool, Nullable};
+    /// #
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// let res = diesel::select(sql::<Nullable<Bool>>("NULL").is_not_true())
+    ///     .get_result::<bool>(connection);
+    /// assert_eq!(Ok(true), res);
+    /// # }
+    /// ```
+    fn is_not_true(self) -> dsl::IsNotTrue<Self> {
+        Grouped(IsNotTrue::new(self))
+    }
+
+    /// Creates a SQL `IS FALSE` expression.
+    ///
+    /// Unlike `.eq(false)`, this returns `false` instead of `NULL` when
+    /// called on a `NULL` value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::{Bool, Nullable};
+    /// #
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// let res = diesel::select(sql::<Nullable<Bool>>("NULL").is_false())
+    ///     .get_result::<bool>(connection);
+    /// assert_eq!(Ok(false), res);
+    /// # }
+    /// ```
+    fn is_false(self) -> dsl::IsFalse<Self> {
+        Grouped(IsFalse::new(self))
+    }
+
+    /// Creates a SQL `IS NOT FALSE` expression.
+    ///
+    /// Returns `true` for both `true` and `NULL` values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::{Bool, Nullable};
+    /// #
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// let res = diesel::select(sql::<Nullable<Bool>>("NULL").is_not_false())
+    ///     .get_result::<bool>(connection);
+    /// assert_eq!(Ok(true), res);
+    /// # }
+    /// ```
+    fn is_not_false(self) -> dsl::IsNotFalse<Self> {
+        Grouped(IsNotFalse::new(self))
+    }
+
+    /// Creates a SQL `IS UNKNOWN` expression.
+    ///
+    /// This is equivalent to `IS NULL` for boolean expressions. It is
+    /// not supported on SQLite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::{Bool, Nullable};
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// let res = diesel::select(sql::<Nullable<Bool>>("NULL").is_unknown())
+    ///     .get_result::<bool>(connection);
+    /// assert_eq!(Ok(true), res);
+    /// # }
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn main() {}
+    /// ```
+    fn is_unknown(self) -> dsl::IsUnknown<Self> {
+        Grouped(IsUnknown::new(self))
+    }
 }
 
 impl<T> BoolExpressionMethods for T
@@ -32,6 +32,10 @@ pub trait ExpressionMethods: Expression + Sized {
 
     /// Creates a SQL `!=` expression.
     ///
+    /// Note that `!=` returns `NULL` when either side is `NULL`. For a
+    /// null-safe comparison that treats two `NULL` values as equal, see
+    /// `is_distinct_from` in the PostgreSQL specific expression methods.
+    ///
     /// # Example
     ///
     /// ```rust
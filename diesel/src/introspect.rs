@@ -0,0 +1,580 @@
+//! Runtime schema introspection helpers
+//!
+//! These helpers answer questions about the schema of the connected
+//! database, e.g. to conditionally apply DDL in migrations.
+
+use crate::connection::Connection;
+use crate::result::QueryResult;
+
+/// Connections which can answer schema introspection queries
+///
+/// This trait provides the backend specific implementations behind
+/// [`table_exists`], [`column_exists`] and [`InformationSchema::load`];
+/// call those instead of using it directly.
+///
+/// [`InformationSchema::load`]: InformationSchema::load()
+pub trait Introspect: Connection {
+    #[doc(hidden)]
+    fn table_exists(&mut self, table: &str) -> QueryResult<bool>;
+
+    #[doc(hidden)]
+    fn column_exists(&mut self, table: &str, column: &str) -> QueryResult<bool>;
+
+    #[doc(hidden)]
+    fn table_names(&mut self) -> QueryResult<Vec<String>>;
+
+    #[doc(hidden)]
+    fn load_columns(&mut self, table: &str) -> QueryResult<Vec<ColumnDefinition>>;
+
+    #[doc(hidden)]
+    fn load_foreign_keys(&mut self, table: &str) -> QueryResult<Vec<ForeignKeyDefinition>>;
+
+    #[doc(hidden)]
+    fn load_indexes(&mut self, table: &str) -> QueryResult<Vec<IndexDefinition>>;
+}
+
+/// A snapshot of the schema of the connected database
+///
+/// Loaded via [`InformationSchema::load`]. This can be used to detect
+/// drift between the deployed schema and the expected one, or to
+/// generate queries dynamically at runtime.
+///
+/// [`InformationSchema::load`]: InformationSchema::load()
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InformationSchema {
+    /// The tables of the connection's default schema
+    pub tables: Vec<TableDefinition>,
+}
+
+/// The definition of a single table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TableDefinition {
+    /// The name of the table
+    pub name: String,
+    /// The columns of the table, in definition order
+    pub columns: Vec<ColumnDefinition>,
+    /// The foreign key constraints declared on the table
+    pub foreign_keys: Vec<ForeignKeyDefinition>,
+    /// The indexes defined on the table
+    pub indexes: Vec<IndexDefinition>,
+}
+
+/// The definition of a single column
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ColumnDefinition {
+    /// The name of the column
+    pub name: String,
+    /// The SQL type of the column, as the backend reports it
+    pub type_name: String,
+    /// Whether the column accepts `NULL`
+    pub is_nullable: bool,
+    /// The default expression of the column, if any
+    pub default: Option<String>,
+}
+
+/// A foreign key constraint of a table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ForeignKeyDefinition {
+    /// The name of the constraint
+    ///
+    /// `None` on SQLite, which does not name foreign key constraints
+    pub name: Option<String>,
+    /// The constrained column
+    pub column: String,
+    /// The table the constraint references
+    pub referenced_table: String,
+    /// The referenced column
+    ///
+    /// `None` on SQLite when the constraint implicitly references the
+    /// primary key of the other table
+    pub referenced_column: Option<String>,
+}
+
+/// An index of a table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IndexDefinition {
+    /// The name of the index
+    pub name: String,
+    /// The indexed columns, in index order
+    pub columns: Vec<String>,
+    /// Whether the index enforces uniqueness
+    pub is_unique: bool,
+}
+
+impl InformationSchema {
+    /// Loads the schema of the connection's default database
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("doctest_setup.rs");
+    /// # use diesel::introspect::InformationSchema;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut establish_connection();
+    /// let schema = InformationSchema::load(conn)?;
+    ///
+    /// let users = schema
+    ///     .tables
+    ///     .iter()
+    ///     .find(|table| table.name == "users")
+    ///     .expect("there is a users table");
+    /// assert!(users.columns.iter().any(|column| column.name == "name"));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn load<Conn: Introspect>(conn: &mut Conn) -> QueryResult<Self> {
+        let mut tables = Vec::new();
+        for name in conn.table_names()? {
+            let columns = conn.load_columns(&name)?;
+            let foreign_keys = conn.load_foreign_keys(&name)?;
+            let indexes = conn.load_indexes(&name)?;
+            tables.push(TableDefinition {
+                name,
+                columns,
+                foreign_keys,
+                indexes,
+            });
+        }
+        Ok(InformationSchema { tables })
+    }
+}
+
+/// Checks whether a table with the given name exists
+///
+/// The table is looked up in the connection's default schema
+/// (`current_schema()` on PostgreSQL, the current database on MySQL).
+/// Views count as existing tables.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let conn = &mut establish_connection();
+/// assert!(diesel::introspect::table_exists(conn, "users")?);
+/// assert!(!diesel::introspect::table_exists(conn, "muppets")?);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn table_exists<Conn: Introspect>(conn: &mut Conn, table: &str) -> QueryResult<bool> {
+    conn.table_exists(table)
+}
+
+/// Checks whether the given table has a column with the given name
+///
+/// Returns `false` if the table itself does not exist.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let conn = &mut establish_connection();
+/// assert!(diesel::introspect::column_exists(conn, "users", "name")?);
+/// assert!(!diesel::introspect::column_exists(conn, "users", "shoe_size")?);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn column_exists<Conn: Introspect>(
+    conn: &mut Conn,
+    table: &str,
+    column: &str,
+) -> QueryResult<bool> {
+    conn.column_exists(table, column)
+}
+
+#[cfg(feature = "postgres")]
+impl Introspect for crate::pg::PgConnection {
+    fn table_exists(&mut self, table: &str) -> QueryResult<bool> {
+        exists_query(
+            self,
+            &format!(
+                "SELECT 1 FROM information_schema.tables \
+                 WHERE table_name = {} AND table_schema = current_schema()",
+                quoted_literal(table),
+            ),
+        )
+    }
+
+    fn column_exists(&mut self, table: &str, column: &str) -> QueryResult<bool> {
+        exists_query(
+            self,
+            &format!(
+                "SELECT 1 FROM information_schema.columns \
+                 WHERE table_name = {} AND column_name = {} \
+                 AND table_schema = current_schema()",
+                quoted_literal(table),
+                quoted_literal(column),
+            ),
+        )
+    }
+
+    fn table_names(&mut self) -> QueryResult<Vec<String>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::Text;
+
+        crate::select(sql::<Text>(
+            "table_name FROM information_schema.tables \
+             WHERE table_schema = current_schema() \
+             AND table_type = 'BASE TABLE' ORDER BY table_name",
+        ))
+        .load(self)
+    }
+
+    fn load_columns(&mut self, table: &str) -> QueryResult<Vec<ColumnDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::{Nullable, Text};
+
+        let rows = crate::select(sql::<(Text, Text, Text, Nullable<Text>)>(&format!(
+            "column_name, data_type, is_nullable, column_default \
+             FROM information_schema.columns \
+             WHERE table_name = {} AND table_schema = current_schema() \
+             ORDER BY ordinal_position",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, String, Option<String>)>(self)?;
+        Ok(rows
+            .into_iter()
+            .map(|(name, type_name, is_nullable, default)| ColumnDefinition {
+                name,
+                type_name,
+                is_nullable: is_nullable == "YES",
+                default,
+            })
+            .collect())
+    }
+
+    fn load_foreign_keys(&mut self, table: &str) -> QueryResult<Vec<ForeignKeyDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::Text;
+
+        let rows = crate::select(sql::<(Text, Text, Text, Text)>(&format!(
+            "tc.constraint_name, kcu.column_name, ccu.table_name, ccu.column_name \
+             FROM information_schema.table_constraints tc \
+             JOIN information_schema.key_column_usage kcu \
+                 ON tc.constraint_name = kcu.constraint_name \
+                 AND tc.table_schema = kcu.table_schema \
+             JOIN information_schema.constraint_column_usage ccu \
+                 ON tc.constraint_name = ccu.constraint_name \
+                 AND tc.table_schema = ccu.table_schema \
+             WHERE tc.constraint_type = 'FOREIGN KEY' \
+             AND tc.table_name = {} AND tc.table_schema = current_schema()",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, String, String)>(self)?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(name, column, referenced_table, referenced_column)| ForeignKeyDefinition {
+                    name: Some(name),
+                    column,
+                    referenced_table,
+                    referenced_column: Some(referenced_column),
+                },
+            )
+            .collect())
+    }
+
+    fn load_indexes(&mut self, table: &str) -> QueryResult<Vec<IndexDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::{Bool, Text};
+
+        let rows = crate::select(sql::<(Text, Text, Bool)>(&format!(
+            "i.relname, a.attname, ix.indisunique \
+             FROM pg_index ix \
+             JOIN pg_class i ON i.oid = ix.indexrelid \
+             JOIN pg_class t ON t.oid = ix.indrelid \
+             CROSS JOIN unnest(ix.indkey) WITH ORDINALITY AS k(attnum, seq) \
+             JOIN pg_attribute a \
+                 ON a.attrelid = t.oid AND a.attnum = k.attnum \
+             WHERE t.relname = {} AND t.relnamespace = \
+                 (SELECT oid FROM pg_namespace WHERE nspname = current_schema()) \
+             ORDER BY i.relname, k.seq",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, bool)>(self)?;
+        Ok(group_indexes(rows))
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl Introspect for crate::mysql::MysqlConnection {
+    fn table_exists(&mut self, table: &str) -> QueryResult<bool> {
+        exists_query(
+            self,
+            &format!(
+                "SELECT 1 FROM information_schema.tables \
+                 WHERE table_name = {} AND table_schema = DATABASE()",
+                quoted_literal(table),
+            ),
+        )
+    }
+
+    fn column_exists(&mut self, table: &str, column: &str) -> QueryResult<bool> {
+        exists_query(
+            self,
+            &format!(
+                "SELECT 1 FROM information_schema.columns \
+                 WHERE table_name = {} AND column_name = {} \
+                 AND table_schema = DATABASE()",
+                quoted_literal(table),
+                quoted_literal(column),
+            ),
+        )
+    }
+
+    fn table_names(&mut self) -> QueryResult<Vec<String>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::Text;
+
+        crate::select(sql::<Text>(
+            "table_name FROM information_schema.tables \
+             WHERE table_schema = DATABASE() \
+             AND table_type = 'BASE TABLE' ORDER BY table_name",
+        ))
+        .load(self)
+    }
+
+    fn load_columns(&mut self, table: &str) -> QueryResult<Vec<ColumnDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::{Nullable, Text};
+
+        let rows = crate::select(sql::<(Text, Text, Text, Nullable<Text>)>(&format!(
+            "column_name, data_type, is_nullable, column_default \
+             FROM information_schema.columns \
+             WHERE table_name = {} AND table_schema = DATABASE() \
+             ORDER BY ordinal_position",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, String, Option<String>)>(self)?;
+        Ok(rows
+            .into_iter()
+            .map(|(name, type_name, is_nullable, default)| ColumnDefinition {
+                name,
+                type_name,
+                is_nullable: is_nullable == "YES",
+                default,
+            })
+            .collect())
+    }
+
+    fn load_foreign_keys(&mut self, table: &str) -> QueryResult<Vec<ForeignKeyDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::Text;
+
+        let rows = crate::select(sql::<(Text, Text, Text, Text)>(&format!(
+            "constraint_name, column_name, referenced_table_name, referenced_column_name \
+             FROM information_schema.key_column_usage \
+             WHERE referenced_table_name IS NOT NULL \
+             AND table_name = {} AND table_schema = DATABASE()",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, String, String)>(self)?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(name, column, referenced_table, referenced_column)| ForeignKeyDefinition {
+                    name: Some(name),
+                    column,
+                    referenced_table,
+                    referenced_column: Some(referenced_column),
+                },
+            )
+            .collect())
+    }
+
+    fn load_indexes(&mut self, table: &str) -> QueryResult<Vec<IndexDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::{BigInt, Text};
+
+        let rows = crate::select(sql::<(Text, Text, BigInt)>(&format!(
+            "index_name, column_name, non_unique \
+             FROM information_schema.statistics \
+             WHERE table_name = {} AND table_schema = DATABASE() \
+             ORDER BY index_name, seq_in_index",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, i64)>(self)?;
+        Ok(group_indexes(rows.into_iter().map(
+            |(name, column, non_unique)| (name, column, non_unique == 0),
+        )))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Introspect for crate::sqlite::SqliteConnection {
+    fn table_exists(&mut self, table: &str) -> QueryResult<bool> {
+        exists_query(
+            self,
+            &format!(
+                "SELECT 1 FROM sqlite_master \
+                 WHERE type IN ('table', 'view') AND name = {}",
+                quoted_literal(table),
+            ),
+        )
+    }
+
+    fn column_exists(&mut self, table: &str, column: &str) -> QueryResult<bool> {
+        exists_query(
+            self,
+            &format!(
+                "SELECT 1 FROM pragma_table_info({}) WHERE name = {}",
+                quoted_literal(table),
+                quoted_literal(column),
+            ),
+        )
+    }
+
+    fn table_names(&mut self) -> QueryResult<Vec<String>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::Text;
+
+        crate::select(sql::<Text>(
+            "name FROM sqlite_master WHERE type = 'table' \
+             AND name NOT LIKE 'sqlite\\_%' ESCAPE '\\' ORDER BY name",
+        ))
+        .load(self)
+    }
+
+    fn load_columns(&mut self, table: &str) -> QueryResult<Vec<ColumnDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::{BigInt, Nullable, Text};
+
+        let rows = crate::select(sql::<(Text, Text, BigInt, Nullable<Text>)>(&format!(
+            "name, type, \"notnull\", dflt_value FROM pragma_table_info({})",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, i64, Option<String>)>(self)?;
+        Ok(rows
+            .into_iter()
+            .map(|(name, type_name, not_null, default)| ColumnDefinition {
+                name,
+                type_name,
+                is_nullable: not_null == 0,
+                default,
+            })
+            .collect())
+    }
+
+    fn load_foreign_keys(&mut self, table: &str) -> QueryResult<Vec<ForeignKeyDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::{Nullable, Text};
+
+        let rows = crate::select(sql::<(Text, Text, Nullable<Text>)>(&format!(
+            "\"table\", \"from\", \"to\" FROM pragma_foreign_key_list({})",
+            quoted_literal(table),
+        )))
+        .load::<(String, String, Option<String>)>(self)?;
+        Ok(rows
+            .into_iter()
+            .map(
+                |(referenced_table, column, referenced_column)| ForeignKeyDefinition {
+                    name: None,
+                    column,
+                    referenced_table,
+                    referenced_column,
+                },
+            )
+            .collect())
+    }
+
+    fn load_indexes(&mut self, table: &str) -> QueryResult<Vec<IndexDefinition>> {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::sql_types::{BigInt, Text};
+
+        let indexes = crate::select(sql::<(Text, BigInt)>(&format!(
+            "name, \"unique\" FROM pragma_index_list({}) ORDER BY name",
+            quoted_literal(table),
+        )))
+        .load::<(String, i64)>(self)?;
+        indexes
+            .into_iter()
+            .map(|(name, unique)| {
+                let columns = crate::select(sql::<Text>(&format!(
+                    "name FROM pragma_index_info({}) ORDER BY seqno",
+                    quoted_literal(&name),
+                )))
+                .load(self)?;
+                Ok(IndexDefinition {
+                    name,
+                    columns,
+                    is_unique: unique != 0,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+fn exists_query<Conn>(conn: &mut Conn, subquery: &str) -> QueryResult<bool>
+where
+    Conn: Connection,
+    crate::dsl::Select<
+        crate::query_builder::SelectStatement<()>,
+        crate::expression::SqlLiteral<crate::sql_types::Bool>,
+    >: crate::query_dsl::LoadQuery<Conn, bool>,
+{
+    use crate::dsl::sql;
+    use crate::prelude::*;
+    use crate::sql_types::Bool;
+
+    crate::select(sql::<Bool>(&format!("EXISTS ({})", subquery))).get_result(conn)
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql", feature = "sqlite"))]
+fn quoted_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// Groups `(index, column, unique)` rows, which are sorted by index
+/// name, into one definition per index
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn group_indexes<I>(rows: I) -> Vec<IndexDefinition>
+where
+    I: IntoIterator<Item = (String, String, bool)>,
+{
+    let mut indexes = Vec::<IndexDefinition>::new();
+    for (name, column, is_unique) in rows {
+        match indexes.last_mut() {
+            Some(index) if index.name == name => index.columns.push(column),
+            _ => indexes.push(IndexDefinition {
+                name,
+                columns: vec![column],
+                is_unique,
+            }),
+        }
+    }
+    indexes
+}
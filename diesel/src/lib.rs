@@ -151,6 +151,7 @@ pub mod expression;
 pub mod expression_methods;
 #[doc(hidden)]
 pub mod insertable;
+pub mod introspect;
 pub mod query_builder;
 pub mod query_dsl;
 pub mod query_source;
@@ -158,7 +159,11 @@ pub mod query_source;
 pub mod r2d2;
 pub mod result;
 pub mod serialize;
+pub mod soft_deletes;
+pub mod testing;
+pub mod touchable;
 pub mod upsert;
+pub mod versioning;
 #[macro_use]
 pub mod sql_types;
 pub mod migration;
@@ -191,8 +196,13 @@ pub mod dsl {
 
     #[doc(inline)]
     pub use crate::query_builder::functions::{
-        delete, insert_into, insert_or_ignore_into, replace_into, select, sql_query, update,
+        delete, insert_into, insert_or_ignore_into, replace_into, select, sql_query,
+        typed_sql_query, update,
     };
+
+    #[doc(inline)]
+    pub use crate::query_builder::derived_table::{from_subquery, DerivedTable, SubqueryField};
+    pub use crate::query_builder::values_table::{values_table, ValuesTable};
 }
 
 pub mod helper_types {
@@ -222,9 +232,19 @@ pub mod helper_types {
     /// Represents the return type of `.filter(predicate)`
     pub type Filter<Source, Predicate> = <Source as FilterDsl<Predicate>>::Output;
 
+    /// Represents the return type of `.filter_if(condition, predicate)`
+    pub type FilterIf<Source, Predicate> =
+        Filter<Source, crate::expression::maybe_predicate::MaybePredicate<Predicate>>;
+
     /// Represents the return type of `.filter(lhs.eq(rhs))`
     pub type FindBy<Source, Column, Value> = Filter<Source, Eq<Column, Value>>;
 
+    /// Represents the return type of `.after_key(key, value)`
+    pub type KeysetPaginated<Source, Column, Value> = Filter<Source, Gt<Column, Value>>;
+
+    /// Represents the return type of `.excluding_deleted(deleted_at)`
+    pub type ExcludingDeleted<Source, Column> = Filter<Source, IsNull<Column>>;
+
     /// Represents the return type of `.for_update()`
     pub type ForUpdate<Source> = <Source as LockingDsl<lock::ForUpdate>>::Output;
 
@@ -269,6 +289,9 @@ pub mod helper_types {
     pub type InnerJoinOn<Source, Rhs, On> =
         <Source as InternalJoinDsl<Rhs, joins::Inner, On>>::Output;
 
+    /// Represents the return type of `.join_on(rhs, on)`
+    pub type ExplicitJoinOn<Source, Rhs, On> = InnerJoin<Source, joins::OnClauseWrapper<Rhs, On>>;
+
     /// Represents the return type of `.left_join(rhs)`
     pub type LeftJoin<Source, Rhs> =
         <Source as JoinWithImplicitOnClause<Rhs, joins::LeftOuter>>::Output;
@@ -403,12 +426,19 @@ pub mod prelude {
     pub use crate::query_builder::DecoratableTarget;
     #[doc(inline)]
     pub use crate::query_dsl::{
-        BelongingToDsl, CombineDsl, JoinOnDsl, QueryDsl, RunQueryDsl, SaveChangesDsl,
+        BelongingToDsl, BelongingToMany, CombineDsl, JoinOnDsl, QueryDsl, RunQueryDsl,
+        SaveChangesDsl,
     };
     #[doc(inline)]
     pub use crate::query_source::{Column, JoinTo, QuerySource, Table};
     #[doc(inline)]
     pub use crate::result::{ConnectionError, ConnectionResult, OptionalExtension, QueryResult};
+    #[doc(inline)]
+    pub use crate::soft_deletes::{SoftDeletable, SoftDeleteDsl};
+    #[doc(inline)]
+    pub use crate::touchable::{TouchDsl, Touchable};
+    #[doc(inline)]
+    pub use crate::versioning::{VersionedModel, VersionedSaveDsl};
 
     pub use crate::expression::SelectableHelper;
 
@@ -428,7 +458,8 @@ pub use crate::prelude::*;
 pub use crate::query_builder::debug_query;
 #[doc(inline)]
 pub use crate::query_builder::functions::{
-    delete, insert_into, insert_or_ignore_into, replace_into, select, sql_query, update,
+    delete, insert_into, insert_or_ignore_into, replace_into, select, sql_query, typed_sql_query,
+    update,
 };
 pub use crate::result::Error::NotFound;
 
@@ -7,6 +7,7 @@ pub(crate) mod prelude {
         allow_columns_to_appear_in_same_group_by_clause,
         allow_tables_to_appear_in_same_query,
         joinable,
+        preload,
         table,
     };
 }
@@ -681,6 +682,23 @@ macro_rules! __diesel_table_impl {
                 pub fn star(&self) -> star {
                     star
                 }
+
+                #[allow(dead_code)]
+                /// Statically asserts that the SQL types of all columns of
+                /// this table are supported by the given backend
+                ///
+                /// Using a column whose SQL type is not supported by the
+                /// backend a query is executed against fails deep inside
+                /// trait resolution. Calling this function, for example as
+                /// `users::table::check_column_types_supported_by::<Sqlite>()`,
+                /// surfaces such a mismatch as a missing `HasSqlType` impl
+                /// naming the offending type instead.
+                pub fn check_column_types_supported_by<DB>()
+                where
+                    DB: $crate::backend::Backend
+                        + $crate::sql_types::HasSqlType<SqlType>,
+                {
+                }
             }
 
             /// The SQL type of all of the columns on this table
@@ -1285,6 +1303,123 @@ macro_rules! allow_columns_to_appear_in_same_group_by_clause {
     () => {};
 }
 
+/// Eagerly loads the records associated with the given parents
+///
+/// This issues one `IN` query per association level using
+/// [`belonging_to`](crate::BelongingToDsl::belonging_to()) and groups the
+/// loaded records by their parent with
+/// [`grouped_by`](crate::GroupedBy::grouped_by()), avoiding the N+1 queries
+/// of loading each parent's children separately. The foreign keys are taken
+/// from the models' [`BelongsTo`](crate::associations::BelongsTo)
+/// associations.
+///
+/// `preload!(conn, parents, Child)` evaluates to
+/// `QueryResult<Vec<(Parent, Vec<Child>)>>`. Additional types chain through
+/// the previous level, so `preload!(conn, users, Post, Comment)` loads the
+/// comments of the loaded posts and evaluates to
+/// `QueryResult<Vec<(User, Vec<(Post, Vec<Comment>)>)>>`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use schema::{comments, posts, users};
+/// #
+/// # #[derive(Identifiable, Queryable, PartialEq, Debug)]
+/// # pub struct User {
+/// #     id: i32,
+/// #     name: String,
+/// # }
+/// #
+/// # #[derive(Debug, PartialEq)]
+/// # #[derive(Identifiable, Queryable, Associations)]
+/// # #[belongs_to(User)]
+/// # pub struct Post {
+/// #     id: i32,
+/// #     user_id: i32,
+/// #     title: String,
+/// # }
+/// #
+/// # #[derive(Debug, PartialEq)]
+/// # #[derive(Identifiable, Queryable, Associations)]
+/// # #[belongs_to(Post)]
+/// # pub struct Comment {
+/// #     id: i32,
+/// #     post_id: i32,
+/// #     body: String,
+/// # }
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// let users = users::table.load::<User>(connection)?;
+/// let data = preload!(connection, users, Post, Comment)?;
+///
+/// let expected_data = vec![
+///     (
+///         User { id: 1, name: "Sean".into() },
+///         vec![
+///             (
+///                 Post { id: 1, user_id: 1, title: "My first post".into() },
+///                 vec![Comment { id: 1, post_id: 1, body: "Great post".into() }],
+///             ),
+///             (
+///                 Post { id: 2, user_id: 1, title: "About Rust".into() },
+///                 vec![Comment { id: 2, post_id: 2, body: "Yay! I am learning Rust".into() }],
+///             ),
+///         ],
+///     ),
+///     (
+///         User { id: 2, name: "Tess".into() },
+///         vec![
+///             (
+///                 Post { id: 3, user_id: 2, title: "My first post too".into() },
+///                 vec![Comment { id: 3, post_id: 3, body: "I enjoyed your post".into() }],
+///             ),
+///         ],
+///     ),
+/// ];
+///
+/// assert_eq!(expected_data, data);
+/// #     Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! preload {
+    ($conn:expr, $parents:expr, $Child:ty $(,)?) => {{
+        let parents = $parents;
+        $crate::RunQueryDsl::load::<$Child>(
+            <$Child as $crate::BelongingToDsl<_>>::belonging_to(&parents),
+            $conn,
+        )
+        .map(|children| {
+            let grouped = $crate::GroupedBy::grouped_by(children, &parents);
+            parents
+                .into_iter()
+                .zip(grouped)
+                .collect::<std::vec::Vec<_>>()
+        })
+    }};
+    ($conn:expr, $parents:expr, $Child:ty, $($Rest:ty),+ $(,)?) => {{
+        let parents = $parents;
+        $crate::RunQueryDsl::load::<$Child>(
+            <$Child as $crate::BelongingToDsl<_>>::belonging_to(&parents),
+            $conn,
+        )
+        .and_then(|children| $crate::preload!($conn, children, $($Rest),+))
+        .map(|children| {
+            let grouped = $crate::GroupedBy::grouped_by(children, &parents);
+            parents
+                .into_iter()
+                .zip(grouped)
+                .collect::<std::vec::Vec<_>>()
+        })
+    }};
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! __diesel_with_dollar_sign {
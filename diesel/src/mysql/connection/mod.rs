@@ -1,8 +1,11 @@
 mod bind;
+mod options;
 mod raw;
 mod stmt;
 mod url;
 
+pub use self::options::{MysqlCharset, MysqlConnectOptions, MysqlSslMode};
+
 use self::raw::RawConnection;
 use self::stmt::Statement;
 use self::url::ConnectionOptions;
@@ -96,7 +99,103 @@ impl Connection for MysqlConnection {
     }
 }
 
+impl StatementTimeout for MysqlConnection {
+    fn set_statement_timeout(&mut self, timeout: std::time::Duration) -> QueryResult<()> {
+        // `max_execution_time` is given in milliseconds and only applies
+        // to read only `SELECT` statements
+        self.execute(&format!(
+            "SET SESSION max_execution_time = {}",
+            timeout.as_millis(),
+        ))
+        .map(|_| ())
+    }
+}
+
 impl MysqlConnection {
+    /// Returns the value generated for an `AUTO_INCREMENT` column by the
+    /// most recent `INSERT` on this connection
+    ///
+    /// This runs `SELECT LAST_INSERT_ID()`, which is scoped to the
+    /// connection: inserts performed by other connections do not affect
+    /// the result. If no `INSERT` into a table with an `AUTO_INCREMENT`
+    /// column has been executed on this connection yet, `0` is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// diesel::insert_into(users)
+    ///     .values(name.eq("Ruby"))
+    ///     .execute(conn)?;
+    ///
+    /// let new_id: i64 = conn.last_insert_id()?;
+    /// assert_ne!(new_id, 0);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn last_insert_id<T>(&mut self) -> QueryResult<T>
+    where
+        T: FromSqlRow<crate::sql_types::BigInt, Mysql>,
+    {
+        use crate::prelude::*;
+
+        crate::select(crate::dsl::sql::<crate::sql_types::BigInt>(
+            "LAST_INSERT_ID()",
+        ))
+        .get_result(self)
+    }
+
+    /// Returns the number of rows matched by the last `UPDATE` on this
+    /// connection, regardless of whether they were actually changed
+    ///
+    /// [`execute`](crate::RunQueryDsl::execute()) reports the number of
+    /// rows MySQL *changed*, so an `UPDATE` assigning a value a row
+    /// already had is not counted there. This method reports the number
+    /// of rows the `WHERE` clause *matched* instead, which distinguishes
+    /// "no such record" from "record already had that value".
+    ///
+    /// Returns `None` if the last statement was not one that reports
+    /// matched rows.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// // "Sean" is already called Sean, so no row is changed
+    /// let changed = diesel::update(users.filter(name.eq("Sean")))
+    ///     .set(name.eq("Sean"))
+    ///     .execute(conn)?;
+    /// assert_eq!(changed, 0);
+    /// assert_eq!(conn.found_rows(), Some(1));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn found_rows(&self) -> Option<u64> {
+        let info = self.raw_connection.info()?;
+        let matched = info.strip_prefix("Rows matched: ")?;
+        let digits = matched
+            .split(' ')
+            .next()
+            .expect("`split` always yields at least one element");
+        digits.parse().ok()
+    }
+
     fn prepare_query<T>(&mut self, source: &T) -> QueryResult<MaybeCached<Statement>>
     where
         T: QueryFragment<Mysql> + QueryId,
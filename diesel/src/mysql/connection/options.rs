@@ -0,0 +1,213 @@
+extern crate mysqlclient_sys as ffi;
+
+use std::ffi::CString;
+use std::path::{Path, PathBuf};
+
+use super::raw::RawConnection;
+use super::url::ConnectionOptions;
+use super::MysqlConnection;
+use crate::connection::{AnsiTransactionManager, StatementCache};
+use crate::result::ConnectionError::CouldntSetupConfiguration;
+use crate::result::ConnectionResult;
+
+/// A builder for [`MysqlConnection`]s, allowing additional per-connection
+/// configuration
+///
+/// Constructed via [`MysqlConnection::build`](MysqlConnection::build()).
+/// The connection is established by
+/// [`establish`](MysqlConnectOptions::establish()); every configured
+/// option is applied to the C client library before the connection is
+/// attempted.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use diesel::mysql::{MysqlConnection, MysqlSslMode};
+/// #
+/// # fn main() {
+/// let conn = MysqlConnection::build("mysql://localhost/my_database")
+///     .ssl_mode(MysqlSslMode::VerifyCa)
+///     .ssl_ca("/etc/mysql/certs/ca.pem")
+///     .establish()
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "The builder does nothing unless you call `establish` on it"]
+pub struct MysqlConnectOptions {
+    database_url: String,
+    ssl_mode: Option<MysqlSslMode>,
+    ssl_ca: Option<PathBuf>,
+    ssl_cert: Option<PathBuf>,
+    ssl_key: Option<PathBuf>,
+    charset: Option<MysqlCharset>,
+}
+
+/// The character sets a connection can be switched to at connect time
+///
+/// See [`MysqlConnectOptions::charset`](MysqlConnectOptions::charset())
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MysqlCharset {
+    /// Full UTF-8, including characters outside the basic multilingual
+    /// plane. This is what nearly every application wants
+    Utf8mb4,
+    /// MySQL's legacy three-byte UTF-8 subset, which cannot store
+    /// characters outside the basic multilingual plane
+    Utf8,
+    /// ISO 8859-1, the historical MySQL default
+    Latin1,
+    /// No character set; strings are treated as raw bytes
+    Binary,
+}
+
+impl MysqlCharset {
+    fn as_str(self) -> &'static str {
+        match self {
+            MysqlCharset::Utf8mb4 => "utf8mb4",
+            MysqlCharset::Utf8 => "utf8",
+            MysqlCharset::Latin1 => "latin1",
+            MysqlCharset::Binary => "binary",
+        }
+    }
+}
+
+/// The TLS negotiation modes accepted by the MySQL client library
+///
+/// The modes differ in whether a TLS connection is attempted or
+/// required, and in how thoroughly the server's certificate is checked.
+/// Only [`VerifyCa`](MysqlSslMode::VerifyCa) and
+/// [`VerifyIdentity`](MysqlSslMode::VerifyIdentity) protect against an
+/// active man-in-the-middle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MysqlSslMode {
+    /// Never use TLS
+    Disabled,
+    /// Use TLS if the server supports it, falling back to an
+    /// unencrypted connection
+    Preferred,
+    /// Require TLS, but do not verify the server certificate
+    Required,
+    /// Require TLS and verify the server certificate is issued by a
+    /// trusted CA
+    VerifyCa,
+    /// Require TLS and additionally verify the server host name matches
+    /// the certificate
+    VerifyIdentity,
+}
+
+impl MysqlSslMode {
+    fn to_ffi(self) -> ffi::mysql_ssl_mode {
+        match self {
+            MysqlSslMode::Disabled => ffi::mysql_ssl_mode::SSL_MODE_DISABLED,
+            MysqlSslMode::Preferred => ffi::mysql_ssl_mode::SSL_MODE_PREFERRED,
+            MysqlSslMode::Required => ffi::mysql_ssl_mode::SSL_MODE_REQUIRED,
+            MysqlSslMode::VerifyCa => ffi::mysql_ssl_mode::SSL_MODE_VERIFY_CA,
+            MysqlSslMode::VerifyIdentity => ffi::mysql_ssl_mode::SSL_MODE_VERIFY_IDENTITY,
+        }
+    }
+}
+
+impl MysqlConnection {
+    /// Builds a connection to the given database URL, specifying
+    /// additional configuration to apply on connect
+    ///
+    /// See [`MysqlConnectOptions`] for the available options.
+    pub fn build(database_url: &str) -> MysqlConnectOptions {
+        MysqlConnectOptions {
+            database_url: database_url.to_owned(),
+            ssl_mode: None,
+            ssl_ca: None,
+            ssl_cert: None,
+            ssl_key: None,
+            charset: None,
+        }
+    }
+}
+
+impl MysqlConnectOptions {
+    /// Sets how TLS is negotiated for the connection
+    ///
+    /// This sets the `MYSQL_OPT_SSL_MODE` option on the underlying C
+    /// client library. Establishing the connection fails if the client
+    /// library is too old to support the option.
+    pub fn ssl_mode(mut self, mode: MysqlSslMode) -> Self {
+        self.ssl_mode = Some(mode);
+        self
+    }
+
+    /// Sets the certificate authority used to verify the server's
+    /// certificate
+    ///
+    /// The file should contain the certificate of the CA which issued
+    /// the server certificate, in PEM format. Required for
+    /// [`MysqlSslMode::VerifyCa`] and [`MysqlSslMode::VerifyIdentity`].
+    pub fn ssl_ca(mut self, path: impl AsRef<Path>) -> Self {
+        self.ssl_ca = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the client certificate used to authenticate to the server
+    ///
+    /// The file should contain the certificate in PEM format. The
+    /// matching private key must be given via
+    /// [`ssl_key`](MysqlConnectOptions::ssl_key()).
+    pub fn ssl_cert(mut self, path: impl AsRef<Path>) -> Self {
+        self.ssl_cert = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the private key for the client certificate
+    pub fn ssl_key(mut self, path: impl AsRef<Path>) -> Self {
+        self.ssl_key = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the character set used for the connection
+    ///
+    /// This calls `mysql_set_character_set` once the connection is
+    /// established, which updates both the session character set (as
+    /// `SET NAMES` would) and the character set the C client library
+    /// uses for escaping. Diesel already configures new connections for
+    /// `utf8mb4`, so this is only needed to pick a different character
+    /// set, e.g. [`MysqlCharset::Latin1`] for legacy databases.
+    pub fn charset(mut self, charset: MysqlCharset) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
+    /// Establishes the connection with the configured options applied
+    pub fn establish(self) -> ConnectionResult<MysqlConnection> {
+        let raw_connection = RawConnection::new();
+        let connection_options = ConnectionOptions::parse(&self.database_url)?;
+        let ssl_ca = cstring_from_path(self.ssl_ca.as_deref())?;
+        let ssl_cert = cstring_from_path(self.ssl_cert.as_deref())?;
+        let ssl_key = cstring_from_path(self.ssl_key.as_deref())?;
+        if ssl_ca.is_some() || ssl_cert.is_some() || ssl_key.is_some() {
+            raw_connection.set_ssl(ssl_key.as_deref(), ssl_cert.as_deref(), ssl_ca.as_deref());
+        }
+        if let Some(mode) = self.ssl_mode {
+            raw_connection.set_ssl_mode(mode.to_ffi())?;
+        }
+        raw_connection.connect(&connection_options)?;
+        let mut conn = MysqlConnection {
+            raw_connection,
+            transaction_state: AnsiTransactionManager::default(),
+            statement_cache: StatementCache::new(),
+        };
+        conn.set_config_options()
+            .map_err(CouldntSetupConfiguration)?;
+        if let Some(charset) = self.charset {
+            let charset = CString::new(charset.as_str())?;
+            conn.raw_connection.set_character_set(&charset)?;
+        }
+        Ok(conn)
+    }
+}
+
+fn cstring_from_path(path: Option<&Path>) -> ConnectionResult<Option<CString>> {
+    path.map(|path| CString::new(path.to_string_lossy().as_bytes()).map_err(Into::into))
+        .transpose()
+}
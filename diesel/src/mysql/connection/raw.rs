@@ -75,6 +75,50 @@ impl RawConnection {
         }
     }
 
+    pub fn set_ssl(&self, key: Option<&CStr>, cert: Option<&CStr>, ca: Option<&CStr>) {
+        unsafe {
+            // This only stores the given paths; errors surface when
+            // `mysql_real_connect` attempts the TLS handshake
+            ffi::mysql_ssl_set(
+                self.0.as_ptr(),
+                key.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                cert.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                ca.map(CStr::as_ptr).unwrap_or_else(ptr::null),
+                ptr::null(),
+                ptr::null(),
+            );
+        }
+    }
+
+    pub fn set_ssl_mode(&self, ssl_mode: ffi::mysql_ssl_mode) -> ConnectionResult<()> {
+        let ssl_mode = ssl_mode as libc::c_uint;
+        let result = unsafe {
+            ffi::mysql_options(
+                self.0.as_ptr(),
+                ffi::mysql_option::MYSQL_OPT_SSL_MODE,
+                &ssl_mode as *const libc::c_uint as *const libc::c_void,
+            )
+        };
+        if result == 0 {
+            Ok(())
+        } else {
+            // This is only non-zero for unrecognized options, i.e. when
+            // the client library predates `MYSQL_OPT_SSL_MODE`
+            Err(ConnectionError::BadConnection(
+                "The MySQL client library in use does not support MYSQL_OPT_SSL_MODE".into(),
+            ))
+        }
+    }
+
+    pub fn set_character_set(&self, charset: &CStr) -> ConnectionResult<()> {
+        let result = unsafe { ffi::mysql_set_character_set(self.0.as_ptr(), charset.as_ptr()) };
+        if result == 0 {
+            Ok(())
+        } else {
+            Err(ConnectionError::BadConnection(self.last_error_message()))
+        }
+    }
+
     pub fn last_error_message(&self) -> String {
         unsafe { CStr::from_ptr(ffi::mysql_error(self.0.as_ptr())) }
             .to_string_lossy()
@@ -125,6 +169,19 @@ impl RawConnection {
         affected_rows as usize
     }
 
+    pub fn info(&self) -> Option<String> {
+        let info = unsafe { ffi::mysql_info(self.0.as_ptr()) };
+        if info.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(info) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
     pub fn prepare(&self, query: &str) -> QueryResult<Statement> {
         let stmt = unsafe { ffi::mysql_stmt_init(self.0.as_ptr()) };
         // It is documented that the only reason `mysql_stmt_init` will fail
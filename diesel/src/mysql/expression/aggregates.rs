@@ -0,0 +1,111 @@
+use crate::expression::{is_aggregate, AsExpression, Expression, ValidGrouping};
+use crate::mysql::Mysql;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{Nullable, Text};
+use crate::{AppearsOnTable, SelectableExpression};
+
+/// Creates a MySQL `GROUP_CONCAT` expression
+///
+/// Concatenates all values of the given text expression, separated by the
+/// given delimiter. As no rows result in a `NULL` value instead of an empty
+/// string, the return type is nullable.
+///
+/// MySQL does not allow the separator to be given as a bind parameter, so
+/// the delimiter is embedded into the generated SQL as an escaped string
+/// literal. Quotes and backslashes in the delimiter are escaped assuming
+/// the default `sql_mode`; if `NO_BACKSLASH_ESCAPES` is enabled, a
+/// delimiter containing a backslash is rendered as two backslashes
+/// instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # #[cfg(feature = "mysql")]
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     use diesel::dsl::group_concat;
+/// #     let connection = &mut establish_connection();
+/// let species_list = animals
+///     .select(group_concat(species, ", "))
+///     .first::<Option<String>>(connection)?;
+/// assert_eq!(Some("dog, spider".to_owned()), species_list);
+/// #     Ok(())
+/// # }
+/// #
+/// # #[cfg(not(feature = "mysql"))]
+/// # fn run_test() -> QueryResult<()> {
+/// #     Ok(())
+/// # }
+/// ```
+pub fn group_concat<E>(expr: E, delimiter: &str) -> GroupConcat<E::Expression>
+where
+    E: AsExpression<Text>,
+{
+    GroupConcat {
+        expr: expr.as_expression(),
+        delimiter: delimiter.to_owned(),
+    }
+}
+
+/// The return type of [`group_concat(expr, delimiter)`](group_concat())
+#[derive(Debug, Clone)]
+pub struct GroupConcat<E> {
+    expr: E,
+    delimiter: String,
+}
+
+impl<E> QueryId for GroupConcat<E> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<E> Expression for GroupConcat<E>
+where
+    E: Expression,
+{
+    type SqlType = Nullable<Text>;
+}
+
+impl<E, GB> ValidGrouping<GB> for GroupConcat<E> {
+    type IsAggregate = is_aggregate::Yes;
+}
+
+impl<E, QS> SelectableExpression<QS> for GroupConcat<E>
+where
+    Self: AppearsOnTable<QS>,
+    E: SelectableExpression<QS>,
+{
+}
+
+impl<E, QS> AppearsOnTable<QS> for GroupConcat<E>
+where
+    Self: Expression,
+    E: AppearsOnTable<QS>,
+{
+}
+
+impl<E> QueryFragment<Mysql> for GroupConcat<E>
+where
+    E: QueryFragment<Mysql>,
+{
+    fn walk_ast(&self, mut out: AstPass<Mysql>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("GROUP_CONCAT(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(" SEPARATOR '");
+        // Backslash is an escape character under MySQL's default `sql_mode`,
+        // so it must be escaped as well or a delimiter ending in `\` would
+        // escape the closing quote
+        out.push_sql(&self.delimiter.replace('\\', "\\\\").replace('\'', "''"));
+        out.push_sql("')");
+        Ok(())
+    }
+}
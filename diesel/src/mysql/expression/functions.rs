@@ -0,0 +1,8 @@
+use crate::expression::functions::sql_function;
+use crate::sql_types::*;
+
+sql_function! {
+    /// Returns whether the given text expression matches the regular
+    /// expression `pattern`. Requires MySQL 8+.
+    fn regexp_like(expr: Text, pattern: Text) -> Bool;
+}
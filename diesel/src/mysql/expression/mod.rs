@@ -0,0 +1,20 @@
+//! MySQL related query builder extensions
+//!
+//! Everything in this module is re-exported from database agnostic locations.
+//! You should rely on the re-exports rather than this module directly. It is
+//! kept separate purely for documentation purposes.
+
+pub(crate) mod aggregates;
+pub(crate) mod functions;
+
+/// MySQL specific expression DSL methods.
+///
+/// This module will be glob imported by
+/// [`diesel::dsl`](crate::dsl) when compiled with the `feature =
+/// "mysql"` flag.
+pub mod dsl {
+    #[doc(inline)]
+    pub use super::aggregates::group_concat;
+    #[doc(inline)]
+    pub use super::functions::regexp_like;
+}
@@ -6,12 +6,13 @@
 
 mod backend;
 mod connection;
+pub(crate) mod expression;
 mod value;
 
 mod query_builder;
 pub mod types;
 
 pub use self::backend::{Mysql, MysqlType};
-pub use self::connection::MysqlConnection;
+pub use self::connection::{MysqlCharset, MysqlConnectOptions, MysqlConnection, MysqlSslMode};
 pub use self::query_builder::MysqlQueryBuilder;
 pub use self::value::{MysqlValue, NumericRepresentation};
@@ -1,5 +1,5 @@
 use super::backend::Mysql;
-use crate::query_builder::QueryBuilder;
+use crate::query_builder::{QueryBuilder, QUERY_BUILDER_INITIAL_CAPACITY};
 use crate::result::QueryResult;
 
 mod limit_offset;
@@ -14,8 +14,13 @@ pub struct MysqlQueryBuilder {
 
 impl MysqlQueryBuilder {
     /// Constructs a new query builder with an empty query
+    ///
+    /// The internal buffer is pre-sized to avoid repeated reallocation
+    /// while the query is built up.
     pub fn new() -> Self {
-        MysqlQueryBuilder::default()
+        MysqlQueryBuilder {
+            sql: String::with_capacity(QUERY_BUILDER_INITIAL_CAPACITY),
+        }
     }
 }
 
@@ -2,39 +2,51 @@ use chrono::*;
 use mysqlclient_sys as ffi;
 use std::io::Write;
 use std::os::raw as libc;
-use std::{mem, slice};
 
-use super::MYSQL_TIME;
+use super::super::MYSQL_TIME;
 use crate::deserialize::{self, FromSql};
 use crate::mysql::{Mysql, MysqlValue};
-use crate::serialize::{self, IsNull, Output, ToSql};
-use crate::sql_types::{Date, Datetime, Time, Timestamp};
-
-macro_rules! mysql_time_impls {
-    ($ty:ty) => {
-        impl ToSql<$ty, Mysql> for MYSQL_TIME {
-            fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
-                let bytes = unsafe {
-                    let bytes_ptr = self as *const MYSQL_TIME as *const u8;
-                    slice::from_raw_parts(bytes_ptr, mem::size_of::<MYSQL_TIME>())
-                };
-                out.write_all(bytes)?;
-                Ok(IsNull::No)
-            }
-        }
-
-        impl FromSql<$ty, Mysql> for MYSQL_TIME {
-            fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
-                value.time_value()
-            }
-        }
-    };
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::{Date, Datetime, Time, Timestamp, Timestamptz};
+
+impl ToSql<Timestamptz, Mysql> for NaiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        <NaiveDateTime as ToSql<Timestamp, Mysql>>::to_sql(self, out)
+    }
+}
+
+impl FromSql<Timestamptz, Mysql> for NaiveDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        <NaiveDateTime as FromSql<Timestamp, Mysql>>::from_sql(bytes)
+    }
+}
+
+impl<TZ: TimeZone> ToSql<Timestamptz, Mysql> for DateTime<TZ> {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        <NaiveDateTime as ToSql<Timestamptz, Mysql>>::to_sql(&self.naive_utc(), out)
+    }
+}
+
+impl FromSql<Timestamptz, Mysql> for DateTime<Utc> {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let naive_date_time = <NaiveDateTime as FromSql<Timestamptz, Mysql>>::from_sql(bytes)?;
+        Ok(DateTime::from_utc(naive_date_time, Utc))
+    }
+}
+
+impl FromSql<Timestamptz, Mysql> for DateTime<Local> {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let naive_date_time = <NaiveDateTime as FromSql<Timestamptz, Mysql>>::from_sql(bytes)?;
+        Ok(Local::from_utc_datetime(&Local, &naive_date_time))
+    }
 }
 
-mysql_time_impls!(Datetime);
-mysql_time_impls!(Timestamp);
-mysql_time_impls!(Time);
-mysql_time_impls!(Date);
+impl FromSql<Timestamptz, Mysql> for DateTime<FixedOffset> {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let naive_date_time = <NaiveDateTime as FromSql<Timestamptz, Mysql>>::from_sql(bytes)?;
+        Ok(FixedOffset::east(0).from_utc_datetime(&naive_date_time))
+    }
+}
 
 impl ToSql<Datetime, Mysql> for NaiveDateTime {
     fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
@@ -0,0 +1,151 @@
+extern crate jiff;
+
+use self::jiff::civil::{Date as CivilDate, DateTime as CivilDateTime, Time as CivilTime};
+use self::jiff::tz::Offset;
+use self::jiff::Timestamp as JiffTimestamp;
+use mysqlclient_sys as ffi;
+use std::io::Write;
+use std::os::raw as libc;
+
+use super::super::MYSQL_TIME;
+use crate::deserialize::{self, FromSql};
+use crate::mysql::{Mysql, MysqlValue};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::{Date, Datetime, Time, Timestamp, Timestamptz};
+
+impl ToSql<Timestamp, Mysql> for CivilDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let mysql_time = MYSQL_TIME {
+            year: self.year() as libc::c_uint,
+            month: self.month() as libc::c_uint,
+            day: self.day() as libc::c_uint,
+            hour: self.hour() as libc::c_uint,
+            minute: self.minute() as libc::c_uint,
+            second: self.second() as libc::c_uint,
+            second_part: (self.subsec_nanosecond() / 1_000) as libc::c_ulong,
+            neg: false,
+            time_type: ffi::enum_mysql_timestamp_type::MYSQL_TIMESTAMP_DATETIME,
+            time_zone_displacement: 0,
+        };
+
+        <MYSQL_TIME as ToSql<Timestamp, Mysql>>::to_sql(&mysql_time, out)
+    }
+}
+
+impl FromSql<Timestamp, Mysql> for CivilDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let mysql_time = <MYSQL_TIME as FromSql<Timestamp, Mysql>>::from_sql(bytes)?;
+
+        CivilDateTime::new(
+            mysql_time.year as i16,
+            mysql_time.month as i8,
+            mysql_time.day as i8,
+            mysql_time.hour as i8,
+            mysql_time.minute as i8,
+            mysql_time.second as i8,
+            (mysql_time.second_part * 1_000) as i32,
+        )
+        .map_err(|_| format!("Cannot parse this date: {:?}", mysql_time).into())
+    }
+}
+
+impl ToSql<Datetime, Mysql> for CivilDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        <CivilDateTime as ToSql<Timestamp, Mysql>>::to_sql(self, out)
+    }
+}
+
+impl FromSql<Datetime, Mysql> for CivilDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        <CivilDateTime as FromSql<Timestamp, Mysql>>::from_sql(bytes)
+    }
+}
+
+impl ToSql<Timestamptz, Mysql> for CivilDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        <CivilDateTime as ToSql<Timestamp, Mysql>>::to_sql(self, out)
+    }
+}
+
+impl FromSql<Timestamptz, Mysql> for CivilDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        <CivilDateTime as FromSql<Timestamp, Mysql>>::from_sql(bytes)
+    }
+}
+
+impl ToSql<Timestamptz, Mysql> for JiffTimestamp {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let datetime = Offset::UTC.to_datetime(*self);
+        <CivilDateTime as ToSql<Timestamptz, Mysql>>::to_sql(&datetime, out)
+    }
+}
+
+impl FromSql<Timestamptz, Mysql> for JiffTimestamp {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let datetime = <CivilDateTime as FromSql<Timestamptz, Mysql>>::from_sql(bytes)?;
+        Offset::UTC.to_timestamp(datetime).map_err(Into::into)
+    }
+}
+
+impl ToSql<Time, Mysql> for CivilTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let mysql_time = MYSQL_TIME {
+            hour: self.hour() as libc::c_uint,
+            minute: self.minute() as libc::c_uint,
+            second: self.second() as libc::c_uint,
+            day: 0,
+            month: 0,
+            second_part: 0,
+            year: 0,
+            neg: false,
+            time_type: ffi::enum_mysql_timestamp_type::MYSQL_TIMESTAMP_TIME,
+            time_zone_displacement: 0,
+        };
+
+        <MYSQL_TIME as ToSql<Time, Mysql>>::to_sql(&mysql_time, out)
+    }
+}
+
+impl FromSql<Time, Mysql> for CivilTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let mysql_time = <MYSQL_TIME as FromSql<Time, Mysql>>::from_sql(bytes)?;
+        CivilTime::new(
+            mysql_time.hour as i8,
+            mysql_time.minute as i8,
+            mysql_time.second as i8,
+            0,
+        )
+        .map_err(|_| format!("Unable to convert {:?} to time", mysql_time).into())
+    }
+}
+
+impl ToSql<Date, Mysql> for CivilDate {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let mysql_time = MYSQL_TIME {
+            year: self.year() as libc::c_uint,
+            month: self.month() as libc::c_uint,
+            day: self.day() as libc::c_uint,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            second_part: 0,
+            neg: false,
+            time_type: ffi::enum_mysql_timestamp_type::MYSQL_TIMESTAMP_DATE,
+            time_zone_displacement: 0,
+        };
+
+        <MYSQL_TIME as ToSql<Date, Mysql>>::to_sql(&mysql_time, out)
+    }
+}
+
+impl FromSql<Date, Mysql> for CivilDate {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let mysql_time = <MYSQL_TIME as FromSql<Date, Mysql>>::from_sql(bytes)?;
+        CivilDate::new(
+            mysql_time.year as i16,
+            mysql_time.month as i8,
+            mysql_time.day as i8,
+        )
+        .map_err(|_| format!("Unable to convert {:?} to date", mysql_time).into())
+    }
+}
@@ -0,0 +1,41 @@
+use std::io::Write;
+use std::{mem, slice};
+
+use super::MYSQL_TIME;
+use crate::deserialize::{self, FromSql};
+use crate::mysql::{Mysql, MysqlValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::{Date, Datetime, Time, Timestamp};
+
+#[cfg(feature = "chrono")]
+mod chrono;
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "time")]
+mod time;
+
+macro_rules! mysql_time_impls {
+    ($ty:ty) => {
+        impl ToSql<$ty, Mysql> for MYSQL_TIME {
+            fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+                let bytes = unsafe {
+                    let bytes_ptr = self as *const MYSQL_TIME as *const u8;
+                    slice::from_raw_parts(bytes_ptr, mem::size_of::<MYSQL_TIME>())
+                };
+                out.write_all(bytes)?;
+                Ok(IsNull::No)
+            }
+        }
+
+        impl FromSql<$ty, Mysql> for MYSQL_TIME {
+            fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+                value.time_value()
+            }
+        }
+    };
+}
+
+mysql_time_impls!(Datetime);
+mysql_time_impls!(Timestamp);
+mysql_time_impls!(Time);
+mysql_time_impls!(Date);
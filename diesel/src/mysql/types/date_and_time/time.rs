@@ -0,0 +1,165 @@
+extern crate time;
+
+use self::time::{
+    Date as NaiveDate, Month, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime, UtcOffset,
+};
+use mysqlclient_sys as ffi;
+use std::convert::TryFrom;
+use std::io::Write;
+use std::os::raw as libc;
+
+use super::super::MYSQL_TIME;
+use crate::deserialize::{self, FromSql};
+use crate::mysql::{Mysql, MysqlValue};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::{Date, Datetime, Time, Timestamp, Timestamptz};
+
+impl ToSql<Timestamp, Mysql> for PrimitiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let mysql_time = MYSQL_TIME {
+            year: self.year() as libc::c_uint,
+            month: u8::from(self.month()) as libc::c_uint,
+            day: self.day() as libc::c_uint,
+            hour: self.hour() as libc::c_uint,
+            minute: self.minute() as libc::c_uint,
+            second: self.second() as libc::c_uint,
+            second_part: libc::c_ulong::from(self.microsecond()),
+            neg: false,
+            time_type: ffi::enum_mysql_timestamp_type::MYSQL_TIMESTAMP_DATETIME,
+            time_zone_displacement: 0,
+        };
+
+        <MYSQL_TIME as ToSql<Timestamp, Mysql>>::to_sql(&mysql_time, out)
+    }
+}
+
+impl FromSql<Timestamp, Mysql> for PrimitiveDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let mysql_time = <MYSQL_TIME as FromSql<Timestamp, Mysql>>::from_sql(bytes)?;
+
+        Month::try_from(mysql_time.month as u8)
+            .map_err(Into::into)
+            .and_then(|month| {
+                NaiveDate::from_calendar_date(mysql_time.year as i32, month, mysql_time.day as u8)
+                    .map_err(Into::into)
+            })
+            .and_then(|date| {
+                NaiveTime::from_hms_micro(
+                    mysql_time.hour as u8,
+                    mysql_time.minute as u8,
+                    mysql_time.second as u8,
+                    mysql_time.second_part as u32,
+                )
+                .map(|time| PrimitiveDateTime::new(date, time))
+                .map_err(Into::into)
+            })
+            .map_err(|_: Box<dyn std::error::Error + Send + Sync>| {
+                format!("Cannot parse this date: {:?}", mysql_time).into()
+            })
+    }
+}
+
+impl ToSql<Datetime, Mysql> for PrimitiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        <PrimitiveDateTime as ToSql<Timestamp, Mysql>>::to_sql(self, out)
+    }
+}
+
+impl FromSql<Datetime, Mysql> for PrimitiveDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        <PrimitiveDateTime as FromSql<Timestamp, Mysql>>::from_sql(bytes)
+    }
+}
+
+impl ToSql<Timestamptz, Mysql> for PrimitiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        <PrimitiveDateTime as ToSql<Timestamp, Mysql>>::to_sql(self, out)
+    }
+}
+
+impl FromSql<Timestamptz, Mysql> for PrimitiveDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        <PrimitiveDateTime as FromSql<Timestamp, Mysql>>::from_sql(bytes)
+    }
+}
+
+impl ToSql<Timestamptz, Mysql> for OffsetDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let utc = self.to_offset(UtcOffset::UTC);
+        let primitive_date_time = PrimitiveDateTime::new(utc.date(), utc.time());
+        <PrimitiveDateTime as ToSql<Timestamptz, Mysql>>::to_sql(&primitive_date_time, out)
+    }
+}
+
+impl FromSql<Timestamptz, Mysql> for OffsetDateTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let primitive_date_time =
+            <PrimitiveDateTime as FromSql<Timestamptz, Mysql>>::from_sql(bytes)?;
+        Ok(primitive_date_time.assume_utc())
+    }
+}
+
+impl ToSql<Time, Mysql> for NaiveTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let mysql_time = MYSQL_TIME {
+            hour: self.hour() as libc::c_uint,
+            minute: self.minute() as libc::c_uint,
+            second: self.second() as libc::c_uint,
+            day: 0,
+            month: 0,
+            second_part: 0,
+            year: 0,
+            neg: false,
+            time_type: ffi::enum_mysql_timestamp_type::MYSQL_TIMESTAMP_TIME,
+            time_zone_displacement: 0,
+        };
+
+        <MYSQL_TIME as ToSql<Time, Mysql>>::to_sql(&mysql_time, out)
+    }
+}
+
+impl FromSql<Time, Mysql> for NaiveTime {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let mysql_time = <MYSQL_TIME as FromSql<Time, Mysql>>::from_sql(bytes)?;
+        NaiveTime::from_hms(
+            mysql_time.hour as u8,
+            mysql_time.minute as u8,
+            mysql_time.second as u8,
+        )
+        .map_err(|_| format!("Unable to convert {:?} to time", mysql_time).into())
+    }
+}
+
+impl ToSql<Date, Mysql> for NaiveDate {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        let mysql_time = MYSQL_TIME {
+            year: self.year() as libc::c_uint,
+            month: u8::from(self.month()) as libc::c_uint,
+            day: self.day() as libc::c_uint,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            second_part: 0,
+            neg: false,
+            time_type: ffi::enum_mysql_timestamp_type::MYSQL_TIMESTAMP_DATE,
+            time_zone_displacement: 0,
+        };
+
+        <MYSQL_TIME as ToSql<Date, Mysql>>::to_sql(&mysql_time, out)
+    }
+}
+
+impl FromSql<Date, Mysql> for NaiveDate {
+    fn from_sql(bytes: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let mysql_time = <MYSQL_TIME as FromSql<Date, Mysql>>::from_sql(bytes)?;
+        Month::try_from(mysql_time.month as u8)
+            .map_err(Into::into)
+            .and_then(|month| {
+                NaiveDate::from_calendar_date(mysql_time.year as i32, month, mysql_time.day as u8)
+                    .map_err(Into::into)
+            })
+            .map_err(|_: Box<dyn std::error::Error + Send + Sync>| {
+                format!("Unable to convert {:?} to time", mysql_time).into()
+            })
+    }
+}
@@ -1,11 +1,12 @@
 //! MySQL specific types
 
-#[cfg(feature = "chrono")]
 mod date_and_time;
 #[cfg(feature = "serde_json")]
 mod json;
 mod numeric;
 mod primitives;
+#[cfg(feature = "uuid")]
+mod uuid;
 
 use byteorder::WriteBytesExt;
 use mysqlclient_sys as ffi;
@@ -35,3 +35,45 @@ pub mod bigdecimal {
         }
     }
 }
+
+#[cfg(feature = "rust_decimal")]
+pub mod rust_decimal {
+    use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal::Decimal;
+    use std::io::prelude::*;
+    use std::str::{self, FromStr};
+
+    use crate::deserialize::{self, FromSql};
+    use crate::mysql::{Mysql, MysqlValue, NumericRepresentation};
+    use crate::serialize::{self, IsNull, Output, ToSql};
+    use crate::sql_types::Numeric;
+
+    impl ToSql<Numeric, Mysql> for Decimal {
+        fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+            write!(out, "{}", *self)
+                .map(|_| IsNull::No)
+                .map_err(Into::into)
+        }
+    }
+
+    impl FromSql<Numeric, Mysql> for Decimal {
+        fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+            match value.numeric_value()? {
+                NumericRepresentation::Tiny(x) => Ok(x.into()),
+                NumericRepresentation::Small(x) => Ok(x.into()),
+                NumericRepresentation::Medium(x) => Ok(x.into()),
+                NumericRepresentation::Big(x) => Ok(x.into()),
+                NumericRepresentation::Float(x) => Decimal::from_f32(x)
+                    .ok_or_else(|| format!("{} is not valid decimal number ", x).into()),
+                NumericRepresentation::Double(x) => Decimal::from_f64(x)
+                    .ok_or_else(|| format!("{} is not valid decimal number ", x).into()),
+                NumericRepresentation::Decimal(bytes) => str::from_utf8(bytes)
+                    .map_err(Into::into)
+                    .and_then(|s| Decimal::from_str(s).map_err(Into::into))
+                    .map_err(|_: Box<dyn std::error::Error + Send + Sync>| {
+                        format!("{:?} is not valid decimal number ", bytes).into()
+                    }),
+            }
+        }
+    }
+}
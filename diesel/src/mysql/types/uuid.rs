@@ -0,0 +1,75 @@
+use std::io::prelude::*;
+use std::str;
+
+use crate::deserialize::{self, FromSql};
+use crate::mysql::{Mysql, MysqlValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::Uuid;
+
+impl FromSql<Uuid, Mysql> for uuid::Uuid {
+    fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+        let bytes = value.as_bytes();
+        if bytes.len() == 16 {
+            // `BINARY(16)` storage
+            uuid::Uuid::from_slice(bytes).map_err(Into::into)
+        } else {
+            // `CHAR(36)` (or any other textual) storage
+            let text = str::from_utf8(bytes)?;
+            uuid::Uuid::parse_str(text).map_err(Into::into)
+        }
+    }
+}
+
+impl ToSql<Uuid, Mysql> for uuid::Uuid {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Mysql>) -> serialize::Result {
+        out.write_all(self.as_bytes())
+            .map(|_| IsNull::No)
+            .map_err(Into::into)
+    }
+}
+
+#[test]
+fn uuid_to_sql() {
+    let mut bytes = Output::test();
+    let test_uuid = uuid::Uuid::from_fields(0xFFFF_FFFF, 0xFFFF, 0xFFFF, b"abcdef12").unwrap();
+    ToSql::<Uuid, Mysql>::to_sql(&test_uuid, &mut bytes).unwrap();
+    assert_eq!(bytes, test_uuid.as_bytes());
+}
+
+#[test]
+fn binary_uuid_from_sql() {
+    use crate::mysql::MysqlType;
+    let input_uuid = uuid::Uuid::from_fields(0xFFFF_FFFF, 0xFFFF, 0xFFFF, b"abcdef12").unwrap();
+    let output_uuid =
+        FromSql::<Uuid, Mysql>::from_sql(MysqlValue::new(input_uuid.as_bytes(), MysqlType::Blob))
+            .unwrap();
+    assert_eq!(input_uuid, output_uuid);
+}
+
+#[test]
+fn text_uuid_from_sql() {
+    use crate::mysql::MysqlType;
+    let input_uuid = uuid::Uuid::from_fields(0xFFFF_FFFF, 0xFFFF, 0xFFFF, b"abcdef12").unwrap();
+    let text = input_uuid.to_string();
+    let output_uuid =
+        FromSql::<Uuid, Mysql>::from_sql(MysqlValue::new(text.as_bytes(), MysqlType::String))
+            .unwrap();
+    assert_eq!(input_uuid, output_uuid);
+}
+
+#[test]
+fn bad_uuid_from_sql() {
+    use crate::mysql::MysqlType;
+    let uuid =
+        <uuid::Uuid as FromSql<Uuid, Mysql>>::from_sql(MysqlValue::new(b"boom", MysqlType::Blob));
+    assert!(uuid.is_err());
+}
+
+#[test]
+fn no_uuid_from_sql() {
+    let uuid = <uuid::Uuid as FromSql<Uuid, Mysql>>::from_nullable_sql(None);
+    assert_eq!(
+        uuid.unwrap_err().to_string(),
+        "Unexpected null for non-null column"
+    );
+}
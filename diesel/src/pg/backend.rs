@@ -102,3 +102,4 @@ impl SupportsOnConflictClause for Pg {}
 impl SupportsOnConflictTargetDecorations for Pg {}
 impl SupportsDefaultKeyword for Pg {}
 impl UsesAnsiSavepointSyntax for Pg {}
+impl UsesAnsiConcatSyntax for Pg {}
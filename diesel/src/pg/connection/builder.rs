@@ -0,0 +1,297 @@
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use super::PgConnection;
+use crate::connection::Connection;
+use crate::result::ConnectionError::CouldntSetupConfiguration;
+use crate::result::ConnectionResult;
+
+/// A builder for [`PgConnection`]s, allowing additional per-connection
+/// configuration
+///
+/// Constructed via [`PgConnection::build`](PgConnection::build()). The
+/// connection is established by [`establish`](PgConnectionBuilder::establish());
+/// every configured option is applied before the connection is returned,
+/// so queries never run without them.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// # use std::time::Duration;
+/// #
+/// # fn main() {
+/// #     let database_url = database_url_from_env("PG_DATABASE_URL");
+/// let conn = PgConnection::build(&database_url)
+///     .idle_in_transaction_timeout(Duration::from_secs(60))
+///     .establish()
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "The builder does nothing unless you call `establish` on it"]
+pub struct PgConnectionBuilder {
+    database_url: String,
+    application_name: Option<String>,
+    idle_in_transaction_timeout: Option<Duration>,
+    search_path: Option<Vec<String>>,
+    ssl_mode: Option<SslMode>,
+    ssl_root_cert: Option<PathBuf>,
+    ssl_client_cert: Option<(PathBuf, PathBuf)>,
+}
+
+/// The SSL negotiation modes accepted by libpq's `sslmode` parameter
+///
+/// The modes differ in whether an SSL connection is attempted or
+/// required, and in how thoroughly the server's certificate is checked.
+/// Only [`VerifyCa`](SslMode::VerifyCa) and
+/// [`VerifyFull`](SslMode::VerifyFull) protect against an active
+/// man-in-the-middle; see [the PostgreSQL documentation] for the full
+/// comparison.
+///
+/// [the PostgreSQL documentation]: https://www.postgresql.org/docs/current/libpq-ssl.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SslMode {
+    /// Never use SSL
+    Disable,
+    /// Try a non-SSL connection first, falling back to SSL
+    Allow,
+    /// Try an SSL connection first, falling back to non-SSL
+    Prefer,
+    /// Require SSL, but do not verify the server certificate
+    Require,
+    /// Require SSL and verify the server certificate is issued by a
+    /// trusted CA
+    VerifyCa,
+    /// Require SSL and additionally verify the server host name matches
+    /// the certificate
+    VerifyFull,
+}
+
+impl SslMode {
+    fn as_str(&self) -> &'static str {
+        match *self {
+            SslMode::Disable => "disable",
+            SslMode::Allow => "allow",
+            SslMode::Prefer => "prefer",
+            SslMode::Require => "require",
+            SslMode::VerifyCa => "verify-ca",
+            SslMode::VerifyFull => "verify-full",
+        }
+    }
+}
+
+impl PgConnection {
+    /// Builds a connection to the given database URL, specifying
+    /// additional configuration to apply on connect
+    ///
+    /// See [`PgConnectionBuilder`] for the available options.
+    pub fn build(database_url: &str) -> PgConnectionBuilder {
+        PgConnectionBuilder {
+            database_url: database_url.to_owned(),
+            application_name: None,
+            idle_in_transaction_timeout: None,
+            search_path: None,
+            ssl_mode: None,
+            ssl_root_cert: None,
+            ssl_client_cert: None,
+        }
+    }
+}
+
+impl PgConnectionBuilder {
+    /// Reports the given name as `application_name` for the connection
+    ///
+    /// The name shows up in `pg_stat_activity` and in the server log, so
+    /// administrators can tell which application a connection belongs
+    /// to. The parameter is appended to the connection string, which
+    /// works both for URLs and for `keyword=value` connection strings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::Text;
+    /// #
+    /// # fn main() {
+    /// #     let database_url = database_url_from_env("PG_DATABASE_URL");
+    /// let conn = &mut PgConnection::build(&database_url)
+    ///     .application_name("my_app")
+    ///     .establish()
+    ///     .unwrap();
+    ///
+    /// let name = diesel::select(sql::<Text>("current_setting('application_name')"))
+    ///     .get_result::<String>(conn);
+    /// assert_eq!(Ok("my_app".into()), name);
+    /// # }
+    /// ```
+    pub fn application_name(mut self, name: &str) -> Self {
+        self.application_name = Some(name.to_owned());
+        self
+    }
+
+    /// Terminates sessions sitting idle inside an open transaction for
+    /// longer than the given duration
+    ///
+    /// This sets the `idle_in_transaction_session_timeout` parameter
+    /// (PostgreSQL 9.6+) for the connection's session. Idle transactions
+    /// hold locks and block vacuum; terminating them keeps a forgotten
+    /// transaction, e.g. of a suspended async task, from doing so
+    /// indefinitely.
+    ///
+    /// The timeout is rounded down to whole milliseconds.
+    pub fn idle_in_transaction_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_in_transaction_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the schema search path for the connection
+    ///
+    /// Unqualified table names are resolved against the given schemas, in
+    /// order, as if `SET search_path TO ...` had been executed on
+    /// connect. This is useful for multi-tenant applications which keep
+    /// one schema per tenant, where prefixing every table reference with
+    /// the schema name would be impractical.
+    ///
+    /// To change the search path of an existing connection, use
+    /// [`PgConnection::set_search_path`](PgConnection::set_search_path())
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::Text;
+    /// #
+    /// # fn main() {
+    /// #     let database_url = database_url_from_env("PG_DATABASE_URL");
+    /// let conn = &mut PgConnection::build(&database_url)
+    ///     .search_path(&["public", "pg_catalog"])
+    ///     .establish()
+    ///     .unwrap();
+    ///
+    /// let search_path = diesel::select(sql::<Text>("current_setting('search_path')"))
+    ///     .get_result::<String>(conn);
+    /// assert_eq!(Ok("public, pg_catalog".into()), search_path);
+    /// # }
+    /// ```
+    pub fn search_path(mut self, schemas: &[&str]) -> Self {
+        self.search_path = Some(schemas.iter().map(|&s| s.to_owned()).collect());
+        self
+    }
+
+    /// Sets how SSL is negotiated for the connection
+    ///
+    /// This sets the `sslmode` connection parameter, overriding any
+    /// value given in the connection string. Use
+    /// [`SslMode::VerifyFull`] together with
+    /// [`ssl_root_cert`](PgConnectionBuilder::ssl_root_cert()) to fully
+    /// authenticate the server.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::pg::SslMode;
+    /// #
+    /// # fn main() {
+    /// #     let database_url = database_url_from_env("PG_DATABASE_URL");
+    /// let conn = PgConnection::build(&database_url)
+    ///     .ssl_mode(SslMode::Prefer)
+    ///     .establish()
+    ///     .unwrap();
+    /// # }
+    /// ```
+    pub fn ssl_mode(mut self, mode: SslMode) -> Self {
+        self.ssl_mode = Some(mode);
+        self
+    }
+
+    /// Sets the root certificate used to verify the server's certificate
+    ///
+    /// This sets the `sslrootcert` connection parameter to the given
+    /// file, which should contain the certificate of the CA which issued
+    /// the server certificate. Required for
+    /// [`SslMode::VerifyCa`] and [`SslMode::VerifyFull`] unless the
+    /// certificate is in the default `~/.postgresql/root.crt` location.
+    pub fn ssl_root_cert(mut self, path: impl AsRef<Path>) -> Self {
+        self.ssl_root_cert = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Sets the client certificate and private key used to authenticate
+    /// to the server
+    ///
+    /// This sets the `sslcert` and `sslkey` connection parameters to the
+    /// given files, for servers which require certificate based client
+    /// authentication.
+    pub fn ssl_client_cert(
+        mut self,
+        cert_path: impl AsRef<Path>,
+        key_path: impl AsRef<Path>,
+    ) -> Self {
+        self.ssl_client_cert = Some((cert_path.as_ref().to_owned(), key_path.as_ref().to_owned()));
+        self
+    }
+
+    /// Establishes the connection and applies the configured options
+    pub fn establish(self) -> ConnectionResult<PgConnection> {
+        let mut database_url = self.database_url;
+        if let Some(ref name) = self.application_name {
+            append_connection_parameter(&mut database_url, "application_name", name);
+        }
+        if let Some(mode) = self.ssl_mode {
+            append_connection_parameter(&mut database_url, "sslmode", mode.as_str());
+        }
+        if let Some(ref path) = self.ssl_root_cert {
+            append_connection_parameter(&mut database_url, "sslrootcert", &path.to_string_lossy());
+        }
+        if let Some((ref cert_path, ref key_path)) = self.ssl_client_cert {
+            append_connection_parameter(&mut database_url, "sslcert", &cert_path.to_string_lossy());
+            append_connection_parameter(&mut database_url, "sslkey", &key_path.to_string_lossy());
+        }
+        let mut conn = PgConnection::establish(&database_url)?;
+        if let Some(timeout) = self.idle_in_transaction_timeout {
+            conn.execute(&format!(
+                "SET idle_in_transaction_session_timeout = {}",
+                timeout.as_millis(),
+            ))
+            .map_err(CouldntSetupConfiguration)?;
+        }
+        if let Some(ref schemas) = self.search_path {
+            let schemas = schemas.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+            conn.set_search_path(&schemas)
+                .map_err(CouldntSetupConfiguration)?;
+        }
+        Ok(conn)
+    }
+}
+
+/// Appends a parameter to a connection string, in the syntax matching
+/// the connection string's format (URL or `keyword=value` pairs).
+fn append_connection_parameter(database_url: &mut String, keyword: &str, value: &str) {
+    if database_url.contains("://") {
+        database_url.push(if database_url.contains('?') { '&' } else { '?' });
+        database_url.push_str(keyword);
+        database_url.push('=');
+        for byte in value.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    database_url.push(byte as char)
+                }
+                _ => database_url.push_str(&format!("%{:02X}", byte)),
+            }
+        }
+    } else {
+        if !database_url.is_empty() {
+            database_url.push(' ');
+        }
+        database_url.push_str(keyword);
+        database_url.push_str("='");
+        database_url.push_str(&value.replace('\\', "\\\\").replace('\'', "\\'"));
+        database_url.push('\'');
+    }
+}
@@ -0,0 +1,224 @@
+use byteorder::{NetworkEndian, WriteBytesExt};
+use std::ffi::CString;
+use std::io::Write;
+use std::marker::PhantomData;
+
+use super::result::PgResult;
+use super::PgConnection;
+use crate::expression::Expression;
+use crate::pg::{Pg, PgMetadataLookup, PgQueryBuilder};
+use crate::query_builder::{QueryBuilder, QueryFragment};
+use crate::query_source::{ColumnNames, Table};
+use crate::result::Error::SerializationError;
+use crate::result::QueryResult;
+use crate::serialize::{IsNull, Output, ToSql};
+
+/// The header every binary `COPY` stream starts with: a magic signature,
+/// followed by a flags field and a header extension length, both zero.
+const BINARY_COPY_HEADER: &[u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+
+/// A bulk loader inserting rows via PostgreSQL's binary `COPY` protocol
+///
+/// Binary `COPY` is significantly faster than `INSERT ... VALUES` for
+/// large data sets, since rows are streamed to the server in its native
+/// wire format without being parsed as SQL. Rows are pushed as tuples
+/// matching the table's columns and are serialized with their usual
+/// [`ToSql`] impls; [`finish`](BulkLoader::finish()) completes the load
+/// and returns the number of inserted rows. Dropping the loader without
+/// calling `finish` aborts the `COPY`, so no partial data is kept.
+///
+/// While the loader exists the connection is in copy-in mode and cannot
+/// execute other queries.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::users;
+/// use diesel::pg::BulkLoader;
+///
+/// # let conn = &mut establish_connection();
+/// let mut loader = BulkLoader::new(conn, users::table)?;
+/// loader.push(&(3, "Ruby"))?;
+/// loader.push(&(4, "Jim"))?;
+/// let inserted = loader.finish()?;
+/// assert_eq!(2, inserted);
+///
+/// let count = users::table.count().get_result::<i64>(conn);
+/// assert_eq!(Ok(4), count);
+/// #     Ok(())
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct BulkLoader<'conn, T> {
+    conn: &'conn mut PgConnection,
+    finished: bool,
+    _marker: PhantomData<T>,
+}
+
+impl<'conn, T> BulkLoader<'conn, T>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Pg>,
+    T::AllColumns: ColumnNames,
+{
+    /// Starts a binary `COPY` into the given table
+    ///
+    /// This issues `COPY "table" ("columns", ...) FROM STDIN
+    /// (FORMAT BINARY)`, so the connection stays in copy-in mode until
+    /// the returned loader is finished or dropped.
+    pub fn new(conn: &'conn mut PgConnection, table: T) -> QueryResult<Self> {
+        let mut query_builder = PgQueryBuilder::new();
+        query_builder.push_sql("COPY ");
+        table.from_clause().to_sql(&mut query_builder)?;
+        query_builder.push_sql(" (");
+        for (idx, column) in T::AllColumns::column_names().iter().enumerate() {
+            if idx != 0 {
+                query_builder.push_sql(", ");
+            }
+            query_builder.push_identifier(column)?;
+        }
+        query_builder.push_sql(") FROM STDIN (FORMAT BINARY)");
+
+        let sql =
+            CString::new(query_builder.finish()).map_err(|e| SerializationError(Box::new(e)))?;
+        conn.raw_connection.begin_copy_in(&sql)?;
+        conn.raw_connection.put_copy_data(BINARY_COPY_HEADER)?;
+
+        Ok(BulkLoader {
+            conn,
+            finished: false,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Sends a single row to the server
+    ///
+    /// The row is given as a tuple with one value per column of the
+    /// table, in the order the columns appear in the table definition.
+    pub fn push<R>(&mut self, row: &R) -> QueryResult<()>
+    where
+        T::AllColumns: Expression,
+        R: CopyRow<<T::AllColumns as Expression>::SqlType>,
+    {
+        let mut buf = Vec::new();
+        buf.write_i16::<NetworkEndian>(R::FIELD_COUNT)
+            .map_err(|e| SerializationError(Box::new(e)))?;
+        row.write_fields(&mut buf, &mut *self.conn)?;
+        self.conn.raw_connection.put_copy_data(&buf)
+    }
+
+    /// Sends every row of the given iterator to the server
+    pub fn push_all<I>(&mut self, rows: I) -> QueryResult<()>
+    where
+        T::AllColumns: Expression,
+        I: IntoIterator,
+        I::Item: CopyRow<<T::AllColumns as Expression>::SqlType>,
+    {
+        for row in rows {
+            self.push(&row)?;
+        }
+        Ok(())
+    }
+
+    /// Completes the `COPY`, returning the number of inserted rows
+    pub fn finish(mut self) -> QueryResult<usize> {
+        self.finished = true;
+        // The stream ends with a field count of -1 instead of a row
+        self.conn
+            .raw_connection
+            .put_copy_data(&(-1i16).to_be_bytes())?;
+        let result = self.conn.raw_connection.put_copy_end(None)?;
+        Ok(PgResult::new(result)?.rows_affected())
+    }
+}
+
+impl<'conn, T> Drop for BulkLoader<'conn, T> {
+    fn drop(&mut self) {
+        if !self.finished {
+            let message = CString::new("`BulkLoader` was dropped without calling `finish`")
+                .expect("The message contains no null bytes");
+            let _ = self.conn.raw_connection.put_copy_end(Some(&message));
+        }
+    }
+}
+
+/// A row which can be streamed to the database in binary `COPY` format
+///
+/// This trait is implemented for tuples of values whose elements
+/// implement [`ToSql`] for the corresponding column's SQL type.
+pub trait CopyRow<ST> {
+    /// The number of fields written by `write_fields`
+    const FIELD_COUNT: i16;
+
+    /// Appends this row's fields to a binary `COPY` data buffer
+    fn write_fields(
+        &self,
+        buf: &mut Vec<u8>,
+        lookup: &mut (dyn PgMetadataLookup + 'static),
+    ) -> QueryResult<()>;
+}
+
+fn write_field<ST, T>(
+    value: &T,
+    buf: &mut Vec<u8>,
+    lookup: &mut (dyn PgMetadataLookup + 'static),
+) -> QueryResult<()>
+where
+    T: ToSql<ST, Pg>,
+{
+    let mut field = Output::new(Vec::new(), lookup);
+    let is_null = value.to_sql(&mut field).map_err(SerializationError)?;
+    let result = match is_null {
+        IsNull::Yes => buf.write_i32::<NetworkEndian>(-1),
+        IsNull::No => {
+            let bytes = field.into_inner();
+            buf.write_i32::<NetworkEndian>(bytes.len() as i32)
+                .and_then(|_| buf.write_all(&bytes))
+        }
+    };
+    result.map_err(|e| SerializationError(Box::new(e)))
+}
+
+macro_rules! copy_row_impls {
+    ($(($($idx:tt -> $T:ident, $ST:ident,)+))+) => {
+        $(
+            impl<$($T,)+ $($ST,)+> CopyRow<($($ST,)+)> for ($($T,)+)
+            where
+                $($T: ToSql<$ST, Pg>,)+
+            {
+                const FIELD_COUNT: i16 = [$(stringify!($T),)+].len() as i16;
+
+                fn write_fields(
+                    &self,
+                    buf: &mut Vec<u8>,
+                    lookup: &mut (dyn PgMetadataLookup + 'static),
+                ) -> QueryResult<()> {
+                    $(write_field::<$ST, $T>(&self.$idx, buf, lookup)?;)+
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
+
+copy_row_impls! {
+    (0 -> A, SA,)
+    (0 -> A, SA, 1 -> B, SB,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE, 5 -> F, SF,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE, 5 -> F, SF, 6 -> G, SG,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE, 5 -> F, SF, 6 -> G, SG, 7 -> H, SH,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE, 5 -> F, SF, 6 -> G, SG, 7 -> H, SH, 8 -> I, SI,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE, 5 -> F, SF, 6 -> G, SG, 7 -> H, SH, 8 -> I, SI, 9 -> J, SJ,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE, 5 -> F, SF, 6 -> G, SG, 7 -> H, SH, 8 -> I, SI, 9 -> J, SJ, 10 -> K, SK,)
+    (0 -> A, SA, 1 -> B, SB, 2 -> C, SC, 3 -> D, SD, 4 -> E, SE, 5 -> F, SF, 6 -> G, SG, 7 -> H, SH, 8 -> I, SI, 9 -> J, SJ, 10 -> K, SK, 11 -> L, SL,)
+}
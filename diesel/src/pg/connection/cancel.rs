@@ -0,0 +1,66 @@
+extern crate pq_sys;
+
+use self::pq_sys::*;
+use std::ffi::CStr;
+use std::os::raw as libc;
+use std::ptr::NonNull;
+
+use crate::result::*;
+
+/// A handle that can cancel the query currently running on a connection
+///
+/// Obtained from [`PgConnection::cancel_handle`]. The handle stays valid
+/// after the connection is dropped, but cancel requests issued at that
+/// point have no effect.
+///
+/// [`PgConnection::cancel_handle`]: super::PgConnection::cancel_handle()
+#[allow(missing_debug_implementations)]
+pub struct PgCancelHandle {
+    inner: NonNull<PGcancel>,
+}
+
+// The `PGcancel` object is only read after creation, and libpq documents
+// `PQcancel` as safe to invoke from a signal handler or a separate thread
+// while the connection is busy.
+unsafe impl Send for PgCancelHandle {}
+unsafe impl Sync for PgCancelHandle {}
+
+impl PgCancelHandle {
+    pub(super) fn new(inner: NonNull<PGcancel>) -> Self {
+        PgCancelHandle { inner }
+    }
+
+    /// Requests that the server abandon the query currently running on
+    /// the connection this handle was created from
+    ///
+    /// The cancelled query fails with a `query_canceled` error; the
+    /// connection itself stays open and usable. If no query is running
+    /// the request is silently ignored by the server. A successful
+    /// return only means the request was sent, not that the query was
+    /// actually cancelled.
+    pub fn cancel(&self) -> QueryResult<()> {
+        let mut error_buffer = [0 as libc::c_char; 256];
+        let result = unsafe {
+            PQcancel(
+                self.inner.as_ptr(),
+                error_buffer.as_mut_ptr(),
+                error_buffer.len() as libc::c_int,
+            )
+        };
+        if result == 1 {
+            Ok(())
+        } else {
+            let message = unsafe { CStr::from_ptr(error_buffer.as_ptr()) };
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::UnableToSendCommand,
+                Box::new(message.to_string_lossy().into_owned()),
+            ))
+        }
+    }
+}
+
+impl Drop for PgCancelHandle {
+    fn drop(&mut self) {
+        unsafe { PQfreeCancel(self.inner.as_ptr()) }
+    }
+}
@@ -1,17 +1,31 @@
+mod builder;
+mod bulk_loader;
+mod cancel;
 mod cursor;
+mod named_cursor;
 pub mod raw;
+mod replication;
 #[doc(hidden)]
 pub mod result;
 mod row;
+mod server_info;
 mod stmt;
+mod two_phase;
 
 use std::ffi::CString;
 use std::os::raw as libc;
 
+pub use self::builder::{PgConnectionBuilder, SslMode};
+pub use self::bulk_loader::{BulkLoader, CopyRow};
+pub use self::cancel::PgCancelHandle;
 use self::cursor::*;
+pub use self::named_cursor::PgCursor;
 use self::raw::RawConnection;
+pub use self::replication::ReplicationChange;
 use self::result::PgResult;
+pub use self::server_info::ServerInfo;
 use self::stmt::Statement;
+pub use self::two_phase::TwoPhaseTransaction;
 use crate::connection::*;
 use crate::deserialize::FromSqlRow;
 use crate::expression::QueryMetadata;
@@ -106,6 +120,13 @@ impl Connection for PgConnection {
     }
 }
 
+impl StatementTimeout for PgConnection {
+    fn set_statement_timeout(&mut self, timeout: std::time::Duration) -> QueryResult<()> {
+        self.execute(&format!("SET statement_timeout = {}", timeout.as_millis(),))
+            .map(|_| ())
+    }
+}
+
 impl GetPgMetadataCache for PgConnection {
     fn get_metadata_cache(&mut self) -> &mut PgMetadataCache {
         &mut self.metadata_cache
@@ -140,6 +161,196 @@ impl PgConnection {
         TransactionBuilder::new(self)
     }
 
+    /// Limits how long statements on this connection wait when trying to
+    /// acquire a lock
+    ///
+    /// Statements waiting longer than the given duration for a lock are
+    /// aborted by the server and return an error. The setting applies to
+    /// the whole session; to limit a single transaction, use
+    /// [`TransactionBuilder::lock_timeout`] instead.
+    ///
+    /// The timeout is rounded down to whole milliseconds.
+    ///
+    /// [`TransactionBuilder::lock_timeout`]: crate::pg::TransactionBuilder::lock_timeout()
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use std::time::Duration;
+    /// #     let conn = &mut establish_connection();
+    /// conn.set_lock_timeout(Duration::from_secs(5)).unwrap();
+    /// # }
+    /// ```
+    pub fn set_lock_timeout(&mut self, timeout: std::time::Duration) -> QueryResult<()> {
+        self.execute(&format!("SET lock_timeout = {}", timeout.as_millis()))
+            .map(|_| ())
+    }
+
+    /// Sets the amount of memory available to query operations for the
+    /// current transaction
+    ///
+    /// This issues `SET LOCAL work_mem`, so the setting only applies
+    /// until the current transaction ends and expensive analytical
+    /// queries can be given more memory for sorts and hash tables
+    /// without changing the server wide configuration. Outside of a
+    /// transaction the statement has no effect.
+    ///
+    /// The value is rounded down to whole kilobytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::dsl::sql;
+    /// # use diesel::sql_types::Text;
+    /// #
+    /// # fn main() {
+    /// #     let conn = &mut establish_connection();
+    /// conn.set_work_mem(1024 * 1024 * 1024).unwrap();
+    ///
+    /// let work_mem = diesel::select(sql::<Text>("current_setting('work_mem')"))
+    ///     .get_result::<String>(conn);
+    /// assert_eq!(Ok("1GB".into()), work_mem);
+    /// # }
+    /// ```
+    pub fn set_work_mem(&mut self, bytes: u64) -> QueryResult<()> {
+        self.execute(&format!("SET LOCAL work_mem = '{}kB'", bytes / 1024))
+            .map(|_| ())
+    }
+
+    /// Sets the amount of memory available to maintenance operations,
+    /// such as `CREATE INDEX` or `VACUUM`, for the current transaction
+    ///
+    /// This issues `SET LOCAL maintenance_work_mem`, so the setting only
+    /// applies until the current transaction ends. Outside of a
+    /// transaction the statement has no effect.
+    ///
+    /// The value is rounded down to whole kilobytes.
+    pub fn set_maintenance_work_mem(&mut self, bytes: u64) -> QueryResult<()> {
+        self.execute(&format!(
+            "SET LOCAL maintenance_work_mem = '{}kB'",
+            bytes / 1024,
+        ))
+        .map(|_| ())
+    }
+
+    /// Sets the schema search path for this connection
+    ///
+    /// This issues `SET search_path TO ...`, so unqualified table names
+    /// in subsequent queries are resolved against the given schemas, in
+    /// order. Schema names are quoted, so they are looked up exactly as
+    /// given. To apply a search path on connect, use
+    /// [`PgConnectionBuilder::search_path`] instead.
+    ///
+    /// [`PgConnectionBuilder::search_path`]: crate::pg::PgConnectionBuilder::search_path()
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     let conn = &mut establish_connection();
+    /// conn.set_search_path(&["public"]).unwrap();
+    /// # }
+    /// ```
+    pub fn set_search_path(&mut self, schemas: &[&str]) -> QueryResult<()> {
+        let mut query_builder = crate::pg::PgQueryBuilder::default();
+        query_builder.push_sql("SET search_path TO ");
+        for (idx, schema) in schemas.iter().enumerate() {
+            if idx != 0 {
+                query_builder.push_sql(", ");
+            }
+            query_builder.push_identifier(schema)?;
+        }
+        self.execute(&query_builder.finish()).map(|_| ())
+    }
+
+    /// Defers all deferrable constraints until the current transaction
+    /// commits
+    ///
+    /// This issues `SET CONSTRAINTS ALL DEFERRED`, so constraints
+    /// declared `DEFERRABLE` are only checked at commit time instead of
+    /// after each statement. This allows inserting records with circular
+    /// foreign key references within a transaction. Has no effect
+    /// outside a transaction, and lasts until the transaction ends. To
+    /// defer a single constraint for one insert, see
+    /// [`InsertStatement::defer_constraint`].
+    ///
+    /// [`InsertStatement::defer_constraint`]: crate::query_builder::InsertStatement::defer_constraint()
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut establish_connection();
+    /// #     conn.execute(
+    /// #         "CREATE TABLE nodes (
+    /// #              id INT PRIMARY KEY,
+    /// #              parent INT REFERENCES nodes (id) DEFERRABLE INITIALLY IMMEDIATE
+    /// #          )",
+    /// #     )?;
+    /// conn.defer_constraints()?;
+    /// // Both rows reference each other, which would fail the foreign
+    /// // key check if it ran after each statement
+    /// conn.execute("INSERT INTO nodes VALUES (1, 2)")?;
+    /// conn.execute("INSERT INTO nodes VALUES (2, 1)")?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn defer_constraints(&mut self) -> QueryResult<()> {
+        self.execute("SET CONSTRAINTS ALL DEFERRED").map(|_| ())
+    }
+
+    /// Creates a handle that can cancel the query running on this connection
+    ///
+    /// The handle can be moved to another thread or stored in a signal
+    /// handler, and calling [`PgCancelHandle::cancel`] from there aborts
+    /// whatever query this connection is executing at that moment without
+    /// closing the connection. Cancelling while the connection is idle is
+    /// a no-op.
+    ///
+    /// [`PgCancelHandle::cancel`]: crate::pg::PgCancelHandle::cancel()
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut establish_connection();
+    /// let handle = conn.cancel_handle()?;
+    /// // Typically the handle is handed to a watchdog thread here.
+    /// // Cancelling while no query is running has no effect.
+    /// handle.cancel()?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn cancel_handle(&mut self) -> QueryResult<PgCancelHandle> {
+        self.raw_connection
+            .get_cancel()
+            .map(PgCancelHandle::new)
+            .ok_or_else(|| {
+                Error::DatabaseError(
+                    DatabaseErrorKind::UnableToSendCommand,
+                    Box::new(self.raw_connection.last_error_message()),
+                )
+            })
+    }
+
     fn with_prepared_query<T: QueryFragment<Pg> + QueryId, R>(
         &mut self,
         source: &T,
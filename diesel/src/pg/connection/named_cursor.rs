@@ -0,0 +1,152 @@
+use std::marker::PhantomData;
+
+use super::PgConnection;
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, Query, QueryFragment, QueryId, TypedSqlQuery};
+use crate::query_dsl::methods::LoadQuery;
+use crate::query_dsl::RunQueryDsl;
+use crate::result::QueryResult;
+
+/// A server side cursor, created by
+/// [`declare_cursor`](PgConnection::declare_cursor())
+///
+/// The cursor borrows the connection it was declared on; rows are
+/// fetched in chunks with [`fetch`](PgCursor::fetch()), so result sets
+/// larger than memory can be processed. The cursor is closed
+/// automatically when it goes out of scope.
+///
+/// Cursors without `WITH HOLD` only live until the end of the current
+/// transaction, so a cursor must be declared inside one, e.g. within
+/// [`Connection::transaction`](crate::connection::Connection::transaction()).
+#[allow(missing_debug_implementations)]
+pub struct PgCursor<'conn, ST> {
+    conn: &'conn mut PgConnection,
+    name: String,
+    _marker: PhantomData<ST>,
+}
+
+impl PgConnection {
+    /// Declares a named server side cursor for the given query
+    ///
+    /// This issues `DECLARE name CURSOR FOR query` and returns a
+    /// [`PgCursor`] for fetching the query's rows in chunks. See its
+    /// documentation for details and an example.
+    pub fn declare_cursor<T>(
+        &mut self,
+        name: &str,
+        query: T,
+    ) -> QueryResult<PgCursor<'_, T::SqlType>>
+    where
+        T: Query + QueryFragment<Pg> + QueryId,
+    {
+        use crate::connection::Connection;
+
+        self.execute_returning_count(&DeclareCursor {
+            name: name.to_owned(),
+            query,
+        })?;
+        Ok(PgCursor {
+            conn: self,
+            name: name.to_owned(),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<'conn, ST> PgCursor<'conn, ST> {
+    /// Fetches the next `count` rows from the cursor
+    ///
+    /// Returns fewer rows when the cursor is exhausted, so an empty
+    /// result marks the end of the result set.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users;
+    /// #     let conn = &mut establish_connection();
+    /// let mut cursor = conn.declare_cursor(
+    ///     "all_users",
+    ///     users::table.select(users::name).order(users::id),
+    /// )?;
+    ///
+    /// let first = cursor.fetch::<String>(1)?;
+    /// assert_eq!(vec!["Sean"], first);
+    ///
+    /// let rest = cursor.fetch_all::<String>()?;
+    /// assert_eq!(vec!["Tess"], rest);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn fetch<U>(&mut self, count: i64) -> QueryResult<Vec<U>>
+    where
+        TypedSqlQuery<ST>: LoadQuery<PgConnection, U>,
+    {
+        self.run_fetch(&format!("FETCH {} FROM ", count))
+    }
+
+    /// Fetches all remaining rows from the cursor
+    pub fn fetch_all<U>(&mut self) -> QueryResult<Vec<U>>
+    where
+        TypedSqlQuery<ST>: LoadQuery<PgConnection, U>,
+    {
+        self.run_fetch("FETCH ALL FROM ")
+    }
+
+    fn run_fetch<U>(&mut self, prefix: &str) -> QueryResult<Vec<U>>
+    where
+        TypedSqlQuery<ST>: LoadQuery<PgConnection, U>,
+    {
+        let mut sql = prefix.to_owned();
+        sql.push_str(&quoted_name(&self.name));
+        TypedSqlQuery::<ST>::new((), sql).load(self.conn)
+    }
+}
+
+impl<'conn, ST> Drop for PgCursor<'conn, ST> {
+    fn drop(&mut self) {
+        use crate::connection::Connection;
+
+        let close = format!("CLOSE {}", quoted_name(&self.name));
+        if let Err(e) = self.conn.execute(&close) {
+            if !std::thread::panicking() {
+                eprintln!("Failed to close cursor {}: {}", self.name, e);
+            }
+        }
+    }
+}
+
+fn quoted_name(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+/// A `DECLARE name CURSOR FOR query` statement
+struct DeclareCursor<T> {
+    name: String,
+    query: T,
+}
+
+impl<T> QueryId for DeclareCursor<T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T> QueryFragment<Pg> for DeclareCursor<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("DECLARE ");
+        out.push_identifier(&self.name)?;
+        out.push_sql(" CURSOR FOR ");
+        self.query.walk_ast(out.reborrow())
+    }
+}
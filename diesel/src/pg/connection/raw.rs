@@ -82,6 +82,100 @@ impl RawConnection {
         RawResult::new(ptr, self)
     }
 
+    /// Executes the given `COPY ... FROM STDIN` statement, leaving the
+    /// connection in copy-in mode
+    pub fn begin_copy_in(&mut self, query: &CStr) -> QueryResult<()> {
+        let result = unsafe { self.exec(query.as_ptr())? };
+        let status = unsafe { PQresultStatus(result.as_ptr()) };
+        if status == ExecStatusType::PGRES_COPY_IN {
+            Ok(())
+        } else {
+            let message = result.error_message();
+            let message = if message.is_empty() {
+                self.last_error_message()
+            } else {
+                message.to_owned()
+            };
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::UnableToSendCommand,
+                Box::new(message),
+            ))
+        }
+    }
+
+    /// Sends a chunk of data while the connection is in copy-in mode
+    pub fn put_copy_data(&mut self, data: &[u8]) -> QueryResult<()> {
+        let status = unsafe {
+            PQputCopyData(
+                self.internal_connection.as_ptr(),
+                data.as_ptr() as *const libc::c_char,
+                data.len() as libc::c_int,
+            )
+        };
+        if status == 1 {
+            Ok(())
+        } else {
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::UnableToSendCommand,
+                Box::new(self.last_error_message()),
+            ))
+        }
+    }
+
+    /// Leaves copy-in mode, returning the result of the `COPY` statement
+    ///
+    /// If an error message is given, the `COPY` is aborted instead and the
+    /// message is reported to the server as the cause.
+    pub fn put_copy_end(&mut self, error_message: Option<&CStr>) -> QueryResult<RawResult> {
+        let status = unsafe {
+            PQputCopyEnd(
+                self.internal_connection.as_ptr(),
+                error_message.map_or(ptr::null(), CStr::as_ptr),
+            )
+        };
+        if status != 1 {
+            return Err(Error::DatabaseError(
+                DatabaseErrorKind::UnableToSendCommand,
+                Box::new(self.last_error_message()),
+            ));
+        }
+        let result = RawResult::new(
+            unsafe { PQgetResult(self.internal_connection.as_ptr()) },
+            self,
+        );
+        // Drain any further results, so the connection can be used for
+        // ordinary queries again
+        loop {
+            let next = unsafe { PQgetResult(self.internal_connection.as_ptr()) };
+            if next.is_null() {
+                break;
+            }
+            unsafe { PQclear(next) };
+        }
+        result
+    }
+
+    pub fn server_version(&self) -> i32 {
+        unsafe { PQserverVersion(self.internal_connection.as_ptr()) }
+    }
+
+    pub fn parameter_status(&self, param: &CStr) -> Option<String> {
+        let value = unsafe { PQparameterStatus(self.internal_connection.as_ptr(), param.as_ptr()) };
+        if value.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(value) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    pub fn get_cancel(&self) -> Option<NonNull<PGcancel>> {
+        NonNull::new(unsafe { PQgetCancel(self.internal_connection.as_ptr()) })
+    }
+
     pub unsafe fn prepare(
         &self,
         stmt_name: *const libc::c_char,
@@ -0,0 +1,119 @@
+use super::PgConnection;
+use crate::prelude::*;
+use crate::result::QueryResult;
+
+/// A single change returned from a logical replication slot
+///
+/// See [`PgConnection::get_changes`] for details.
+///
+/// [`PgConnection::get_changes`]: PgConnection::get_changes()
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ReplicationChange {
+    /// The WAL position of the change
+    pub lsn: String,
+    /// The id of the transaction the change was part of
+    pub xid: i64,
+    /// The change itself, in the format produced by the slot's output
+    /// plugin
+    pub data: String,
+}
+
+impl PgConnection {
+    /// Creates a logical replication slot with the given output plugin
+    ///
+    /// A replication slot records changes to the database for change
+    /// data capture, retaining WAL until the changes are consumed. The
+    /// server must be configured with `wal_level = logical`. Commonly
+    /// used plugins are `test_decoding` (shipped with PostgreSQL) and
+    /// `pgoutput`.
+    ///
+    /// Note that an unconsumed slot prevents the server from discarding
+    /// WAL, so slots should be dropped with
+    /// [`drop_replication_slot`](PgConnection::drop_replication_slot())
+    /// when no longer needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_transaction();
+    /// conn.create_replication_slot("my_slot", "test_decoding")?;
+    /// // ... make some changes ...
+    /// for change in conn.get_changes("my_slot", None, None)? {
+    ///     println!("{} {}", change.lsn, change.data);
+    /// }
+    /// conn.drop_replication_slot("my_slot")?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn create_replication_slot(&mut self, name: &str, plugin: &str) -> QueryResult<()> {
+        self.execute(&format!(
+            "SELECT pg_create_logical_replication_slot({}, {})",
+            quoted_literal(name),
+            quoted_literal(plugin),
+        ))
+        .map(|_| ())
+    }
+
+    /// Drops a replication slot, discarding any remaining changes
+    pub fn drop_replication_slot(&mut self, name: &str) -> QueryResult<()> {
+        self.execute(&format!(
+            "SELECT pg_drop_replication_slot({})",
+            quoted_literal(name),
+        ))
+        .map(|_| ())
+    }
+
+    /// Consumes changes from a logical replication slot
+    ///
+    /// Returns the changes recorded by the slot since they were last
+    /// consumed, decoded by the slot's output plugin. Consumed changes
+    /// are gone from the slot. `upto_lsn` stops at the given WAL
+    /// position, `max_changes` limits the number of returned changes;
+    /// `None` puts no bound on either.
+    ///
+    /// See [`create_replication_slot`] for an example.
+    ///
+    /// [`create_replication_slot`]: PgConnection::create_replication_slot()
+    pub fn get_changes(
+        &mut self,
+        slot: &str,
+        upto_lsn: Option<&str>,
+        max_changes: Option<i64>,
+    ) -> QueryResult<Vec<ReplicationChange>> {
+        use crate::dsl::sql;
+        use crate::sql_types::{BigInt, Text};
+
+        let upto_lsn = match upto_lsn {
+            Some(lsn) => format!("{}::pg_lsn", quoted_literal(lsn)),
+            None => String::from("NULL"),
+        };
+        let max_changes = match max_changes {
+            Some(limit) => limit.to_string(),
+            None => String::from("NULL"),
+        };
+        let rows = crate::select(sql::<(Text, BigInt, Text)>(&format!(
+            "lsn::text, xid::text::bigint, data \
+             FROM pg_logical_slot_get_changes({}, {}, {})",
+            quoted_literal(slot),
+            upto_lsn,
+            max_changes,
+        )))
+        .load::<(String, i64, String)>(self)?;
+        Ok(rows
+            .into_iter()
+            .map(|(lsn, xid, data)| ReplicationChange { lsn, xid, data })
+            .collect())
+    }
+}
+
+fn quoted_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
@@ -0,0 +1,73 @@
+use std::ffi::CStr;
+
+use super::PgConnection;
+
+/// The version and capabilities of the server a connection is talking to
+///
+/// Obtained from [`PgConnection::server_info`]. The capability flags are
+/// derived from the server version, so application code can branch on a
+/// named capability instead of comparing version numbers.
+///
+/// [`PgConnection::server_info`]: PgConnection::server_info()
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ServerInfo {
+    /// The server version as `(major, minor, patch)`
+    ///
+    /// PostgreSQL 10 and later only have two version components; for
+    /// those the patch component is `0`.
+    pub version: (u32, u32, u32),
+    /// The character encoding the server stores text in, e.g. `UTF8`
+    pub server_encoding: String,
+    /// Whether the server supports the `RETURNING` clause
+    /// (PostgreSQL 8.2+)
+    pub supports_returning: bool,
+    /// Whether the server supports the `json` type and its operators
+    /// (PostgreSQL 9.2+)
+    pub supports_json: bool,
+}
+
+impl PgConnection {
+    /// Returns the version and capabilities of the connected server
+    ///
+    /// This is answered from information exchanged when the connection
+    /// was established, so it does not perform a round trip to the
+    /// server.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     let conn = &mut establish_connection();
+    /// let info = conn.server_info();
+    /// assert!(info.version.0 >= 9);
+    /// assert!(info.supports_returning);
+    /// # }
+    /// ```
+    pub fn server_info(&self) -> ServerInfo {
+        let raw_version = self.raw_connection.server_version() as u32;
+        // `PQserverVersion` multiplies the components into one number,
+        // e.g. 150004 for 15.4 and 90624 for 9.6.24
+        let version = if raw_version >= 100_000 {
+            (raw_version / 10_000, raw_version % 10_000, 0)
+        } else {
+            (
+                raw_version / 10_000,
+                (raw_version / 100) % 100,
+                raw_version % 100,
+            )
+        };
+        let server_encoding = self
+            .raw_connection
+            .parameter_status(unsafe { CStr::from_bytes_with_nul_unchecked(b"server_encoding\0") })
+            .unwrap_or_default();
+        ServerInfo {
+            version,
+            server_encoding,
+            supports_returning: raw_version >= 80_200,
+            supports_json: raw_version >= 90_200,
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use std::ops::{Deref, DerefMut};
+
+use super::PgConnection;
+use crate::connection::{AnsiTransactionManager, SimpleConnection, TransactionManager};
+use crate::result::QueryResult;
+
+/// A guard for a transaction which is finished via two-phase commit
+///
+/// Obtained from [`PgConnection::begin_two_phase`]. The guard
+/// dereferences to the underlying connection, so queries can be run on
+/// it directly. Calling [`prepare`](TwoPhaseTransaction::prepare())
+/// completes the first phase; if the guard is dropped without preparing,
+/// the transaction is rolled back.
+///
+/// [`PgConnection::begin_two_phase`]: PgConnection::begin_two_phase()
+#[allow(missing_debug_implementations)] // Connection isn't Debug
+#[must_use = "Two phase transactions are rolled back when the guard is dropped without calling `prepare`"]
+pub struct TwoPhaseTransaction<'conn> {
+    connection: &'conn mut PgConnection,
+    xid: String,
+    prepared: bool,
+}
+
+impl<'conn> TwoPhaseTransaction<'conn> {
+    /// Ends the first phase by executing `PREPARE TRANSACTION`
+    ///
+    /// The transaction is dissociated from this connection and stored on
+    /// the server, where it survives crashes and restarts. It holds its
+    /// locks until it is finalized with
+    /// [`commit_prepared`](PgConnection::commit_prepared()) or
+    /// [`rollback_prepared`](PgConnection::rollback_prepared()), which
+    /// may happen from any connection.
+    pub fn prepare(mut self) -> QueryResult<()> {
+        AnsiTransactionManager::commit_transaction_sql(
+            self.connection,
+            &format!("PREPARE TRANSACTION {}", quoted_xid(&self.xid)),
+        )?;
+        self.prepared = true;
+        Ok(())
+    }
+}
+
+impl Deref for TwoPhaseTransaction<'_> {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        self.connection
+    }
+}
+
+impl DerefMut for TwoPhaseTransaction<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.connection
+    }
+}
+
+impl Drop for TwoPhaseTransaction<'_> {
+    fn drop(&mut self) {
+        if !self.prepared {
+            let _ = AnsiTransactionManager::rollback_transaction(self.connection);
+        }
+    }
+}
+
+impl PgConnection {
+    /// Begins a transaction which is finished via two-phase commit
+    ///
+    /// The returned guard dereferences to the connection, so the
+    /// transaction's queries are run on it directly. Calling
+    /// [`prepare`](TwoPhaseTransaction::prepare()) stores the
+    /// transaction on the server under the given transaction identifier,
+    /// to be finalized later with
+    /// [`commit_prepared`](PgConnection::commit_prepared()) or
+    /// [`rollback_prepared`](PgConnection::rollback_prepared()) — this
+    /// is the building block for distributed (XA) transactions spanning
+    /// multiple data stores.
+    ///
+    /// The server rejects `PREPARE TRANSACTION` unless its
+    /// `max_prepared_transactions` setting is non-zero. This method
+    /// returns an error if a transaction is already open. The transaction
+    /// is registered with the connection's transaction manager, so
+    /// [`transaction`](crate::Connection::transaction()) calls on the
+    /// guard run as savepoints inside it; note that a savepoint left open
+    /// causes [`prepare`](TwoPhaseTransaction::prepare()) to fail.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::connection::SimpleConnection;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_transaction();
+    /// let mut tx = conn.begin_two_phase("order_42")?;
+    /// tx.batch_execute("UPDATE users SET name = 'Jim' WHERE name = 'Sean'")?;
+    /// tx.prepare()?;
+    ///
+    /// // Later, from this or any other connection
+    /// conn.commit_prepared("order_42")?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn begin_two_phase(&mut self, xid: &str) -> QueryResult<TwoPhaseTransaction<'_>> {
+        AnsiTransactionManager::begin_transaction_sql(self, "BEGIN")?;
+        Ok(TwoPhaseTransaction {
+            connection: self,
+            xid: xid.to_owned(),
+            prepared: false,
+        })
+    }
+
+    /// Commits a transaction previously stored with
+    /// [`prepare`](TwoPhaseTransaction::prepare())
+    ///
+    /// Any connection may finalize a prepared transaction, including one
+    /// newly established after the preparing session (or the whole
+    /// server) went away.
+    pub fn commit_prepared(&mut self, xid: &str) -> QueryResult<()> {
+        self.batch_execute(&format!("COMMIT PREPARED {}", quoted_xid(xid)))
+    }
+
+    /// Rolls back a transaction previously stored with
+    /// [`prepare`](TwoPhaseTransaction::prepare())
+    pub fn rollback_prepared(&mut self, xid: &str) -> QueryResult<()> {
+        self.batch_execute(&format!("ROLLBACK PREPARED {}", quoted_xid(xid)))
+    }
+}
+
+fn quoted_xid(xid: &str) -> String {
+    format!("'{}'", xid.replace('\'', "''"))
+}
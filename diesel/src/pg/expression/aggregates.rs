@@ -0,0 +1,314 @@
+use std::marker::PhantomData;
+
+use crate::expression::{is_aggregate, AsExpression, Expression, ValidGrouping};
+use crate::pg::Pg;
+use crate::query_builder::distinct_clause::{DistinctClause, NoDistinctClause};
+use crate::query_builder::order_clause::{NoOrderClause, OrderClause};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{Array, Nullable, SingleValue, SqlType, Text};
+use crate::{AppearsOnTable, SelectableExpression};
+
+/// Creates a PostgreSQL `ARRAY_AGG` expression
+///
+/// Collects all values of the given expression into a single array. As no
+/// rows result in a `NULL` value instead of an empty array, the return type
+/// is nullable.
+///
+/// Use [`order`](ArrayAgg::order()) and [`distinct`](ArrayAgg::distinct())
+/// to control the order of and duplicates in the aggregated array.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     use diesel::dsl::array_agg;
+/// #     let connection = &mut establish_connection();
+/// let names = animals
+///     .select(array_agg(species).order(species))
+///     .first::<Option<Vec<String>>>(connection)?;
+/// assert_eq!(Some(vec!["dog".to_owned(), "spider".to_owned()]), names);
+/// #     Ok(())
+/// # }
+/// #
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn run_test() -> QueryResult<()> {
+/// #     Ok(())
+/// # }
+/// ```
+pub fn array_agg<ST, E>(expr: E) -> ArrayAgg<ST, E::Expression>
+where
+    ST: SqlType + SingleValue,
+    E: AsExpression<ST>,
+{
+    ArrayAgg {
+        expr: expr.as_expression(),
+        order: NoOrderClause,
+        distinct: NoDistinctClause,
+        _marker: PhantomData,
+    }
+}
+
+/// The return type of [`array_agg(expr)`](array_agg())
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct ArrayAgg<ST, E, Order = NoOrderClause, Distinct = NoDistinctClause> {
+    expr: E,
+    order: Order,
+    distinct: Distinct,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST, E, Order, Distinct> ArrayAgg<ST, E, Order, Distinct> {
+    /// Adds an `ORDER BY` clause to this `ARRAY_AGG` expression, controlling
+    /// the order of the elements in the resulting array.
+    pub fn order<Expr>(self, expr: Expr) -> ArrayAgg<ST, E, OrderClause<Expr>, Distinct>
+    where
+        Expr: Expression,
+    {
+        ArrayAgg {
+            expr: self.expr,
+            order: OrderClause(expr),
+            distinct: self.distinct,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Turns this expression into `ARRAY_AGG(DISTINCT expr)`, removing
+    /// duplicate values from the resulting array.
+    pub fn distinct(self) -> ArrayAgg<ST, E, Order, DistinctClause> {
+        ArrayAgg {
+            expr: self.expr,
+            order: self.order,
+            distinct: DistinctClause,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ST, E, Order, Distinct> Expression for ArrayAgg<ST, E, Order, Distinct>
+where
+    ST: SqlType + SingleValue,
+    E: Expression,
+{
+    type SqlType = Nullable<Array<ST>>;
+}
+
+impl<ST, E, Order, Distinct, GB> ValidGrouping<GB> for ArrayAgg<ST, E, Order, Distinct>
+where
+    ST: SqlType + SingleValue,
+{
+    type IsAggregate = is_aggregate::Yes;
+}
+
+impl<ST, E, Order, Distinct, QS> SelectableExpression<QS> for ArrayAgg<ST, E, Order, Distinct>
+where
+    Self: AppearsOnTable<QS>,
+    E: SelectableExpression<QS>,
+{
+}
+
+impl<ST, E, Order, Distinct, QS> AppearsOnTable<QS> for ArrayAgg<ST, E, Order, Distinct>
+where
+    Self: Expression,
+    E: AppearsOnTable<QS>,
+{
+}
+
+impl<ST, E, Order, Distinct> QueryFragment<Pg> for ArrayAgg<ST, E, Order, Distinct>
+where
+    ST: SqlType + SingleValue,
+    E: QueryFragment<Pg>,
+    Order: QueryFragment<Pg>,
+    Distinct: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("ARRAY_AGG(");
+        self.distinct.walk_ast(out.reborrow())?;
+        self.expr.walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Creates a PostgreSQL `STRING_AGG` expression
+///
+/// Concatenates all values of the given text expression, separated by the
+/// given delimiter. As no rows result in a `NULL` value instead of an empty
+/// string, the return type is nullable.
+///
+/// Use [`order`](StringAgg::order()) to control the order in which the
+/// values are concatenated.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     use diesel::dsl::string_agg;
+/// #     let connection = &mut establish_connection();
+/// let species_list = animals
+///     .select(string_agg(species, ", ").order(species))
+///     .first::<Option<String>>(connection)?;
+/// assert_eq!(Some("dog, spider".to_owned()), species_list);
+/// #     Ok(())
+/// # }
+/// #
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn run_test() -> QueryResult<()> {
+/// #     Ok(())
+/// # }
+/// ```
+pub fn string_agg<E, D>(expr: E, delimiter: D) -> StringAgg<E::Expression, D::Expression>
+where
+    E: AsExpression<Text>,
+    D: AsExpression<Text>,
+{
+    StringAgg {
+        expr: expr.as_expression(),
+        delimiter: delimiter.as_expression(),
+        order: NoOrderClause,
+    }
+}
+
+/// The return type of [`string_agg(expr, delimiter)`](string_agg())
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct StringAgg<E, D, Order = NoOrderClause> {
+    expr: E,
+    delimiter: D,
+    order: Order,
+}
+
+impl<E, D, Order> StringAgg<E, D, Order> {
+    /// Adds an `ORDER BY` clause to this `STRING_AGG` expression, controlling
+    /// the order in which the values are concatenated.
+    pub fn order<Expr>(self, expr: Expr) -> StringAgg<E, D, OrderClause<Expr>>
+    where
+        Expr: Expression,
+    {
+        StringAgg {
+            expr: self.expr,
+            delimiter: self.delimiter,
+            order: OrderClause(expr),
+        }
+    }
+}
+
+impl<E, D, Order> Expression for StringAgg<E, D, Order>
+where
+    E: Expression,
+    D: Expression,
+{
+    type SqlType = Nullable<Text>;
+}
+
+impl<E, D, Order, GB> ValidGrouping<GB> for StringAgg<E, D, Order> {
+    type IsAggregate = is_aggregate::Yes;
+}
+
+impl<E, D, Order, QS> SelectableExpression<QS> for StringAgg<E, D, Order>
+where
+    Self: AppearsOnTable<QS>,
+    E: SelectableExpression<QS>,
+{
+}
+
+impl<E, D, Order, QS> AppearsOnTable<QS> for StringAgg<E, D, Order>
+where
+    Self: Expression,
+    E: AppearsOnTable<QS>,
+{
+}
+
+impl<E, D, Order> QueryFragment<Pg> for StringAgg<E, D, Order>
+where
+    E: QueryFragment<Pg>,
+    D: QueryFragment<Pg>,
+    Order: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("STRING_AGG(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.delimiter.walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// The return type of
+/// [`agg.filter_aggregate(predicate)`](crate::expression_methods::PgAggregateExpressionMethods::filter_aggregate())
+///
+/// Represents an aggregate expression with an attached
+/// `FILTER (WHERE predicate)` clause.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Filtered<Agg, Pred> {
+    aggregate: Agg,
+    predicate: Pred,
+}
+
+impl<Agg, Pred> Filtered<Agg, Pred> {
+    pub(crate) fn new(aggregate: Agg, predicate: Pred) -> Self {
+        Filtered {
+            aggregate,
+            predicate,
+        }
+    }
+}
+
+impl<Agg, Pred> Expression for Filtered<Agg, Pred>
+where
+    Agg: Expression,
+{
+    type SqlType = Agg::SqlType;
+}
+
+impl<Agg, Pred, GB> ValidGrouping<GB> for Filtered<Agg, Pred> {
+    type IsAggregate = is_aggregate::Yes;
+}
+
+impl<Agg, Pred, QS> SelectableExpression<QS> for Filtered<Agg, Pred>
+where
+    Self: AppearsOnTable<QS>,
+    Agg: SelectableExpression<QS>,
+    Pred: SelectableExpression<QS>,
+{
+}
+
+impl<Agg, Pred, QS> AppearsOnTable<QS> for Filtered<Agg, Pred>
+where
+    Self: Expression,
+    Agg: AppearsOnTable<QS>,
+    Pred: AppearsOnTable<QS>,
+{
+}
+
+impl<Agg, Pred> QueryFragment<Pg> for Filtered<Agg, Pred>
+where
+    Agg: QueryFragment<Pg>,
+    Pred: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.aggregate.walk_ast(out.reborrow())?;
+        out.push_sql(" FILTER (WHERE ");
+        self.predicate.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::expression::{Expression, ValidGrouping};
+use crate::expression::{AsExpression, Expression, ValidGrouping};
 use crate::pg::Pg;
 use crate::query_builder::*;
 use crate::result::QueryResult;
@@ -48,3 +48,168 @@ where
 }
 
 impl_selectable_expression!(AtTimeZone<Ts, Tz>);
+
+/// The time period to which [`date_trunc()`] truncates its argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateTruncPrecision {
+    /// Truncate to the microsecond
+    Microseconds,
+    /// Truncate to the millisecond
+    Milliseconds,
+    /// Truncate to the second
+    Second,
+    /// Truncate to the minute
+    Minute,
+    /// Truncate to the hour
+    Hour,
+    /// Truncate to the day
+    Day,
+    /// Truncate to the first day of the week
+    Week,
+    /// Truncate to the first day of the month
+    Month,
+    /// Truncate to the first day of the quarter
+    Quarter,
+    /// Truncate to the first day of the year
+    Year,
+    /// Truncate to the first day of the decade
+    Decade,
+    /// Truncate to the first day of the century
+    Century,
+}
+
+impl DateTruncPrecision {
+    fn as_sql(self) -> &'static str {
+        match self {
+            DateTruncPrecision::Microseconds => "'microseconds'",
+            DateTruncPrecision::Milliseconds => "'milliseconds'",
+            DateTruncPrecision::Second => "'second'",
+            DateTruncPrecision::Minute => "'minute'",
+            DateTruncPrecision::Hour => "'hour'",
+            DateTruncPrecision::Day => "'day'",
+            DateTruncPrecision::Week => "'week'",
+            DateTruncPrecision::Month => "'month'",
+            DateTruncPrecision::Quarter => "'quarter'",
+            DateTruncPrecision::Year => "'year'",
+            DateTruncPrecision::Decade => "'decade'",
+            DateTruncPrecision::Century => "'century'",
+        }
+    }
+}
+
+/// Represents the PostgreSQL `DATE_TRUNC` function. Truncates the given
+/// timestamp expression to the given precision, e.g.
+/// `date_trunc(DateTruncPrecision::Month, created_at)` emits
+/// `DATE_TRUNC('month', created_at)`.
+pub fn date_trunc<E>(precision: DateTruncPrecision, expr: E) -> DateTrunc<E::Expression>
+where
+    E: AsExpression<Timestamp>,
+{
+    DateTrunc {
+        precision,
+        expr: expr.as_expression(),
+    }
+}
+
+/// The return type of [`date_trunc(precision, expr)`](date_trunc())
+#[derive(Debug, Clone, Copy, ValidGrouping)]
+pub struct DateTrunc<E> {
+    precision: DateTruncPrecision,
+    expr: E,
+}
+
+impl<E> QueryId for DateTrunc<E> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<E> Expression for DateTrunc<E>
+where
+    E: Expression,
+{
+    type SqlType = Timestamp;
+}
+
+impl_selectable_expression!(DateTrunc<E>);
+
+impl<E> QueryFragment<Pg> for DateTrunc<E>
+where
+    E: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("DATE_TRUNC(");
+        out.push_sql(self.precision.as_sql());
+        out.push_sql(", ");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// Represents the SQL `OVERLAPS` expression. Returns whether the time
+/// period between the first pair of timestamp expressions overlaps the
+/// period between the second pair, e.g.
+/// `overlaps((booked_from, booked_to), (requested_from, requested_to))`
+/// emits
+/// `(booked_from, booked_to) OVERLAPS (requested_from, requested_to)`.
+pub fn overlaps<A1, A2, B1, B2>(
+    first: (A1, A2),
+    second: (B1, B2),
+) -> Overlaps<A1::Expression, A2::Expression, B1::Expression, B2::Expression>
+where
+    A1: AsExpression<Timestamp>,
+    A2: AsExpression<Timestamp>,
+    B1: AsExpression<Timestamp>,
+    B2: AsExpression<Timestamp>,
+{
+    Overlaps {
+        first_start: first.0.as_expression(),
+        first_end: first.1.as_expression(),
+        second_start: second.0.as_expression(),
+        second_end: second.1.as_expression(),
+    }
+}
+
+/// The return type of [`overlaps(first, second)`](overlaps())
+#[derive(Debug, Clone, Copy, QueryId, ValidGrouping)]
+pub struct Overlaps<A1, A2, B1, B2> {
+    first_start: A1,
+    first_end: A2,
+    second_start: B1,
+    second_end: B2,
+}
+
+impl<A1, A2, B1, B2> Expression for Overlaps<A1, A2, B1, B2>
+where
+    A1: Expression,
+    A2: Expression,
+    B1: Expression,
+    B2: Expression,
+{
+    type SqlType = crate::sql_types::Bool;
+}
+
+impl_selectable_expression!(Overlaps<A1, A2, B1, B2>);
+
+impl<A1, A2, B1, B2> QueryFragment<Pg> for Overlaps<A1, A2, B1, B2>
+where
+    A1: QueryFragment<Pg>,
+    A2: QueryFragment<Pg>,
+    B1: QueryFragment<Pg>,
+    B2: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.first_start.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.first_end.walk_ast(out.reborrow())?;
+        out.push_sql(") OVERLAPS (");
+        self.second_start.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.second_end.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
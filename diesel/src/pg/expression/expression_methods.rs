@@ -3,8 +3,10 @@
 use super::operators::*;
 use crate::dsl;
 use crate::expression::grouped::Grouped;
-use crate::expression::{AsExpression, Expression, IntoSql, TypedExpressionType};
-use crate::sql_types::{Array, Cidr, Inet, Nullable, Range, SqlType, Text};
+use crate::expression::{
+    is_aggregate, AsExpression, Expression, IntoSql, TypedExpressionType, ValidGrouping,
+};
+use crate::sql_types::{Array, Bool, Cidr, Inet, Nullable, Range, SqlType, Text};
 
 /// PostgreSQL specific methods which are present on all expressions.
 pub trait PgExpressionMethods: Expression + Sized {
@@ -82,6 +84,10 @@ pub trait PgTimestampExpressionMethods: Expression + Sized {
     /// the value will be converted to the given time zone,
     /// and then have its time zone information removed.
     ///
+    /// In both cases the resulting expression has the SQL type
+    /// `Timestamp`, so the result can be deserialized into types
+    /// without time zone information.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -542,6 +548,92 @@ pub trait PgTextExpressionMethods: Expression + Sized {
     {
         Grouped(NotSimilarTo::new(self, other.as_expression()))
     }
+
+    /// Creates a PostgreSQL `~` (POSIX regular expression match) expression
+    ///
+    /// # Example
+    /// ```
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let starts_with_s = animals
+    ///     .select(species)
+    ///     .filter(name.regex_match("^s").or(species.regex_match("^s")))
+    ///     .get_results::<String>(connection)?;
+    /// assert_eq!(vec!["spider"], starts_with_s);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn regex_match<T>(self, other: T) -> dsl::RegexMatch<Self, T>
+    where
+        T: AsExpression<Text>,
+    {
+        Grouped(RegexMatch::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `~*` (case insensitive POSIX regular expression
+    /// match) expression
+    ///
+    /// # Example
+    /// ```
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let starts_with_s = animals
+    ///     .select(species)
+    ///     .filter(name.regex_match_case_insensitive("^S"))
+    ///     .get_results::<String>(connection)?;
+    /// assert_eq!(vec!["spider"], starts_with_s);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn regex_match_case_insensitive<T>(self, other: T) -> dsl::RegexMatchCaseInsensitive<Self, T>
+    where
+        T: AsExpression<Text>,
+    {
+        Grouped(RegexMatchCaseInsensitive::new(self, other.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `!~` (POSIX regular expression no match)
+    /// expression
+    ///
+    /// # Example
+    /// ```
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let doesnt_start_with_s = animals
+    ///     .select(species)
+    ///     .filter(name.regex_not_match("^s").and(species.regex_not_match("^s")))
+    ///     .get_results::<String>(connection)?;
+    /// assert_eq!(vec!["dog"], doesnt_start_with_s);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn regex_not_match<T>(self, other: T) -> dsl::NotRegexMatch<Self, T>
+    where
+        T: AsExpression<Text>,
+    {
+        Grouped(NotRegexMatch::new(self, other.as_expression()))
+    }
 }
 
 #[doc(hidden)]
@@ -1164,3 +1256,46 @@ impl InetOrCidr for Inet {}
 impl InetOrCidr for Cidr {}
 impl InetOrCidr for Nullable<Inet> {}
 impl InetOrCidr for Nullable<Cidr> {}
+
+/// PostgreSQL specific methods present on aggregate expressions.
+pub trait PgAggregateExpressionMethods: Sized {
+    /// Attaches a `FILTER (WHERE predicate)` clause to this aggregate
+    /// expression, so that only rows for which the predicate is true are
+    /// fed into the aggregate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::animals::dsl::*;
+    /// #     use diesel::dsl::count;
+    /// #     let connection = &mut establish_connection();
+    /// let eight_legged = animals
+    ///     .select(count(id).filter_aggregate(legs.eq(8)))
+    ///     .first::<i64>(connection)?;
+    /// assert_eq!(1, eight_legged);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn filter_aggregate<P>(self, predicate: P) -> dsl::FilterAggregate<Self, P>
+    where
+        Self: ValidGrouping<(), IsAggregate = is_aggregate::Yes>,
+        P: AsExpression<Bool>,
+    {
+        super::aggregates::Filtered::new(self, predicate.as_expression())
+    }
+}
+
+impl<T: Expression> PgAggregateExpressionMethods for T {}
@@ -0,0 +1,164 @@
+use crate::expression::{Expression, ValidGrouping};
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{Bool, Float, Text};
+
+/// A convenience builder for PostgreSQL full text search expressions.
+///
+/// The builder is created with the text expression to search and the name
+/// of the text search configuration to use. Calling
+/// [`query`](FullTextSearch::query()) produces a boolean expression of the
+/// form `to_tsvector(config, column) @@ plainto_tsquery(config, query)`,
+/// suitable for use in `filter`. The configuration name and the query are
+/// sent as bind parameters.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::users::dsl::*;
+/// #     use diesel::pg::expression::dsl::FullTextSearch;
+/// #     let connection = &mut establish_connection();
+/// let search = FullTextSearch::new(name, "english").query("Sean");
+/// let found = users
+///     .filter(search.clone())
+///     .order(search.rank_by(name).desc())
+///     .select(name)
+///     .load::<String>(connection)?;
+/// assert_eq!(vec![String::from("Sean")], found);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FullTextSearch<Col> {
+    column: Col,
+    config: String,
+}
+
+impl<Col> FullTextSearch<Col> {
+    /// Starts building a full text search over the given text expression,
+    /// using the named text search configuration (e.g. `"english"`).
+    pub fn new(column: Col, config: &str) -> Self {
+        FullTextSearch {
+            column,
+            config: config.to_owned(),
+        }
+    }
+
+    /// Completes the builder with the given search terms, returning a
+    /// boolean expression matching rows whose document matches the query.
+    pub fn query(self, query: &str) -> TsMatch<Col> {
+        TsMatch {
+            column: self.column,
+            config: self.config,
+            query: query.to_owned(),
+        }
+    }
+}
+
+/// The return type of [`FullTextSearch::query()`]
+#[derive(Debug, Clone, ValidGrouping)]
+pub struct TsMatch<Col> {
+    column: Col,
+    config: String,
+    query: String,
+}
+
+impl<Col> TsMatch<Col> {
+    /// Returns a `ts_rank` expression ranking the given text expression
+    /// against this search's query, for use in `order`.
+    pub fn rank_by<R>(&self, column: R) -> TsRank<R> {
+        TsRank {
+            column,
+            config: self.config.clone(),
+            query: self.query.clone(),
+        }
+    }
+}
+
+impl<Col> QueryId for TsMatch<Col>
+where
+    Col: QueryId,
+{
+    type QueryId = TsMatch<Col::QueryId>;
+
+    const HAS_STATIC_QUERY_ID: bool = Col::HAS_STATIC_QUERY_ID;
+}
+
+impl<Col> Expression for TsMatch<Col>
+where
+    Col: Expression,
+{
+    type SqlType = Bool;
+}
+
+impl_selectable_expression!(TsMatch<Col>);
+
+impl<Col> QueryFragment<Pg> for TsMatch<Col>
+where
+    Col: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("to_tsvector(");
+        out.push_bind_param::<Text, _>(&self.config)?;
+        out.push_sql("::regconfig, ");
+        self.column.walk_ast(out.reborrow())?;
+        out.push_sql(") @@ plainto_tsquery(");
+        out.push_bind_param::<Text, _>(&self.config)?;
+        out.push_sql("::regconfig, ");
+        out.push_bind_param::<Text, _>(&self.query)?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// The return type of [`TsMatch::rank_by()`]
+#[derive(Debug, Clone, ValidGrouping)]
+pub struct TsRank<Col> {
+    column: Col,
+    config: String,
+    query: String,
+}
+
+impl<Col> QueryId for TsRank<Col>
+where
+    Col: QueryId,
+{
+    type QueryId = TsRank<Col::QueryId>;
+
+    const HAS_STATIC_QUERY_ID: bool = Col::HAS_STATIC_QUERY_ID;
+}
+
+impl<Col> Expression for TsRank<Col>
+where
+    Col: Expression,
+{
+    type SqlType = Float;
+}
+
+impl_selectable_expression!(TsRank<Col>);
+
+impl<Col> QueryFragment<Pg> for TsRank<Col>
+where
+    Col: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("ts_rank(to_tsvector(");
+        out.push_bind_param::<Text, _>(&self.config)?;
+        out.push_sql("::regconfig, ");
+        self.column.walk_ast(out.reborrow())?;
+        out.push_sql("), plainto_tsquery(");
+        out.push_bind_param::<Text, _>(&self.config)?;
+        out.push_sql("::regconfig, ");
+        out.push_bind_param::<Text, _>(&self.query)?;
+        out.push_sql("))");
+        Ok(())
+    }
+}
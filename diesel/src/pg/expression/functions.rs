@@ -51,3 +51,30 @@ sql_function! {
     /// netmask are set to zero.
     fn set_masklen<T: InetOrCidr + SingleValue>(addr: T, len: Integer) -> T;
 }
+sql_function! {
+    /// Aggregates all values of the given expression into a single JSON
+    /// array. As no rows result in a `NULL` value instead of an empty array,
+    /// the return type is nullable.
+    #[aggregate]
+    fn json_agg<T: SqlType + SingleValue>(expr: T) -> Nullable<Json>;
+}
+sql_function! {
+    /// Aggregates key/value pairs into a single JSON object. As no rows
+    /// result in a `NULL` value instead of an empty object, the return type
+    /// is nullable.
+    #[aggregate]
+    fn json_object_agg<K: SqlType + SingleValue, V: SqlType + SingleValue>(key: K, value: V) -> Nullable<Json>;
+}
+sql_function! {
+    /// Returns the captures of the first match of the POSIX regular
+    /// expression `pattern` against the given text expression, or `NULL`
+    /// if there is no match.
+    fn regexp_match(string: Text, pattern: Text) -> Nullable<Array<Nullable<Text>>>;
+}
+sql_function! {
+    /// Returns the interval between the given timestamp and the current
+    /// date at midnight. Use together with
+    /// [`now`](crate::dsl::now), which can be coerced to `Timestamptz`,
+    /// to filter on recent rows.
+    fn age(timestamp: Timestamptz) -> Interval;
+}
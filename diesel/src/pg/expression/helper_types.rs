@@ -15,6 +15,17 @@ pub type SimilarTo<Lhs, Rhs> = Grouped<super::operators::SimilarTo<Lhs, AsExprOf
 pub type NotSimilarTo<Lhs, Rhs> =
     Grouped<super::operators::NotSimilarTo<Lhs, AsExprOf<Rhs, VarChar>>>;
 
+/// The return type of `lhs.regex_match(rhs)`
+pub type RegexMatch<Lhs, Rhs> = Grouped<super::operators::RegexMatch<Lhs, AsExprOf<Rhs, VarChar>>>;
+
+/// The return type of `lhs.regex_match_case_insensitive(rhs)`
+pub type RegexMatchCaseInsensitive<Lhs, Rhs> =
+    Grouped<super::operators::RegexMatchCaseInsensitive<Lhs, AsExprOf<Rhs, VarChar>>>;
+
+/// The return type of `lhs.regex_not_match(rhs)`
+pub type NotRegexMatch<Lhs, Rhs> =
+    Grouped<super::operators::NotRegexMatch<Lhs, AsExprOf<Rhs, VarChar>>>;
+
 /// The return type of `lhs.is_not_distinct_from(rhs)`
 pub type IsNotDistinctFrom<Lhs, Rhs> =
     Grouped<super::operators::IsNotDistinctFrom<Lhs, AsExpr<Rhs, Lhs>>>;
@@ -78,3 +89,7 @@ pub type OrNet<Lhs, Rhs> = Grouped<super::operators::OrNet<Lhs, AsExprOf<Rhs, In
 /// The return type of `lsh.diff(rhs)`
 pub type DifferenceNet<Lhs, Rhs> =
     Grouped<super::operators::DifferenceNet<Lhs, AsExprOf<Rhs, Inet>>>;
+
+/// The return type of `agg.filter_aggregate(predicate)`
+pub type FilterAggregate<Agg, Pred> =
+    super::aggregates::Filtered<Agg, AsExprOf<Pred, crate::sql_types::Bool>>;
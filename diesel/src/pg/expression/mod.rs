@@ -4,6 +4,7 @@
 //! You should rely on the re-exports rather than this module directly. It is
 //! kept separate purely for documentation purposes.
 
+pub(crate) mod aggregates;
 pub(crate) mod array;
 #[doc(hidden)]
 pub mod array_comparison;
@@ -14,8 +15,10 @@ pub mod functions;
 pub mod helper_types;
 #[doc(hidden)]
 pub mod operators;
+pub(crate) mod window_functions;
 
 mod date_and_time;
+mod full_text_search;
 
 /// PostgreSQL specific expression DSL methods.
 ///
@@ -26,9 +29,33 @@ pub mod dsl {
     #[doc(inline)]
     pub use super::array_comparison::{all, any};
 
+    #[doc(inline)]
+    pub use super::aggregates::{array_agg, string_agg};
+
+    #[doc(inline)]
+    pub use super::window_functions::{first_value, lag, last_value, lead, nth_value};
+
+    #[doc(inline)]
+    pub use crate::pg::query_builder::series::generate_series;
+
+    #[doc(inline)]
+    pub use crate::pg::query_builder::unnest::unnest;
+
+    #[doc(inline)]
+    pub use crate::pg::query_builder::lateral::{CrossJoinLateral, CrossJoinLateralDsl};
+
     #[doc(inline)]
     pub use super::array::array;
 
+    #[doc(inline)]
+    pub use super::date_and_time::{date_trunc, DateTruncPrecision};
+
+    #[doc(inline)]
+    pub use super::date_and_time::overlaps;
+
+    #[doc(inline)]
+    pub use super::full_text_search::FullTextSearch;
+
     pub use super::extensions::*;
 
     #[cfg(not(feature = "sqlite"))]
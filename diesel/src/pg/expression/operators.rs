@@ -11,6 +11,9 @@ infix_operator!(ILike, " ILIKE ", backend: Pg);
 infix_operator!(NotILike, " NOT ILIKE ", backend: Pg);
 infix_operator!(SimilarTo, " SIMILAR TO ", backend: Pg);
 infix_operator!(NotSimilarTo, " NOT SIMILAR TO ", backend: Pg);
+infix_operator!(RegexMatch, " ~ ", backend: Pg);
+infix_operator!(RegexMatchCaseInsensitive, " ~* ", backend: Pg);
+infix_operator!(NotRegexMatch, " !~ ", backend: Pg);
 postfix_operator!(NullsFirst, " NULLS FIRST", NotSelectable, backend: Pg);
 postfix_operator!(NullsLast, " NULLS LAST", NotSelectable, backend: Pg);
 infix_operator!(ContainsNet, " >> ", backend: Pg);
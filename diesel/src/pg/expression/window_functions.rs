@@ -0,0 +1,272 @@
+//! PostgreSQL specific window functions
+
+use std::marker::PhantomData;
+
+use crate::expression::functions::window::OverDsl;
+use crate::expression::{is_aggregate, AsExpression, Expression, ValidGrouping};
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{BigInt, Nullable, SingleValue, SqlType};
+
+/// The absence of an optional window function argument
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct NoArg;
+
+impl QueryFragment<Pg> for NoArg {
+    fn walk_ast(&self, _: AstPass<Pg>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+/// An optional window function argument, emitted as `, arg`
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Arg<E>(E);
+
+impl<E> QueryFragment<Pg> for Arg<E>
+where
+    E: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql(", ");
+        self.0.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+macro_rules! offset_window_function {
+    ($(#[$meta:meta])* $fn_name:ident, $type_name:ident, $sql:expr) => {
+        $(#[$meta])*
+        ///
+        /// The returned expression evaluates the given expression on a
+        /// neighbouring row of the current window partition. Use
+        /// [`offset`](Self::offset()) to control the distance and
+        /// [`default_value`](Self::default_value()) to replace the `NULL`
+        /// returned when no such row exists.
+        ///
+        /// This expression is only usable in combination with a window
+        /// specification. See
+        /// [`over`](crate::expression::functions::window::OverDsl::over())
+        /// for details.
+        pub fn $fn_name<ST, E>(expr: E) -> $type_name<ST, E::Expression>
+        where
+            ST: SqlType + SingleValue,
+            E: AsExpression<ST>,
+        {
+            $type_name {
+                expr: expr.as_expression(),
+                offset: NoArg,
+                default: NoArg,
+                _marker: PhantomData,
+            }
+        }
+
+        /// The return type of the corresponding window function
+        #[derive(Debug, Clone, Copy, QueryId)]
+        pub struct $type_name<ST, E, Offset = NoArg, Default = NoArg> {
+            expr: E,
+            offset: Offset,
+            default: Default,
+            _marker: PhantomData<ST>,
+        }
+
+        impl<ST, E, Offset, Default> $type_name<ST, E, Offset, Default> {
+            /// Sets the offset of the row to look at, relative to the
+            /// current row. Defaults to 1 if not given.
+            pub fn offset<O>(self, offset: O) -> $type_name<ST, E, Arg<O::Expression>, Default>
+            where
+                O: AsExpression<BigInt>,
+            {
+                $type_name {
+                    expr: self.expr,
+                    offset: Arg(offset.as_expression()),
+                    default: self.default,
+                    _marker: PhantomData,
+                }
+            }
+
+            /// Sets the value returned when the offset row does not exist,
+            /// instead of `NULL`. Requires an explicit offset.
+            pub fn default_value<D>(self, default: D) -> $type_name<ST, E, Offset, Arg<D::Expression>>
+            where
+                ST: SqlType + SingleValue,
+                D: AsExpression<ST>,
+            {
+                $type_name {
+                    expr: self.expr,
+                    offset: self.offset,
+                    default: Arg(default.as_expression()),
+                    _marker: PhantomData,
+                }
+            }
+        }
+
+        impl<ST, E, Offset, Default> Expression for $type_name<ST, E, Offset, Default>
+        where
+            ST: SqlType + SingleValue,
+            E: Expression,
+        {
+            type SqlType = Nullable<ST>;
+        }
+
+        impl<ST, E, Offset, Default, GB> ValidGrouping<GB> for $type_name<ST, E, Offset, Default> {
+            type IsAggregate = is_aggregate::No;
+        }
+
+        impl<ST, E, Offset, Default> OverDsl for $type_name<ST, E, Offset, Default> {}
+
+        impl<ST, E, Offset, Default> QueryFragment<Pg> for $type_name<ST, E, Offset, Default>
+        where
+            E: QueryFragment<Pg>,
+            Offset: QueryFragment<Pg>,
+            Default: QueryFragment<Pg>,
+        {
+            fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+                out.push_sql(concat!($sql, "("));
+                self.expr.walk_ast(out.reborrow())?;
+                self.offset.walk_ast(out.reborrow())?;
+                self.default.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+    };
+}
+
+offset_window_function! {
+    /// Creates a PostgreSQL `LAG` window function expression
+    lag, Lag, "LAG"
+}
+offset_window_function! {
+    /// Creates a PostgreSQL `LEAD` window function expression
+    lead, Lead, "LEAD"
+}
+
+macro_rules! value_window_function {
+    ($(#[$meta:meta])* $fn_name:ident, $type_name:ident, $sql:expr) => {
+        $(#[$meta])*
+        ///
+        /// The returned expression evaluates the given expression on another
+        /// row of the current window frame.
+        ///
+        /// This expression is only usable in combination with a window
+        /// specification. See
+        /// [`over`](crate::expression::functions::window::OverDsl::over())
+        /// for details.
+        pub fn $fn_name<ST, E>(expr: E) -> $type_name<ST, E::Expression>
+        where
+            ST: SqlType + SingleValue,
+            E: AsExpression<ST>,
+        {
+            $type_name {
+                expr: expr.as_expression(),
+                _marker: PhantomData,
+            }
+        }
+
+        /// The return type of the corresponding window function
+        #[derive(Debug, Clone, Copy, QueryId)]
+        pub struct $type_name<ST, E> {
+            expr: E,
+            _marker: PhantomData<ST>,
+        }
+
+        impl<ST, E> Expression for $type_name<ST, E>
+        where
+            ST: SqlType + SingleValue,
+            E: Expression,
+        {
+            type SqlType = ST;
+        }
+
+        impl<ST, E, GB> ValidGrouping<GB> for $type_name<ST, E> {
+            type IsAggregate = is_aggregate::No;
+        }
+
+        impl<ST, E> OverDsl for $type_name<ST, E> {}
+
+        impl<ST, E> QueryFragment<Pg> for $type_name<ST, E>
+        where
+            E: QueryFragment<Pg>,
+        {
+            fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+                out.push_sql(concat!($sql, "("));
+                self.expr.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+    };
+}
+
+value_window_function! {
+    /// Creates a PostgreSQL `FIRST_VALUE` window function expression
+    first_value, FirstValue, "FIRST_VALUE"
+}
+value_window_function! {
+    /// Creates a PostgreSQL `LAST_VALUE` window function expression
+    last_value, LastValue, "LAST_VALUE"
+}
+
+/// Creates a PostgreSQL `NTH_VALUE` window function expression
+///
+/// The returned expression evaluates the given expression on the `n`th row
+/// of the current window frame (counting from 1), or `NULL` if no such row
+/// exists.
+///
+/// This expression is only usable in combination with a window
+/// specification. See
+/// [`over`](crate::expression::functions::window::OverDsl::over())
+/// for details.
+pub fn nth_value<ST, E, N>(expr: E, n: N) -> NthValue<ST, E::Expression, N::Expression>
+where
+    ST: SqlType + SingleValue,
+    E: AsExpression<ST>,
+    N: AsExpression<BigInt>,
+{
+    NthValue {
+        expr: expr.as_expression(),
+        n: n.as_expression(),
+        _marker: PhantomData,
+    }
+}
+
+/// The return type of [`nth_value(expr, n)`](nth_value())
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct NthValue<ST, E, N> {
+    expr: E,
+    n: N,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST, E, N> Expression for NthValue<ST, E, N>
+where
+    ST: SqlType + SingleValue,
+    E: Expression,
+    N: Expression,
+{
+    type SqlType = Nullable<ST>;
+}
+
+impl<ST, E, N, GB> ValidGrouping<GB> for NthValue<ST, E, N> {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<ST, E, N> OverDsl for NthValue<ST, E, N> {}
+
+impl<ST, E, N> QueryFragment<Pg> for NthValue<ST, E, N>
+where
+    E: QueryFragment<Pg>,
+    N: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("NTH_VALUE(");
+        self.expr.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.n.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
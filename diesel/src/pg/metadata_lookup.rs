@@ -12,6 +12,17 @@ use std::collections::HashMap;
 /// Custom implementations of `Connection<Backend = Pg>` should not implement this trait directly.
 /// Instead `GetPgMetadataCache` should be implemented, afterwards the generic implementation will provide
 /// the necessary functions to perform the type lookup.
+///
+/// This is how custom and extension types whose OID is not known at
+/// compile time are resolved. Deriving [`SqlType`](derive@crate::sql_types::SqlType)
+/// with `#[postgres(type_name = "my_type")]` generates a
+/// [`HasSqlType`](crate::sql_types::HasSqlType) implementation which calls
+/// [`lookup_type`](PgMetadataLookup::lookup_type) the first time the type
+/// is used. The lookup queries `pg_type` for the OID, restricted to the
+/// schemas on the current `search_path` (or to the schema given via
+/// `#[postgres(type_name = "my_type", type_schema = "my_schema")]`), and
+/// caches the result in the connection's [`PgMetadataCache`] so subsequent
+/// uses do not hit the database again.
 pub trait PgMetadataLookup {
     /// Determine the type metadata for the given `type_name`
     ///
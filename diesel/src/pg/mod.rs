@@ -5,6 +5,7 @@
 //! PostgreSQL, you may need to work with this module directly.
 
 pub mod expression;
+pub mod pg_stat_statements;
 pub mod types;
 
 mod backend;
@@ -16,10 +17,21 @@ mod transaction;
 mod value;
 
 pub use self::backend::{Pg, PgTypeMetadata};
-pub use self::connection::PgConnection;
+pub use self::connection::{
+    BulkLoader, CopyRow, PgCancelHandle, PgConnection, PgConnectionBuilder, PgCursor,
+    ReplicationChange, ServerInfo, SslMode, TwoPhaseTransaction,
+};
 #[doc(hidden)]
 pub use self::metadata_lookup::{GetPgMetadataCache, PgMetadataCache, PgMetadataLookup};
+pub use self::query_builder::copy_from_program::CopyFromProgram;
+#[cfg(feature = "serde_json")]
+pub use self::query_builder::explain::{ExplainAnalyzeDsl, PlanNode, QueryPlan};
+pub use self::query_builder::sequence_statements::{
+    AlterSequence, CreateSequence, CreateSequenceOwnedBy, DropSequence,
+};
+pub use self::query_builder::DeferConstraints;
 pub use self::query_builder::DistinctOnClause;
+pub use self::query_builder::OverridingSystemValue;
 pub use self::query_builder::PgQueryBuilder;
 pub use self::transaction::TransactionBuilder;
 pub use self::value::PgValue;
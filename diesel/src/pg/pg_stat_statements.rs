@@ -0,0 +1,79 @@
+//! Typed access to the `pg_stat_statements` extension
+//!
+//! [`pg_stat_statements`] tracks planning and execution statistics for
+//! all statements the server executes. The extension must be installed
+//! (`CREATE EXTENSION pg_stat_statements`) and loaded via the server's
+//! `shared_preload_libraries` setting before it can be queried.
+//!
+//! [`pg_stat_statements`]: https://www.postgresql.org/docs/current/pgstatstatements.html
+
+use super::PgConnection;
+use crate::prelude::*;
+use crate::result::QueryResult;
+
+/// The execution statistics of one normalized statement
+///
+/// Statistics are accumulated per normalized query text, so all
+/// executions of the same statement with different bind values are
+/// reported as one entry. All times are in milliseconds.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct PgStatStatement {
+    /// The normalized text of the statement
+    pub query: String,
+    /// How often the statement was executed
+    pub calls: i64,
+    /// The total time spent executing the statement
+    pub total_exec_time: f64,
+    /// The mean execution time of a single call
+    pub mean_exec_time: f64,
+    /// The population standard deviation of the execution time
+    pub stddev_exec_time: f64,
+}
+
+/// Loads the statistics tracked by `pg_stat_statements`
+///
+/// Statements are returned in descending order of
+/// [`total_exec_time`](PgStatStatement::total_exec_time), so the
+/// statements the server spends the most time on come first. Fails if
+/// the extension is not installed and preloaded.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let conn = &mut establish_connection();
+/// for stat in diesel::pg::pg_stat_statements::load(conn)? {
+///     println!("{:10.2}ms {:6} calls {}", stat.total_exec_time, stat.calls, stat.query);
+/// }
+/// #     Ok(())
+/// # }
+/// ```
+pub fn load(conn: &mut PgConnection) -> QueryResult<Vec<PgStatStatement>> {
+    use crate::dsl::sql;
+    use crate::sql_types::{BigInt, Double, Text};
+
+    let rows = crate::select(sql::<(Text, BigInt, Double, Double, Double)>(
+        "query, calls, total_exec_time, mean_exec_time, stddev_exec_time \
+         FROM pg_stat_statements ORDER BY total_exec_time DESC",
+    ))
+    .load::<(String, i64, f64, f64, f64)>(conn)?;
+    Ok(rows
+        .into_iter()
+        .map(
+            |(query, calls, total_exec_time, mean_exec_time, stddev_exec_time)| PgStatStatement {
+                query,
+                calls,
+                total_exec_time,
+                mean_exec_time,
+                stddev_exec_time,
+            },
+        )
+        .collect())
+}
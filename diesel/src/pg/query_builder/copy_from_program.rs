@@ -0,0 +1,111 @@
+//! A `COPY … FROM PROGRAM` statement for external data ingestion
+
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::query_source::Table;
+use crate::result::QueryResult;
+
+/// Builds a SQL `COPY … FROM PROGRAM` statement
+///
+/// This lets PostgreSQL populate a table from the standard output of an
+/// external command, e.g. to ingest compressed files or network streams
+/// without materializing them on disk first. The table is given as the
+/// type generated by [`table!`]. Note that `FROM PROGRAM` requires
+/// superuser privileges or membership in the `pg_execute_server_program`
+/// role, and the command runs on the database server.
+///
+/// Calling [`delimiter`](CopyFromProgram::delimiter()) or
+/// [`header`](CopyFromProgram::header()) switches the statement to
+/// `FORMAT CSV`; without them the data is expected in PostgreSQL's
+/// text format.
+///
+/// [`table!`]: crate::table!
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::pg::CopyFromProgram;
+/// # use schema::users;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `COPY "users" FROM PROGRAM 'gzip -dc /tmp/users.csv.gz'
+/// //            WITH (FORMAT CSV, DELIMITER ',', HEADER)`
+/// CopyFromProgram::new(users::table, "gzip -dc /tmp/users.csv.gz")
+///     .delimiter(',')
+///     .header(true)
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CopyFromProgram<T> {
+    table: T,
+    command: String,
+    delimiter: Option<char>,
+    header: bool,
+}
+
+impl<T: Table> CopyFromProgram<T> {
+    /// Starts a `COPY … FROM PROGRAM` statement copying the output of the
+    /// given command into the given table
+    pub fn new(table: T, command: &str) -> Self {
+        CopyFromProgram {
+            table,
+            command: command.to_owned(),
+            delimiter: None,
+            header: false,
+        }
+    }
+
+    /// Sets the character separating the columns of the input
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Declares whether the first line of the input is a header to skip
+    pub fn header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+impl<T> QueryId for CopyFromProgram<T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T> QueryFragment<Pg> for CopyFromProgram<T>
+where
+    T: Table,
+    T::FromClause: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("COPY ");
+        self.table.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" FROM PROGRAM '");
+        out.push_sql(&self.command.replace('\'', "''"));
+        out.push_sql("'");
+        if self.delimiter.is_some() || self.header {
+            out.push_sql(" WITH (FORMAT CSV");
+            if let Some(delimiter) = self.delimiter {
+                out.push_sql(&format!(
+                    ", DELIMITER '{}'",
+                    delimiter.to_string().replace('\'', "''"),
+                ));
+            }
+            if self.header {
+                out.push_sql(", HEADER");
+            }
+            out.push_sql(")");
+        }
+        Ok(())
+    }
+}
+
+impl<T, Conn> RunQueryDsl<Conn> for CopyFromProgram<T> {}
@@ -0,0 +1,96 @@
+use crate::connection::Connection;
+use crate::pg::{Pg, PgQueryBuilder};
+use crate::query_builder::{InsertStatement, QueryBuilder};
+use crate::query_dsl::methods::ExecuteDsl;
+use crate::query_dsl::RunQueryDsl;
+use crate::result::QueryResult;
+
+/// A statement which defers a constraint before executing
+///
+/// See [`InsertStatement::defer_constraint`] for details.
+///
+/// [`InsertStatement::defer_constraint`]: crate::query_builder::InsertStatement::defer_constraint()
+#[derive(Debug, Clone)]
+#[must_use = "Queries are only executed when calling `execute`"]
+pub struct DeferConstraints<Stmt> {
+    statement: Stmt,
+    constraint: String,
+}
+
+impl<T, U, Op, Ret> InsertStatement<T, U, Op, Ret> {
+    /// Defers the given constraint until the end of the current
+    /// transaction before executing this statement
+    ///
+    /// This issues `SET CONSTRAINTS <name> DEFERRED` ahead of the
+    /// insert, so the named constraint is only checked when the
+    /// transaction commits. This allows inserting records with circular
+    /// foreign key references within a single transaction. The
+    /// constraint must be declared `DEFERRABLE` and, like
+    /// `SET CONSTRAINTS` itself, this only has an effect inside a
+    /// transaction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     nodes (id) {
+    /// #         id -> Integer,
+    /// #         parent -> Integer,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::nodes::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     conn.execute(
+    /// #         "CREATE TABLE nodes (
+    /// #              id INT PRIMARY KEY,
+    /// #              parent INT,
+    /// #              CONSTRAINT nodes_parent_fkey FOREIGN KEY (parent)
+    /// #                  REFERENCES nodes (id) DEFERRABLE INITIALLY IMMEDIATE
+    /// #          )",
+    /// #     )?;
+    /// // Each row references the other, so neither insert passes the
+    /// // foreign key check on its own
+    /// diesel::insert_into(nodes)
+    ///     .values(&vec![(id.eq(1), parent.eq(2)), (id.eq(2), parent.eq(1))])
+    ///     .defer_constraint("nodes_parent_fkey")
+    ///     .execute(conn)?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn defer_constraint(self, name: &str) -> DeferConstraints<Self> {
+        DeferConstraints {
+            statement: self,
+            constraint: name.to_owned(),
+        }
+    }
+}
+
+impl<Conn, Stmt> RunQueryDsl<Conn> for DeferConstraints<Stmt> {}
+
+impl<Conn, Stmt> ExecuteDsl<Conn, Pg> for DeferConstraints<Stmt>
+where
+    Conn: Connection<Backend = Pg>,
+    Stmt: ExecuteDsl<Conn, Pg>,
+{
+    fn execute(query: Self, conn: &mut Conn) -> QueryResult<usize> {
+        let mut query_builder = PgQueryBuilder::default();
+        query_builder.push_sql("SET CONSTRAINTS ");
+        query_builder.push_identifier(&query.constraint)?;
+        query_builder.push_sql(" DEFERRED");
+        conn.execute(&query_builder.finish())?;
+        ExecuteDsl::execute(query.statement, conn)
+    }
+}
@@ -0,0 +1,181 @@
+//! Running `EXPLAIN ANALYZE` on a query, returning a structured plan
+
+extern crate serde_json;
+
+use self::serde_json::Value;
+use crate::connection::Connection;
+use crate::pg::Pg;
+use crate::query_builder::{AsQuery, AstPass, Query, QueryFragment, QueryId};
+use crate::query_dsl::load_dsl::LoadQuery;
+use crate::query_dsl::RunQueryDsl;
+use crate::result::{Error, QueryResult};
+use crate::sql_types::Json;
+
+/// The `explain_analyze` method
+///
+/// This trait is implemented for all queries and should not need to be
+/// implemented manually.
+pub trait ExplainAnalyzeDsl: AsQuery + Sized {
+    /// Executes this query with `EXPLAIN (ANALYZE, FORMAT JSON)`,
+    /// returning the query plan chosen by PostgreSQL
+    ///
+    /// Note that `ANALYZE` actually executes the query. Side effects of
+    /// data modifying statements are applied, so those should only be
+    /// explained inside a transaction which is rolled back afterwards.
+    ///
+    /// This is mostly useful for asserting on performance characteristics
+    /// of a query in a test suite:
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::pg::ExplainAnalyzeDsl;
+    /// # use schema::users;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let connection = &mut establish_connection();
+    /// let plan = users::table
+    ///     .find(1)
+    ///     .explain_analyze(connection)?;
+    /// assert!(plan.plan.contains_node_type("Index Scan"));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn explain_analyze<Conn>(self, conn: &mut Conn) -> QueryResult<QueryPlan>
+    where
+        Conn: Connection<Backend = Pg>,
+        ExplainAnalyze<Self::Query>: LoadQuery<Conn, Value>,
+    {
+        let mut rows = ExplainAnalyze {
+            query: self.as_query(),
+        }
+        .internal_load(conn)?;
+        let value = rows
+            .pop()
+            .ok_or_else(|| deserialize_error("`EXPLAIN` did not return a plan"))?;
+        QueryPlan::from_value(&value)
+    }
+}
+
+impl<T: AsQuery> ExplainAnalyzeDsl for T {}
+
+/// The query constructed by
+/// [`explain_analyze`](ExplainAnalyzeDsl::explain_analyze())
+#[derive(Debug, Clone, Copy)]
+pub struct ExplainAnalyze<Q> {
+    query: Q,
+}
+
+impl<Q> Query for ExplainAnalyze<Q> {
+    type SqlType = Json;
+}
+
+impl<Q> QueryId for ExplainAnalyze<Q>
+where
+    Q: QueryId,
+{
+    type QueryId = ExplainAnalyze<Q::QueryId>;
+
+    const HAS_STATIC_QUERY_ID: bool = Q::HAS_STATIC_QUERY_ID;
+}
+
+impl<Q> QueryFragment<Pg> for ExplainAnalyze<Q>
+where
+    Q: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("EXPLAIN (ANALYZE, FORMAT JSON) ");
+        self.query.walk_ast(out.reborrow())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for ExplainAnalyze<Q> {}
+
+/// A query plan returned by
+/// [`explain_analyze`](ExplainAnalyzeDsl::explain_analyze())
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryPlan {
+    /// The time spent planning the query, in milliseconds
+    pub planning_time: Option<f64>,
+    /// The time spent executing the query, in milliseconds
+    pub execution_time: Option<f64>,
+    /// The root node of the plan tree
+    pub plan: PlanNode,
+}
+
+impl QueryPlan {
+    fn from_value(value: &Value) -> QueryResult<Self> {
+        let root = value
+            .as_array()
+            .and_then(|array| array.first())
+            .and_then(Value::as_object)
+            .ok_or_else(|| deserialize_error("expected a one element array of plans"))?;
+        let plan = root
+            .get("Plan")
+            .ok_or_else(|| deserialize_error("plan has no `Plan` entry"))?;
+        Ok(QueryPlan {
+            planning_time: root.get("Planning Time").and_then(Value::as_f64),
+            execution_time: root.get("Execution Time").and_then(Value::as_f64),
+            plan: PlanNode::from_value(plan)?,
+        })
+    }
+}
+
+/// A single node of a [`QueryPlan`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlanNode {
+    /// The type of this node, e.g. `Seq Scan` or `Index Scan`
+    pub node_type: String,
+    /// The estimated total cost of this node
+    pub total_cost: Option<f64>,
+    /// The number of rows actually returned by this node
+    pub actual_rows: Option<u64>,
+    /// The time actually spent in this node, in milliseconds
+    pub actual_time: Option<f64>,
+    /// The child nodes this node receives its input from
+    pub plan_nodes: Vec<PlanNode>,
+}
+
+impl PlanNode {
+    /// Returns true if this node or any node below it has the given type
+    pub fn contains_node_type(&self, node_type: &str) -> bool {
+        self.node_type == node_type
+            || self
+                .plan_nodes
+                .iter()
+                .any(|node| node.contains_node_type(node_type))
+    }
+
+    fn from_value(value: &Value) -> QueryResult<Self> {
+        let node = value
+            .as_object()
+            .ok_or_else(|| deserialize_error("expected a plan node to be an object"))?;
+        let node_type = node
+            .get("Node Type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| deserialize_error("plan node has no `Node Type`"))?;
+        let plan_nodes = match node.get("Plans") {
+            Some(children) => children
+                .as_array()
+                .ok_or_else(|| deserialize_error("expected `Plans` to be an array"))?
+                .iter()
+                .map(PlanNode::from_value)
+                .collect::<QueryResult<_>>()?,
+            None => Vec::new(),
+        };
+        Ok(PlanNode {
+            node_type: node_type.to_owned(),
+            total_cost: node.get("Total Cost").and_then(Value::as_f64),
+            actual_rows: node.get("Actual Rows").and_then(Value::as_u64),
+            actual_time: node.get("Actual Total Time").and_then(Value::as_f64),
+            plan_nodes,
+        })
+    }
+}
+
+fn deserialize_error(message: &str) -> Error {
+    Error::DeserializationError(message.to_owned().into())
+}
@@ -0,0 +1,129 @@
+//! `CROSS JOIN LATERAL` support for set-returning functions
+
+use super::series::{SeriesTable, SeriesValue};
+use super::unnest::{Unnest, UnnestValue};
+use super::with_ordinality::{Ordinal, WithOrdinality};
+use crate::expression::{is_aggregate, Expression, ValidGrouping};
+use crate::pg::Pg;
+use crate::query_builder::{AsQuery, AstPass, QueryFragment, QueryId, SelectStatement};
+use crate::query_source::{AppearsInFromClause, Plus, QuerySource};
+use crate::result::QueryResult;
+use crate::SelectableExpression;
+
+/// Combines a query source with a lateral set-returning function
+///
+/// This trait is implemented for all query sources. The right hand side is
+/// evaluated once per row of the left hand side and may refer to its
+/// columns, which is the standard pattern for applying `UNNEST` or
+/// `generate_series` row by row.
+pub trait CrossJoinLateralDsl: QuerySource + Sized {
+    /// Creates a `self CROSS JOIN LATERAL rhs` query source
+    fn cross_join_lateral<Rhs>(self, rhs: Rhs) -> CrossJoinLateral<Self, Rhs>
+    where
+        Rhs: QuerySource,
+    {
+        CrossJoinLateral {
+            left: self,
+            right: rhs,
+        }
+    }
+}
+
+impl<T: QuerySource> CrossJoinLateralDsl for T {}
+
+/// A `left CROSS JOIN LATERAL right` query source
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct CrossJoinLateral<Left, Right> {
+    left: Left,
+    right: Right,
+}
+
+impl<Left, Right> QuerySource for CrossJoinLateral<Left, Right>
+where
+    Left: QuerySource,
+    Right: QuerySource,
+    Self: Clone,
+    (Left::DefaultSelection, Right::DefaultSelection): SelectableExpression<Self>,
+{
+    type FromClause = Self;
+    type DefaultSelection = (Left::DefaultSelection, Right::DefaultSelection);
+
+    fn from_clause(&self) -> Self {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        (
+            self.left.default_selection(),
+            self.right.default_selection(),
+        )
+    }
+}
+
+impl<Left, Right> AsQuery for CrossJoinLateral<Left, Right>
+where
+    Self: QuerySource,
+    <Self as QuerySource>::DefaultSelection:
+        Expression + ValidGrouping<(), IsAggregate = is_aggregate::No>,
+{
+    type SqlType = <<Self as QuerySource>::DefaultSelection as Expression>::SqlType;
+    type Query = SelectStatement<Self>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<Left, Right, QS> AppearsInFromClause<QS> for CrossJoinLateral<Left, Right>
+where
+    Left: AppearsInFromClause<QS>,
+    Right: AppearsInFromClause<QS>,
+    Left::Count: Plus<Right::Count>,
+{
+    type Count = <Left::Count as Plus<Right::Count>>::Output;
+}
+
+impl<Left, Right> QueryFragment<Pg> for CrossJoinLateral<Left, Right>
+where
+    Left: QuerySource,
+    Right: QuerySource,
+    Left::FromClause: QueryFragment<Pg>,
+    Right::FromClause: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.left.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" CROSS JOIN LATERAL ");
+        self.right.from_clause().walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<Left, ST, E> SelectableExpression<CrossJoinLateral<Left, Unnest<ST, E>>> for UnnestValue<ST> where
+    Self: Expression
+{
+}
+
+impl<Left, ST, Start, Stop, Step>
+    SelectableExpression<CrossJoinLateral<Left, SeriesTable<ST, Start, Stop, Step>>>
+    for SeriesValue<ST>
+where
+    Self: Expression,
+{
+}
+
+impl<Left, ST, E> SelectableExpression<CrossJoinLateral<Left, WithOrdinality<Unnest<ST, E>>>>
+    for UnnestValue<ST>
+where
+    Self: Expression,
+{
+}
+
+impl<Left, ST, Start, Stop, Step>
+    SelectableExpression<CrossJoinLateral<Left, WithOrdinality<SeriesTable<ST, Start, Stop, Step>>>>
+    for SeriesValue<ST>
+where
+    Self: Expression,
+{
+}
+
+impl<Left, SRF> SelectableExpression<CrossJoinLateral<Left, WithOrdinality<SRF>>> for Ordinal {}
@@ -1,12 +1,24 @@
 use super::backend::Pg;
-use crate::query_builder::QueryBuilder;
+use crate::query_builder::{QueryBuilder, QUERY_BUILDER_INITIAL_CAPACITY};
 use crate::result::QueryResult;
 
+pub(crate) mod copy_from_program;
+mod deferred_constraints;
 mod distinct_on;
+#[cfg(feature = "serde_json")]
+pub(crate) mod explain;
+pub(crate) mod lateral;
 mod limit_offset;
 pub(crate) mod on_constraint;
+mod overriding_clause;
 mod query_fragment_impls;
+pub(crate) mod sequence_statements;
+pub(crate) mod series;
+pub(crate) mod unnest;
+pub(crate) mod with_ordinality;
+pub use self::deferred_constraints::DeferConstraints;
 pub use self::distinct_on::DistinctOnClause;
+pub use self::overriding_clause::OverridingSystemValue;
 
 /// The PostgreSQL query builder
 #[allow(missing_debug_implementations)]
@@ -18,8 +30,14 @@ pub struct PgQueryBuilder {
 
 impl PgQueryBuilder {
     /// Constructs a new query builder with an empty query
+    ///
+    /// The internal buffer is pre-sized to avoid repeated reallocation
+    /// while the query is built up.
     pub fn new() -> Self {
-        PgQueryBuilder::default()
+        PgQueryBuilder {
+            sql: String::with_capacity(QUERY_BUILDER_INITIAL_CAPACITY),
+            bind_idx: 0,
+        }
     }
 }
 
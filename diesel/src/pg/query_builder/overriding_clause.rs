@@ -0,0 +1,95 @@
+use crate::insertable::{CanInsertInSingleQuery, InsertValues};
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, InsertStatement, QueryFragment, QueryId, ValuesClause};
+use crate::query_source::Table;
+use crate::result::QueryResult;
+
+/// The `OVERRIDING SYSTEM VALUE` clause of an `INSERT` statement
+///
+/// See [`InsertStatement::override_system_value`] for details.
+///
+/// [`InsertStatement::override_system_value`]: crate::query_builder::InsertStatement::override_system_value()
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct OverridingSystemValue<Values>(Values);
+
+impl<Values> CanInsertInSingleQuery<Pg> for OverridingSystemValue<Values>
+where
+    Values: CanInsertInSingleQuery<Pg>,
+{
+    fn rows_to_insert(&self) -> Option<usize> {
+        self.0.rows_to_insert()
+    }
+}
+
+impl<V, Tab> QueryFragment<Pg> for OverridingSystemValue<ValuesClause<V, Tab>>
+where
+    Tab: Table,
+    V: InsertValues<Tab, Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        if self.0.values.is_noop()? {
+            out.push_sql("OVERRIDING SYSTEM VALUE DEFAULT VALUES");
+        } else {
+            out.push_sql("(");
+            self.0.values.column_names(out.reborrow())?;
+            out.push_sql(") OVERRIDING SYSTEM VALUE VALUES (");
+            self.0.values.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+        }
+        Ok(())
+    }
+}
+
+impl<T, V, Tab, Op, Ret> InsertStatement<T, ValuesClause<V, Tab>, Op, Ret> {
+    /// Adds `OVERRIDING SYSTEM VALUE` to the insert statement
+    ///
+    /// Columns declared as `GENERATED ALWAYS AS IDENTITY` reject
+    /// explicitly provided values. This clause instructs PostgreSQL to
+    /// accept the provided values instead of the identity sequence,
+    /// which is useful when restoring or migrating data.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     robots (id) {
+    /// #         id -> Integer,
+    /// #         name -> Text,
+    /// #     }
+    /// # }
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::robots::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// #     conn.execute(
+    /// #         "CREATE TABLE robots (id INT GENERATED ALWAYS AS IDENTITY, name TEXT NOT NULL)",
+    /// #     )?;
+    /// // `id` is `GENERATED ALWAYS AS IDENTITY`, so inserting an
+    /// // explicit value is only accepted with the override
+    /// diesel::insert_into(robots)
+    ///     .values((id.eq(42), name.eq("R2-D2")))
+    ///     .override_system_value()
+    ///     .execute(conn)?;
+    ///
+    /// let ids = robots.select(id).load::<i32>(conn)?;
+    /// assert_eq!(vec![42], ids);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn override_system_value(
+        self,
+    ) -> InsertStatement<T, OverridingSystemValue<ValuesClause<V, Tab>>, Op, Ret> {
+        self.replace_values(OverridingSystemValue)
+    }
+}
@@ -0,0 +1,265 @@
+//! Sequence DDL statements for use in PostgreSQL migration code
+
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::query_source::Column;
+use crate::result::QueryResult;
+
+/// Builds a SQL `CREATE SEQUENCE` statement
+///
+/// Sequences only exist on PostgreSQL, so this statement can only be
+/// executed against a [`PgConnection`](crate::pg::PgConnection).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::pg::CreateSequence;
+/// # use schema::users;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `CREATE SEQUENCE "user_id_seq" INCREMENT BY 1
+/// //            START WITH 1 OWNED BY "users"."id"`
+/// CreateSequence::named("user_id_seq")
+///     .start(1)
+///     .increment(1)
+///     .owned_by(users::id)
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreateSequence {
+    name: String,
+    start: Option<i64>,
+    increment: Option<i64>,
+}
+
+impl CreateSequence {
+    /// Starts a `CREATE SEQUENCE` statement for a sequence with the given
+    /// name
+    pub fn named(name: &str) -> Self {
+        CreateSequence {
+            name: name.to_owned(),
+            start: None,
+            increment: None,
+        }
+    }
+
+    /// Sets the first value of the sequence
+    pub fn start(mut self, start: i64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the step between consecutive sequence values
+    ///
+    /// A negative value makes the sequence descending.
+    pub fn increment(mut self, increment: i64) -> Self {
+        self.increment = Some(increment);
+        self
+    }
+
+    /// Ties the sequence to a column, given as the type generated by
+    /// [`table!`]
+    ///
+    /// The sequence is dropped together with the column or table.
+    ///
+    /// [`table!`]: crate::table!
+    pub fn owned_by<Col>(self, column: Col) -> CreateSequenceOwnedBy<Col>
+    where
+        Col: Column,
+    {
+        CreateSequenceOwnedBy {
+            inner: self,
+            owner: column,
+        }
+    }
+
+    fn walk_base(&self, out: &mut AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("CREATE SEQUENCE ");
+        out.push_identifier(&self.name)?;
+        if let Some(increment) = self.increment {
+            out.push_sql(&format!(" INCREMENT BY {}", increment));
+        }
+        if let Some(start) = self.start {
+            out.push_sql(&format!(" START WITH {}", start));
+        }
+        Ok(())
+    }
+}
+
+impl QueryId for CreateSequence {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl QueryFragment<Pg> for CreateSequence {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.walk_base(&mut out)
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for CreateSequence {}
+
+/// A `CREATE SEQUENCE` statement with an `OWNED BY` clause
+///
+/// Constructed via [`CreateSequence::owned_by`](CreateSequence::owned_by()).
+#[derive(Debug, Clone)]
+pub struct CreateSequenceOwnedBy<Col> {
+    inner: CreateSequence,
+    owner: Col,
+}
+
+impl<Col> QueryId for CreateSequenceOwnedBy<Col> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Col> QueryFragment<Pg> for CreateSequenceOwnedBy<Col>
+where
+    Col: Column + QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        self.inner.walk_base(&mut out)?;
+        out.push_sql(" OWNED BY ");
+        self.owner.walk_ast(out.reborrow())
+    }
+}
+
+impl<Col, Conn> RunQueryDsl<Conn> for CreateSequenceOwnedBy<Col> {}
+
+/// Builds a SQL `ALTER SEQUENCE` statement
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::pg::AlterSequence;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `ALTER SEQUENCE "user_id_seq" RESTART WITH 1000`
+/// AlterSequence::named("user_id_seq")
+///     .restart_with(1000)
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AlterSequence {
+    name: String,
+    restart: Option<i64>,
+    increment: Option<i64>,
+}
+
+impl AlterSequence {
+    /// Starts an `ALTER SEQUENCE` statement for the sequence with the
+    /// given name
+    pub fn named(name: &str) -> Self {
+        AlterSequence {
+            name: name.to_owned(),
+            restart: None,
+            increment: None,
+        }
+    }
+
+    /// Restarts the sequence at the given value
+    pub fn restart_with(mut self, value: i64) -> Self {
+        self.restart = Some(value);
+        self
+    }
+
+    /// Changes the step between consecutive sequence values
+    pub fn increment(mut self, increment: i64) -> Self {
+        self.increment = Some(increment);
+        self
+    }
+}
+
+impl QueryId for AlterSequence {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl QueryFragment<Pg> for AlterSequence {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("ALTER SEQUENCE ");
+        out.push_identifier(&self.name)?;
+        if let Some(increment) = self.increment {
+            out.push_sql(&format!(" INCREMENT BY {}", increment));
+        }
+        if let Some(restart) = self.restart {
+            out.push_sql(&format!(" RESTART WITH {}", restart));
+        }
+        Ok(())
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for AlterSequence {}
+
+/// Builds a SQL `DROP SEQUENCE` statement
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::pg::DropSequence;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `DROP SEQUENCE IF EXISTS "user_id_seq"`
+/// DropSequence::named("user_id_seq")
+///     .if_exists()
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DropSequence {
+    name: String,
+    if_exists: bool,
+}
+
+impl DropSequence {
+    /// Starts a `DROP SEQUENCE` statement for the sequence with the given
+    /// name
+    pub fn named(name: &str) -> Self {
+        DropSequence {
+            name: name.to_owned(),
+            if_exists: false,
+        }
+    }
+
+    /// Adds `IF EXISTS`, so dropping a missing sequence is not an error
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl QueryId for DropSequence {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl QueryFragment<Pg> for DropSequence {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("DROP SEQUENCE ");
+        if self.if_exists {
+            out.push_sql("IF EXISTS ");
+        }
+        out.push_identifier(&self.name)
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for DropSequence {}
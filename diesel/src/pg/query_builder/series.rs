@@ -0,0 +1,197 @@
+//! The `generate_series` set-returning function
+
+use std::marker::PhantomData;
+
+use super::with_ordinality::{SetReturningFunction, WithOrdinality};
+use crate::expression::{is_aggregate, AsExpression, Expression, ValidGrouping};
+use crate::pg::Pg;
+use crate::query_builder::{AsQuery, AstPass, QueryFragment, QueryId, SelectStatement};
+use crate::query_source::{AppearsInFromClause, Never, Once, QuerySource};
+use crate::result::QueryResult;
+use crate::sql_types::{SingleValue, SqlType};
+use crate::{AppearsOnTable, SelectableExpression};
+
+/// Creates a PostgreSQL `generate_series(start, stop, step)` query source
+///
+/// The resulting value can be used like a table in `FROM` position. It
+/// provides a single column named `value` of the series element type, which
+/// is also its default selection.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn run_test() -> QueryResult<()> {
+/// #     use diesel::dsl::generate_series;
+/// #     use diesel::sql_types::Integer;
+/// #     let connection = &mut establish_connection();
+/// let series = generate_series::<Integer, _, _, _>(1, 7, 2)
+///     .load::<i32>(connection)?;
+/// assert_eq!(vec![1, 3, 5, 7], series);
+/// #     Ok(())
+/// # }
+/// #
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn run_test() -> QueryResult<()> {
+/// #     Ok(())
+/// # }
+/// ```
+pub fn generate_series<ST, Start, Stop, Step>(
+    start: Start,
+    stop: Stop,
+    step: Step,
+) -> SeriesTable<ST, Start::Expression, Stop::Expression, Step::Expression>
+where
+    ST: SqlType + SingleValue,
+    Start: AsExpression<ST>,
+    Stop: AsExpression<ST>,
+    Step: AsExpression<ST>,
+{
+    SeriesTable {
+        start: start.as_expression(),
+        stop: stop.as_expression(),
+        step: step.as_expression(),
+        _marker: PhantomData,
+    }
+}
+
+/// The return type of [`generate_series(start, stop, step)`](generate_series())
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct SeriesTable<ST, Start, Stop, Step> {
+    start: Start,
+    stop: Stop,
+    step: Step,
+    _marker: PhantomData<ST>,
+}
+
+/// The `value` column of a [`SeriesTable`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct SeriesValue<ST>(PhantomData<ST>);
+
+impl<ST, Start, Stop, Step> QuerySource for SeriesTable<ST, Start, Stop, Step>
+where
+    ST: SqlType + SingleValue,
+    Self: Clone,
+{
+    type FromClause = Self;
+    type DefaultSelection = SeriesValue<ST>;
+
+    fn from_clause(&self) -> Self {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        SeriesValue(PhantomData)
+    }
+}
+
+impl<ST, Start, Stop, Step> AsQuery for SeriesTable<ST, Start, Stop, Step>
+where
+    ST: SqlType + SingleValue,
+    Self: Clone,
+{
+    type SqlType = ST;
+    type Query = SelectStatement<Self>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<ST, Start, Stop, Step> AppearsInFromClause<SeriesTable<ST, Start, Stop, Step>>
+    for SeriesTable<ST, Start, Stop, Step>
+{
+    type Count = Once;
+}
+
+impl<ST, Start, Stop, Step> AppearsInFromClause<SeriesTable<ST, Start, Stop, Step>> for () {
+    type Count = Never;
+}
+
+impl<ST, Start, Stop, Step> QueryFragment<Pg> for SeriesTable<ST, Start, Stop, Step>
+where
+    Start: QueryFragment<Pg>,
+    Stop: QueryFragment<Pg>,
+    Step: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("generate_series(");
+        self.start.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.stop.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.step.walk_ast(out.reborrow())?;
+        out.push_sql(") AS series (value)");
+        Ok(())
+    }
+}
+
+impl<ST> Expression for SeriesValue<ST>
+where
+    ST: SqlType + SingleValue,
+{
+    type SqlType = ST;
+}
+
+impl<ST, GB> ValidGrouping<GB> for SeriesValue<ST> {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<ST, Start, Stop, Step> SelectableExpression<SeriesTable<ST, Start, Stop, Step>>
+    for SeriesValue<ST>
+where
+    Self: Expression,
+{
+}
+
+impl<ST, QS> AppearsOnTable<QS> for SeriesValue<ST> where Self: Expression {}
+
+impl<ST> QueryFragment<Pg> for SeriesValue<ST> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("series.value");
+        Ok(())
+    }
+}
+
+impl<ST, Start, Stop, Step> SeriesTable<ST, Start, Stop, Step> {
+    /// Attaches a `WITH ORDINALITY` modifier to this query source, adding
+    /// an `ordinality` column counting the generated rows.
+    pub fn with_ordinality(self) -> WithOrdinality<Self> {
+        WithOrdinality::new(self)
+    }
+}
+
+impl<ST, Start, Stop, Step> SetReturningFunction for SeriesTable<ST, Start, Stop, Step>
+where
+    Start: QueryFragment<Pg>,
+    Stop: QueryFragment<Pg>,
+    Step: QueryFragment<Pg>,
+{
+    fn srf_alias(&self) -> &str {
+        "series"
+    }
+
+    fn walk_call(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("generate_series(");
+        self.start.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.stop.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.step.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<ST, Start, Stop, Step> SelectableExpression<WithOrdinality<SeriesTable<ST, Start, Stop, Step>>>
+    for SeriesValue<ST>
+where
+    Self: Expression,
+{
+}
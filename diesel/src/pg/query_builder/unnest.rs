@@ -0,0 +1,212 @@
+//! The `UNNEST` set-returning function
+
+use std::marker::PhantomData;
+
+use super::with_ordinality::{SetReturningFunction, WithOrdinality};
+use crate::expression::{is_aggregate, AsExpression, Expression, ValidGrouping};
+use crate::pg::Pg;
+use crate::query_builder::{AsQuery, AstPass, QueryFragment, QueryId, SelectStatement};
+use crate::query_source::{AppearsInFromClause, Never, Once, QuerySource};
+use crate::result::QueryResult;
+use crate::sql_types::{Array, SingleValue, SqlType};
+use crate::{AppearsOnTable, SelectableExpression};
+
+/// Creates a PostgreSQL `UNNEST(array)` query source
+///
+/// The resulting value can be used like a table in `FROM` position. It
+/// provides a single column named `value` of the array element type, which
+/// is also its default selection. Use [`alias`](Unnest::alias()) to control
+/// the name under which the expanded rows appear in the query.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn run_test() -> QueryResult<()> {
+/// #     use diesel::dsl::unnest;
+/// #     use diesel::sql_types::{Array, Integer};
+/// #     let connection = &mut establish_connection();
+/// let elements = unnest::<Array<Integer>, _>(vec![1, 2, 3])
+///     .load::<i32>(connection)?;
+/// assert_eq!(vec![1, 2, 3], elements);
+/// #     Ok(())
+/// # }
+/// #
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn run_test() -> QueryResult<()> {
+/// #     Ok(())
+/// # }
+/// ```
+pub fn unnest<AT, E>(array: E) -> Unnest<<AT as ArraySqlType>::Element, E::Expression>
+where
+    AT: SqlType + SingleValue + ArraySqlType,
+    E: AsExpression<AT>,
+{
+    Unnest {
+        array: array.as_expression(),
+        alias: "unnest".into(),
+        _marker: PhantomData,
+    }
+}
+
+/// A helper trait to extract the element type out of an `Array` sql type
+pub trait ArraySqlType {
+    /// The element type of this array
+    type Element;
+}
+
+impl<ST> ArraySqlType for Array<ST> {
+    type Element = ST;
+}
+
+/// The return type of [`unnest(array)`](unnest())
+#[derive(Debug, Clone)]
+pub struct Unnest<ST, E> {
+    array: E,
+    alias: String,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST, E> Unnest<ST, E> {
+    /// Sets the name under which the expanded rows appear in the query.
+    /// Defaults to `unnest` if not given.
+    pub fn alias(self, alias: &str) -> Self {
+        Unnest {
+            alias: alias.into(),
+            ..self
+        }
+    }
+}
+
+impl<ST, E> QueryId for Unnest<ST, E> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+/// The `value` column of an [`Unnest`] query source
+#[derive(Debug, Clone)]
+pub struct UnnestValue<ST> {
+    alias: String,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST> QueryId for UnnestValue<ST> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<ST, E> QuerySource for Unnest<ST, E>
+where
+    ST: SqlType + SingleValue,
+    Self: Clone,
+{
+    type FromClause = Self;
+    type DefaultSelection = UnnestValue<ST>;
+
+    fn from_clause(&self) -> Self {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        UnnestValue {
+            alias: self.alias.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ST, E> AsQuery for Unnest<ST, E>
+where
+    ST: SqlType + SingleValue,
+    Self: Clone,
+{
+    type SqlType = ST;
+    type Query = SelectStatement<Self>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<ST, E> AppearsInFromClause<Unnest<ST, E>> for Unnest<ST, E> {
+    type Count = Once;
+}
+
+impl<ST, E> AppearsInFromClause<Unnest<ST, E>> for () {
+    type Count = Never;
+}
+
+impl<ST, E> QueryFragment<Pg> for Unnest<ST, E>
+where
+    E: QueryFragment<Pg>,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("UNNEST(");
+        self.array.walk_ast(out.reborrow())?;
+        out.push_sql(") AS ");
+        out.push_identifier(&self.alias)?;
+        out.push_sql(" (value)");
+        Ok(())
+    }
+}
+
+impl<ST> Expression for UnnestValue<ST>
+where
+    ST: SqlType + SingleValue,
+{
+    type SqlType = ST;
+}
+
+impl<ST, GB> ValidGrouping<GB> for UnnestValue<ST> {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<ST, E> SelectableExpression<Unnest<ST, E>> for UnnestValue<ST> where Self: Expression {}
+
+impl<ST, QS> AppearsOnTable<QS> for UnnestValue<ST> where Self: Expression {}
+
+impl<ST> QueryFragment<Pg> for UnnestValue<ST> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_identifier(&self.alias)?;
+        out.push_sql(".value");
+        Ok(())
+    }
+}
+
+impl<ST, E> Unnest<ST, E> {
+    /// Attaches a `WITH ORDINALITY` modifier to this query source, adding
+    /// an `ordinality` column counting the expanded rows.
+    pub fn with_ordinality(self) -> WithOrdinality<Self> {
+        WithOrdinality::new(self)
+    }
+}
+
+impl<ST, E> SetReturningFunction for Unnest<ST, E>
+where
+    E: QueryFragment<Pg>,
+{
+    fn srf_alias(&self) -> &str {
+        &self.alias
+    }
+
+    fn walk_call(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_sql("UNNEST(");
+        self.array.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<ST, E> SelectableExpression<WithOrdinality<Unnest<ST, E>>> for UnnestValue<ST> where
+    Self: Expression
+{
+}
@@ -0,0 +1,128 @@
+//! The `WITH ORDINALITY` modifier for set-returning functions
+
+use crate::expression::{is_aggregate, Expression, ValidGrouping};
+use crate::pg::Pg;
+use crate::query_builder::{AsQuery, AstPass, QueryFragment, QueryId, SelectStatement};
+use crate::query_source::{AppearsInFromClause, Never, Once, QuerySource};
+use crate::result::QueryResult;
+use crate::sql_types::BigInt;
+use crate::{AppearsOnTable, SelectableExpression};
+
+/// A set-returning function with an attached `WITH ORDINALITY` modifier
+///
+/// In addition to the `value` column of the wrapped function, this query
+/// source provides an `ordinality` column of type `BigInt` counting the
+/// produced rows, starting at 1. Both columns together form the default
+/// selection.
+///
+/// Constructed via the `with_ordinality` method of the wrapped query
+/// source, for example [`Unnest::with_ordinality`](super::unnest::Unnest::with_ordinality()).
+#[derive(Debug, Clone, QueryId)]
+pub struct WithOrdinality<SRF> {
+    source: SRF,
+}
+
+impl<SRF> WithOrdinality<SRF> {
+    pub(crate) fn new(source: SRF) -> Self {
+        WithOrdinality { source }
+    }
+}
+
+/// A set-returning function usable with the `WITH ORDINALITY` modifier
+pub trait SetReturningFunction {
+    /// The name under which the expanded rows appear in the query
+    fn srf_alias(&self) -> &str;
+
+    /// Walks only the function call itself, without any alias
+    fn walk_call(&self, out: AstPass<Pg>) -> QueryResult<()>;
+}
+
+/// The `ordinality` column of a [`WithOrdinality`] query source
+#[derive(Debug, Clone)]
+pub struct Ordinal {
+    alias: String,
+}
+
+impl QueryId for Ordinal {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<SRF> QuerySource for WithOrdinality<SRF>
+where
+    SRF: QuerySource + SetReturningFunction + Clone,
+    SRF::DefaultSelection: Expression + SelectableExpression<Self>,
+{
+    type FromClause = Self;
+    type DefaultSelection = (SRF::DefaultSelection, Ordinal);
+
+    fn from_clause(&self) -> Self {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        (
+            self.source.default_selection(),
+            Ordinal {
+                alias: self.source.srf_alias().into(),
+            },
+        )
+    }
+}
+
+impl<SRF> AsQuery for WithOrdinality<SRF>
+where
+    SRF: QuerySource + SetReturningFunction + Clone,
+    SRF::DefaultSelection:
+        Expression + SelectableExpression<Self> + ValidGrouping<(), IsAggregate = is_aggregate::No>,
+{
+    type SqlType = (<SRF::DefaultSelection as Expression>::SqlType, BigInt);
+    type Query = SelectStatement<Self>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<SRF> AppearsInFromClause<WithOrdinality<SRF>> for WithOrdinality<SRF> {
+    type Count = Once;
+}
+
+impl<SRF> AppearsInFromClause<WithOrdinality<SRF>> for () {
+    type Count = Never;
+}
+
+impl<SRF> QueryFragment<Pg> for WithOrdinality<SRF>
+where
+    SRF: SetReturningFunction,
+{
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        self.source.walk_call(out.reborrow())?;
+        out.push_sql(" WITH ORDINALITY AS ");
+        out.push_identifier(self.source.srf_alias())?;
+        out.push_sql(" (value, ordinality)");
+        Ok(())
+    }
+}
+
+impl Expression for Ordinal {
+    type SqlType = BigInt;
+}
+
+impl<GB> ValidGrouping<GB> for Ordinal {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<SRF> SelectableExpression<WithOrdinality<SRF>> for Ordinal {}
+
+impl<QS> AppearsOnTable<QS> for Ordinal {}
+
+impl QueryFragment<Pg> for Ordinal {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        out.push_identifier(&self.alias)?;
+        out.push_sql(".ordinality");
+        Ok(())
+    }
+}
@@ -22,6 +22,7 @@ pub struct TransactionBuilder<'a, C> {
     isolation_level: Option<IsolationLevel>,
     read_mode: Option<ReadMode>,
     deferrable: Option<Deferrable>,
+    lock_timeout: Option<std::time::Duration>,
 }
 
 impl<'a, C> TransactionBuilder<'a, C>
@@ -34,6 +35,7 @@ where
             isolation_level: None,
             read_mode: None,
             deferrable: None,
+            lock_timeout: None,
         }
     }
 
@@ -258,6 +260,40 @@ where
         self
     }
 
+    /// Makes statements in the transaction wait at most the given
+    /// duration when trying to acquire a lock
+    ///
+    /// This issues `SET LOCAL lock_timeout` right after beginning the
+    /// transaction, so the setting only applies within the transaction
+    /// and is restored automatically at its end. A lock timeout keeps
+    /// DDL statements, like the ones run by migrations, from waiting
+    /// behind long running queries indefinitely while blocking all
+    /// queries arriving after them.
+    ///
+    /// The timeout is rounded down to whole milliseconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_transaction();
+    /// conn.build_transaction()
+    ///     .lock_timeout(Duration::from_secs(5))
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn lock_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.lock_timeout = Some(timeout);
+        self
+    }
+
     /// Runs the given function inside of the transaction
     /// with the parameters given to this builder.
     ///
@@ -279,6 +315,15 @@ where
         let sql = query_builder.finish();
 
         AnsiTransactionManager::begin_transaction_sql(&mut *self.connection, &sql)?;
+        if let Some(timeout) = self.lock_timeout {
+            let set_timeout = self
+                .connection
+                .execute(&format!("SET LOCAL lock_timeout = {}", timeout.as_millis()));
+            if let Err(e) = set_timeout {
+                AnsiTransactionManager::rollback_transaction(&mut *self.connection)?;
+                return Err(e.into());
+            }
+        }
         match f(&mut *self.connection) {
             Ok(value) => {
                 AnsiTransactionManager::commit_transaction(&mut *self.connection)?;
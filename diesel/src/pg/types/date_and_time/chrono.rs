@@ -4,7 +4,9 @@
 extern crate chrono;
 
 use self::chrono::naive::MAX_DATE;
-use self::chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use self::chrono::{
+    DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
 use std::io::Write;
 
 use super::{PgDate, PgTime, PgTimestamp};
@@ -71,6 +73,13 @@ impl FromSql<Timestamptz, Pg> for DateTime<Local> {
     }
 }
 
+impl FromSql<Timestamptz, Pg> for DateTime<FixedOffset> {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let naive_date_time = <NaiveDateTime as FromSql<Timestamptz, Pg>>::from_sql(bytes)?;
+        Ok(FixedOffset::east(0).from_utc_datetime(&naive_date_time))
+    }
+}
+
 impl<TZ: TimeZone> ToSql<Timestamptz, Pg> for DateTime<TZ> {
     fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
         ToSql::<Timestamptz, Pg>::to_sql(&self.naive_utc(), out)
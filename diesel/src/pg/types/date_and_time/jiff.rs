@@ -0,0 +1,147 @@
+//! This module makes it possible to map `jiff` date and time values to
+//! postgres `Date`, `Time`, `Timestamp` and `Timestamptz` fields. It is
+//! enabled with the `jiff` feature.
+
+extern crate jiff;
+
+use self::jiff::civil::{Date as CivilDate, DateTime as CivilDateTime, Time as CivilTime};
+use self::jiff::tz::Offset;
+use self::jiff::{Span, Timestamp as JiffTimestamp};
+use std::io::Write;
+
+use super::{PgDate, PgTime, PgTimestamp};
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::{Date, Time, Timestamp, Timestamptz};
+
+// Postgres timestamps start from January 1st 2000, which corresponds to
+// this number of microseconds since the unix epoch.
+const PG_EPOCH_MICROSECONDS: i64 = 946_684_800_000_000;
+
+const PG_EPOCH_DATE: CivilDate = CivilDate::constant(2000, 1, 1);
+
+impl ToSql<Timestamptz, Pg> for JiffTimestamp {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let micros = self.as_microsecond() - PG_EPOCH_MICROSECONDS;
+        ToSql::<Timestamptz, Pg>::to_sql(&PgTimestamp(micros), out)
+    }
+}
+
+impl FromSql<Timestamptz, Pg> for JiffTimestamp {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let PgTimestamp(offset) = FromSql::<Timestamptz, Pg>::from_sql(bytes)?;
+        let micros = match offset.checked_add(PG_EPOCH_MICROSECONDS) {
+            Some(micros) => micros,
+            None => {
+                return Err("Tried to deserialize a timestamp that is too large for jiff".into())
+            }
+        };
+        JiffTimestamp::from_microsecond(micros)
+            .map_err(|_| "Tried to deserialize a timestamp that is too large for jiff".into())
+    }
+}
+
+impl ToSql<Timestamp, Pg> for CivilDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let timestamp = Offset::UTC.to_timestamp(*self)?;
+        let micros = timestamp.as_microsecond() - PG_EPOCH_MICROSECONDS;
+        ToSql::<Timestamp, Pg>::to_sql(&PgTimestamp(micros), out)
+    }
+}
+
+impl FromSql<Timestamp, Pg> for CivilDateTime {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let timestamp = <JiffTimestamp as FromSql<Timestamptz, Pg>>::from_sql(bytes)?;
+        Ok(Offset::UTC.to_datetime(timestamp))
+    }
+}
+
+impl ToSql<Timestamptz, Pg> for CivilDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        ToSql::<Timestamp, Pg>::to_sql(self, out)
+    }
+}
+
+impl FromSql<Timestamptz, Pg> for CivilDateTime {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<Timestamp, Pg>::from_sql(bytes)
+    }
+}
+
+impl ToSql<Time, Pg> for CivilTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let micros = i64::from(self.hour()) * 3_600_000_000
+            + i64::from(self.minute()) * 60_000_000
+            + i64::from(self.second()) * 1_000_000
+            + i64::from(self.subsec_nanosecond()) / 1_000;
+        ToSql::<Time, Pg>::to_sql(&PgTime(micros), out)
+    }
+}
+
+impl FromSql<Time, Pg> for CivilTime {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let PgTime(micros) = FromSql::<Time, Pg>::from_sql(bytes)?;
+        let hour = micros / 3_600_000_000;
+        let minute = micros % 3_600_000_000 / 60_000_000;
+        let second = micros % 60_000_000 / 1_000_000;
+        let nanos = micros % 1_000_000 * 1_000;
+        CivilTime::new(hour as i8, minute as i8, second as i8, nanos as i32)
+            .map_err(|_| format!("Cannot deserialize {} microseconds as a time", micros).into())
+    }
+}
+
+impl ToSql<Date, Pg> for CivilDate {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let days_since_epoch = self.since(PG_EPOCH_DATE)?.get_days();
+        ToSql::<Date, Pg>::to_sql(&PgDate(days_since_epoch), out)
+    }
+}
+
+impl FromSql<Date, Pg> for CivilDate {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let PgDate(offset) = FromSql::<Date, Pg>::from_sql(bytes)?;
+        PG_EPOCH_DATE
+            .checked_add(Span::new().days(offset))
+            .map_err(|_| "Tried to deserialize a date that is too large for jiff".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::jiff::civil::{date, datetime, time};
+
+    use crate::pg::Pg;
+    use crate::serialize::{Output, ToSql};
+    use crate::sql_types::{Date, Time, Timestamp};
+
+    #[test]
+    fn times_relative_to_pg_epoch_encode_correctly() {
+        let mut bytes = Output::test();
+        ToSql::<Timestamp, Pg>::to_sql(&datetime(2000, 1, 1, 0, 0, 0, 0), &mut bytes).unwrap();
+        ToSql::<Timestamp, Pg>::to_sql(&datetime(2000, 1, 1, 0, 0, 1, 0), &mut bytes).unwrap();
+        let mut expected = vec![0; 8];
+        expected.extend_from_slice(&1_000_000i64.to_be_bytes());
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn times_of_day_encode_correctly() {
+        let mut bytes = Output::test();
+        ToSql::<Time, Pg>::to_sql(&time(0, 0, 0, 0), &mut bytes).unwrap();
+        ToSql::<Time, Pg>::to_sql(&time(0, 0, 1, 0), &mut bytes).unwrap();
+        let mut expected = vec![0; 8];
+        expected.extend_from_slice(&1_000_000i64.to_be_bytes());
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn dates_encode_correctly() {
+        let mut bytes = Output::test();
+        ToSql::<Date, Pg>::to_sql(&date(2000, 1, 1), &mut bytes).unwrap();
+        ToSql::<Date, Pg>::to_sql(&date(2000, 1, 2), &mut bytes).unwrap();
+        let mut expected = vec![0; 4];
+        expected.extend_from_slice(&1i32.to_be_bytes());
+        assert_eq!(bytes, expected);
+    }
+}
@@ -9,9 +9,13 @@ use crate::sql_types::{self, Date, Interval, Time, Timestamp, Timestamptz};
 
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(feature = "jiff")]
+mod jiff;
 #[cfg(feature = "quickcheck")]
 mod quickcheck_impls;
 mod std_time;
+#[cfg(feature = "time")]
+mod time;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, AsExpression, FromSqlRow)]
 #[sql_type = "Timestamp"]
@@ -39,6 +43,10 @@ pub struct PgTime(pub i64);
 /// microseconds, a 32 bit integer representing number of days, and a 32 bit integer
 /// representing number of months. This struct is a dumb wrapper type, meant only to indicate the
 /// meaning of these parts.
+///
+/// For constructing intervals in queries, e.g. for date arithmetic like
+/// `created_at + 7.days()`, see
+/// [`IntervalDsl`](crate::dsl::IntervalDsl) instead.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, AsExpression, FromSqlRow)]
 #[sql_type = "Interval"]
 pub struct PgInterval {
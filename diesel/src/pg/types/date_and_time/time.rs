@@ -0,0 +1,151 @@
+//! This module makes it possible to map `time` date and time values to
+//! postgres `Date`, `Time`, `Timestamp` and `Timestamptz` fields. It is
+//! enabled with the `time` feature.
+
+extern crate time;
+
+use self::time::{
+    macros::datetime, Date as NaiveDate, Duration, OffsetDateTime, PrimitiveDateTime,
+    Time as NaiveTime, UtcOffset,
+};
+use std::convert::TryInto;
+use std::io::Write;
+
+use super::{PgDate, PgTime, PgTimestamp};
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::{Date, Time, Timestamp, Timestamptz};
+
+// Postgres timestamps start from January 1st 2000.
+const PG_EPOCH: PrimitiveDateTime = datetime!(2000-01-01 0:00:00);
+
+impl FromSql<Timestamp, Pg> for PrimitiveDateTime {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let PgTimestamp(offset) = FromSql::<Timestamp, Pg>::from_sql(bytes)?;
+        match PG_EPOCH.checked_add(Duration::microseconds(offset)) {
+            Some(v) => Ok(v),
+            None => {
+                let message = "Tried to deserialize a timestamp that is too large for time";
+                Err(message.into())
+            }
+        }
+    }
+}
+
+impl ToSql<Timestamp, Pg> for PrimitiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let micros: i64 = match (*self - PG_EPOCH).whole_microseconds().try_into() {
+            Ok(micros) => micros,
+            Err(_) => {
+                let error_message =
+                    format!("{:?} as microseconds is too large to fit in an i64", self);
+                return Err(error_message.into());
+            }
+        };
+        ToSql::<Timestamp, Pg>::to_sql(&PgTimestamp(micros), out)
+    }
+}
+
+impl FromSql<Timestamptz, Pg> for PrimitiveDateTime {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<Timestamp, Pg>::from_sql(bytes)
+    }
+}
+
+impl ToSql<Timestamptz, Pg> for PrimitiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        ToSql::<Timestamp, Pg>::to_sql(self, out)
+    }
+}
+
+impl FromSql<Timestamptz, Pg> for OffsetDateTime {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let primitive_date_time = <PrimitiveDateTime as FromSql<Timestamptz, Pg>>::from_sql(bytes)?;
+        Ok(primitive_date_time.assume_utc())
+    }
+}
+
+impl ToSql<Timestamptz, Pg> for OffsetDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let utc = self.to_offset(UtcOffset::UTC);
+        let primitive_date_time = PrimitiveDateTime::new(utc.date(), utc.time());
+        ToSql::<Timestamptz, Pg>::to_sql(&primitive_date_time, out)
+    }
+}
+
+impl ToSql<Time, Pg> for NaiveTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let duration = *self - NaiveTime::MIDNIGHT;
+        let offset = duration
+            .whole_microseconds()
+            .try_into()
+            .expect("a time always fits into an i64 number of microseconds");
+        ToSql::<Time, Pg>::to_sql(&PgTime(offset), out)
+    }
+}
+
+impl FromSql<Time, Pg> for NaiveTime {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let PgTime(offset) = FromSql::<Time, Pg>::from_sql(bytes)?;
+        Ok(NaiveTime::MIDNIGHT + Duration::microseconds(offset))
+    }
+}
+
+impl ToSql<Date, Pg> for NaiveDate {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        let days_since_epoch = self.to_julian_day() - PG_EPOCH.date().to_julian_day();
+        ToSql::<Date, Pg>::to_sql(&PgDate(days_since_epoch), out)
+    }
+}
+
+impl FromSql<Date, Pg> for NaiveDate {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let PgDate(offset) = FromSql::<Date, Pg>::from_sql(bytes)?;
+        let julian_day = match PG_EPOCH.date().to_julian_day().checked_add(offset) {
+            Some(julian_day) => julian_day,
+            None => return Err("Tried to deserialize a date that is too large for time".into()),
+        };
+        NaiveDate::from_julian_day(julian_day)
+            .map_err(|_| "Tried to deserialize a date that is too large for time".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::time::macros::{date, datetime, time};
+
+    use crate::pg::Pg;
+    use crate::serialize::{Output, ToSql};
+    use crate::sql_types::{Date, Time, Timestamp};
+
+    #[test]
+    fn times_relative_to_pg_epoch_encode_correctly() {
+        let mut bytes = Output::test();
+        ToSql::<Timestamp, Pg>::to_sql(&datetime!(2000-01-01 0:00:00), &mut bytes).unwrap();
+        ToSql::<Timestamp, Pg>::to_sql(&datetime!(2000-01-01 0:00:01), &mut bytes).unwrap();
+        let mut expected = vec![0; 8];
+        expected.extend_from_slice(&1_000_000i64.to_be_bytes());
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn times_of_day_encode_correctly() {
+        let mut bytes = Output::test();
+        ToSql::<Time, Pg>::to_sql(&time!(0:00:00), &mut bytes).unwrap();
+        ToSql::<Time, Pg>::to_sql(&time!(0:00:01), &mut bytes).unwrap();
+        let mut expected = vec![0; 8];
+        expected.extend_from_slice(&1_000_000i64.to_be_bytes());
+        assert_eq!(bytes, expected);
+    }
+
+    #[test]
+    fn dates_encode_correctly() {
+        let mut bytes = Output::test();
+        ToSql::<Date, Pg>::to_sql(&date!(2000 - 01 - 01), &mut bytes).unwrap();
+        ToSql::<Date, Pg>::to_sql(&date!(2000 - 01 - 02), &mut bytes).unwrap();
+        let mut expected = vec![0; 4];
+        expected.extend_from_slice(&1i32.to_be_bytes());
+        assert_eq!(bytes, expected);
+    }
+}
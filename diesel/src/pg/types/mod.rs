@@ -44,40 +44,8 @@ pub mod sql_types {
     #[postgres(oid = "26", array_oid = "1018")]
     pub struct Oid;
 
-    /// The "timestamp with time zone" SQL type, which PostgreSQL abbreviates
-    /// to `timestamptz`.
-    ///
-    /// ### [`ToSql`] impls
-    ///
-    /// - [`PgTimestamp`]
-    /// - [`chrono::NaiveDateTime`] with `feature = "chrono"`
-    /// - [`chrono::DateTime`] with `feature = "chrono"`
-    ///
-    /// ### [`FromSql`] impls
-    ///
-    /// - [`PgTimestamp`]
-    /// - [`chrono::NaiveDateTime`] with `feature = "chrono"`
-    /// - [`chrono::DateTime`] with `feature = "chrono"`
-    ///
-    /// [`ToSql`]: crate::serialize::ToSql
-    /// [`FromSql`]: crate::deserialize::FromSql
-    /// [`PgTimestamp`]: super::super::data_types::PgTimestamp
-    #[cfg_attr(
-        feature = "chrono",
-        doc = " [`chrono::NaiveDateTime`]: chrono::naive::NaiveDateTime"
-    )]
-    #[cfg_attr(
-        not(feature = "chrono"),
-        doc = " [`chrono::NaiveDateTime`]: https://docs.rs/chrono/0.4.19/chrono/naive/struct.NaiveDateTime.html"
-    )]
-    #[cfg_attr(feature = "chrono", doc = " [`chrono::DateTime`]: chrono::DateTime")]
-    #[cfg_attr(
-        not(feature = "chrono"),
-        doc = " [`chrono::DateTime`]: https://docs.rs/chrono/0.4.19/chrono/struct.DateTime.html"
-    )]
-    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
-    #[postgres(oid = "1184", array_oid = "1185")]
-    pub struct Timestamptz;
+    #[doc(inline)]
+    pub use crate::sql_types::Timestamptz;
 
     /// The `Array` SQL type.
     ///
@@ -182,22 +150,8 @@ pub mod sql_types {
     /// Alias for `BigInt`
     pub type BigSerial = crate::sql_types::BigInt;
 
-    /// The `UUID` SQL type. This type can only be used with `feature = "uuid"`
-    ///
-    /// ### [`ToSql`] impls
-    ///
-    /// - [`uuid::Uuid`][Uuid]
-    ///
-    /// ### [`FromSql`] impls
-    ///
-    /// - [`uuid::Uuid`][Uuid]
-    ///
-    /// [`ToSql`]: crate::serialize::ToSql
-    /// [`FromSql`]: crate::deserialize::FromSql
-    /// [Uuid]: https://docs.rs/uuid/*/uuid/struct.Uuid.html
-    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
-    #[postgres(oid = "2950", array_oid = "2951")]
-    pub struct Uuid;
+    #[doc(inline)]
+    pub use crate::sql_types::Uuid;
 
     /// Alias for `Binary`, to ensure `infer_schema!` works
     #[doc(hidden)]
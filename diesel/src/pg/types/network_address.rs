@@ -3,7 +3,7 @@ extern crate libc;
 
 use self::ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use std::io::prelude::*;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use crate::deserialize::{self, FromSql, FromSqlRow};
 use crate::pg::{Pg, PgValue};
@@ -31,6 +31,11 @@ mod foreign_derives {
     #[sql_type = "Inet"]
     #[sql_type = "Cidr"]
     struct IpNetworkProxy(IpNetwork);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Inet"]
+    struct IpAddrProxy(IpAddr);
 }
 
 macro_rules! err {
@@ -134,6 +139,67 @@ macro_rules! impl_Sql {
 impl_Sql!(Inet, 0);
 impl_Sql!(Cidr, 1);
 
+impl FromSql<Inet, Pg> for IpAddr {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        // https://github.com/postgres/postgres/blob/55c3391d1e6a201b5b891781d21fe682a8c64fe6/src/include/utils/inet.h#L23-L28
+        let bytes = value.as_bytes();
+        assert_or_error!(4 <= bytes.len(), "input is too short.");
+        let af = bytes[0];
+        let prefix = bytes[1];
+        let len = bytes[3];
+        if af == PGSQL_AF_INET {
+            assert_or_error!(bytes.len() == 8);
+            assert_or_error!(len == 4, "the data isn't the size of ipv4");
+            assert_or_error!(
+                prefix == 32,
+                "cannot deserialize a network address as a host address"
+            );
+            let b = &bytes[4..];
+            Ok(IpAddr::V4(Ipv4Addr::new(b[0], b[1], b[2], b[3])))
+        } else if af == PGSQL_AF_INET6 {
+            assert_or_error!(bytes.len() == 20);
+            assert_or_error!(len == 16, "the data isn't the size of ipv6");
+            assert_or_error!(
+                prefix == 128,
+                "cannot deserialize a network address as a host address"
+            );
+            let b = &bytes[4..];
+            let addr = Ipv6Addr::from([
+                b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9], b[10], b[11], b[12],
+                b[13], b[14], b[15],
+            ]);
+            Ok(IpAddr::V6(addr))
+        } else {
+            err!()
+        }
+    }
+}
+
+impl ToSql<Inet, Pg> for IpAddr {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        match *self {
+            IpAddr::V4(addr) => {
+                let mut data = [0u8; 8];
+                data[0] = PGSQL_AF_INET;
+                data[1] = 32;
+                data[2] = 0;
+                data[3] = 4;
+                data[4..].copy_from_slice(&addr.octets());
+                out.write_all(&data).map(|_| IsNull::No).map_err(Into::into)
+            }
+            IpAddr::V6(addr) => {
+                let mut data = [0u8; 20];
+                data[0] = PGSQL_AF_INET6;
+                data[1] = 128;
+                data[2] = 0;
+                data[3] = 16;
+                data[4..].copy_from_slice(&addr.octets());
+                out.write_all(&data).map(|_| IsNull::No).map_err(Into::into)
+            }
+        }
+    }
+}
+
 #[test]
 fn v4address_to_sql() {
     macro_rules! test_to_sql {
@@ -258,3 +324,34 @@ fn no_address_from_sql() {
     test_no_address_from_sql!(Inet);
     test_no_address_from_sql!(Cidr);
 }
+
+#[test]
+fn v4host_to_sql() {
+    let mut bytes = Output::test();
+    let test_address = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+    ToSql::<Inet, Pg>::to_sql(&test_address, &mut bytes).unwrap();
+    assert_eq!(bytes, vec![PGSQL_AF_INET, 32, 0, 4, 127, 0, 0, 1]);
+}
+
+#[test]
+fn some_host_from_sql() {
+    let input_address = IpAddr::V6(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1));
+    let mut bytes = Output::test();
+    ToSql::<Inet, Pg>::to_sql(&input_address, &mut bytes).unwrap();
+    let output_address =
+        <IpAddr as FromSql<Inet, Pg>>::from_sql(PgValue::for_test(bytes.as_ref())).unwrap();
+    assert_eq!(input_address, output_address);
+}
+
+#[test]
+fn network_address_from_sql_is_rejected_for_host() {
+    let input_address = IpNetwork::V4(Ipv4Network::new(Ipv4Addr::new(127, 0, 0, 0), 24).unwrap());
+    let mut bytes = Output::test();
+    ToSql::<Inet, Pg>::to_sql(&input_address, &mut bytes).unwrap();
+    let output_address: Result<IpAddr, _> =
+        FromSql::<Inet, Pg>::from_sql(PgValue::for_test(bytes.as_ref()));
+    assert_eq!(
+        output_address.unwrap_err().to_string(),
+        "invalid network address format. cannot deserialize a network address as a host address"
+    );
+}
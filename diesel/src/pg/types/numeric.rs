@@ -344,3 +344,264 @@ mod bigdecimal {
         }
     }
 }
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    use rust_decimal::Decimal;
+    use std::convert::{TryFrom, TryInto};
+    use std::error::Error;
+    use std::io::prelude::*;
+
+    use crate::deserialize::{self, FromSql};
+    use crate::pg::data_types::PgNumeric;
+    use crate::pg::{Pg, PgValue};
+    use crate::serialize::{self, Output, ToSql};
+    use crate::sql_types::Numeric;
+
+    /// The maximum scale representable by [`Decimal`]
+    const MAX_SCALE: u16 = 28;
+    /// The largest mantissa representable by [`Decimal`] (2^96 - 1)
+    const MAX_MANTISSA: i128 = 0x0000_0000_FFFF_FFFF_FFFF_FFFF_FFFF_FFFF;
+
+    impl<'a> TryFrom<&'a PgNumeric> for Decimal {
+        type Error = Box<dyn Error + Send + Sync>;
+
+        fn try_from(numeric: &'a PgNumeric) -> deserialize::Result<Self> {
+            let (is_positive, weight, scale, digits) = match *numeric {
+                PgNumeric::Positive {
+                    weight,
+                    scale,
+                    ref digits,
+                } => (true, weight, scale, digits),
+                PgNumeric::Negative {
+                    weight,
+                    scale,
+                    ref digits,
+                } => (false, weight, scale, digits),
+                PgNumeric::NaN => return Err(Box::from("NaN is not (yet) supported in Decimal")),
+            };
+
+            if scale > MAX_SCALE {
+                return Err(format!(
+                    "Numeric with a scale of {} cannot be represented as a Decimal",
+                    scale
+                )
+                .into());
+            }
+
+            let overflow = || Box::<dyn Error + Send + Sync>::from("Numeric overflows Decimal");
+
+            let mut mantissa = 0i128;
+            for digit in digits {
+                mantissa = mantissa
+                    .checked_mul(10_000)
+                    .and_then(|v| v.checked_add(i128::from(*digit)))
+                    .ok_or_else(overflow)?;
+            }
+
+            // The first digit got the factor 10_000^(digits.len() - 1), but
+            // should get 10_000^weight. Additionally the mantissa needs to be
+            // shifted so that it contains exactly `scale` fractional digits.
+            let count = digits.len() as i64;
+            let exponent = 4 * (i64::from(weight) - count + 1) + i64::from(scale);
+            if exponent >= 0 {
+                for _ in 0..exponent {
+                    mantissa = mantissa.checked_mul(10).ok_or_else(overflow)?;
+                }
+            } else {
+                // The last base 10_000 digit may contain up to 3 trailing
+                // zeroes which are not part of the fractional digits
+                for _ in 0..-exponent {
+                    if mantissa % 10 != 0 {
+                        return Err(Box::from("Numeric cannot be represented as a Decimal"));
+                    }
+                    mantissa /= 10;
+                }
+            }
+
+            if mantissa > MAX_MANTISSA {
+                return Err(overflow());
+            }
+            if !is_positive {
+                mantissa = -mantissa;
+            }
+            Ok(Decimal::from_i128_with_scale(mantissa, u32::from(scale)))
+        }
+    }
+
+    impl TryFrom<PgNumeric> for Decimal {
+        type Error = Box<dyn Error + Send + Sync>;
+
+        fn try_from(numeric: PgNumeric) -> deserialize::Result<Self> {
+            (&numeric).try_into()
+        }
+    }
+
+    impl<'a> From<&'a Decimal> for PgNumeric {
+        fn from(decimal: &'a Decimal) -> Self {
+            let mut mantissa = decimal.mantissa().unsigned_abs();
+            if mantissa == 0 {
+                return PgNumeric::Positive {
+                    digits: vec![0],
+                    scale: 0,
+                    weight: 0,
+                };
+            }
+
+            // `Decimal` limits the scale to 28, so this cast cannot truncate
+            let scale = decimal.scale() as u16;
+
+            // Ensure that the decimal will always lie on a digit boundary
+            for _ in 0..(4 - scale % 4) {
+                mantissa *= 10;
+            }
+
+            let mut digits = Vec::new();
+            while mantissa != 0 {
+                digits.push((mantissa % 10_000) as i16);
+                mantissa /= 10_000;
+            }
+            digits.reverse();
+
+            let digits_after_decimal = scale / 4 + 1;
+            let weight = digits.len() as i16 - digits_after_decimal as i16 - 1;
+
+            let unnecessary_zeroes = digits.iter().rev().take_while(|i| **i == 0).count();
+            let relevant_digits = digits.len() - unnecessary_zeroes;
+            digits.truncate(relevant_digits);
+
+            if decimal.is_sign_negative() {
+                PgNumeric::Negative {
+                    digits,
+                    scale,
+                    weight,
+                }
+            } else {
+                PgNumeric::Positive {
+                    digits,
+                    scale,
+                    weight,
+                }
+            }
+        }
+    }
+
+    impl From<Decimal> for PgNumeric {
+        fn from(decimal: Decimal) -> Self {
+            (&decimal).into()
+        }
+    }
+
+    impl ToSql<Numeric, Pg> for Decimal {
+        fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+            let numeric = PgNumeric::from(self);
+            ToSql::<Numeric, Pg>::to_sql(&numeric, out)
+        }
+    }
+
+    impl FromSql<Numeric, Pg> for Decimal {
+        fn from_sql(numeric: PgValue<'_>) -> deserialize::Result<Self> {
+            PgNumeric::from_sql(numeric)?.try_into()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::str::FromStr;
+
+        #[test]
+        fn decimal_to_pg_numeric_converts_digits_to_base_10000() {
+            let decimal = Decimal::from_str("1").unwrap();
+            let expected = PgNumeric::Positive {
+                weight: 0,
+                scale: 0,
+                digits: vec![1],
+            };
+            assert_eq!(expected, decimal.into());
+
+            let decimal = Decimal::from_str("10001").unwrap();
+            let expected = PgNumeric::Positive {
+                weight: 1,
+                scale: 0,
+                digits: vec![1, 1],
+            };
+            assert_eq!(expected, decimal.into());
+        }
+
+        #[test]
+        fn decimal_to_pg_numeric_properly_adjusts_scale() {
+            let decimal = Decimal::from_str("1.0").unwrap();
+            let expected = PgNumeric::Positive {
+                weight: 0,
+                scale: 1,
+                digits: vec![1],
+            };
+            assert_eq!(expected, decimal.into());
+
+            let decimal = Decimal::from_str("1.1").unwrap();
+            let expected = PgNumeric::Positive {
+                weight: 0,
+                scale: 1,
+                digits: vec![1, 1000],
+            };
+            assert_eq!(expected, decimal.into());
+
+            let decimal = Decimal::from_str("0.1").unwrap();
+            let expected = PgNumeric::Positive {
+                weight: -1,
+                scale: 1,
+                digits: vec![1000],
+            };
+            assert_eq!(expected, decimal.into());
+        }
+
+        #[test]
+        fn decimal_to_pg_numeric_retains_sign() {
+            let decimal = Decimal::from_str("123.456").unwrap();
+            let expected = PgNumeric::Positive {
+                weight: 0,
+                scale: 3,
+                digits: vec![123, 4560],
+            };
+            assert_eq!(expected, decimal.into());
+
+            let decimal = Decimal::from_str("-123.456").unwrap();
+            let expected = PgNumeric::Negative {
+                weight: 0,
+                scale: 3,
+                digits: vec![123, 4560],
+            };
+            assert_eq!(expected, decimal.into());
+        }
+
+        #[test]
+        fn pg_numeric_to_decimal_works() {
+            let numeric = PgNumeric::Positive {
+                weight: 0,
+                scale: 3,
+                digits: vec![123, 4560],
+            };
+            let expected = Decimal::from_str("123.456").unwrap();
+            assert_eq!(expected, Decimal::try_from(numeric).unwrap());
+
+            let numeric = PgNumeric::Negative {
+                weight: -1,
+                scale: 8,
+                digits: vec![31, 5937],
+            };
+            let expected = Decimal::from_str("-0.00315937").unwrap();
+            assert_eq!(expected, Decimal::try_from(numeric).unwrap());
+        }
+
+        #[test]
+        fn pg_numeric_exceeding_decimal_range_errors() {
+            let numeric = PgNumeric::Positive {
+                weight: 10,
+                scale: 0,
+                digits: vec![1],
+            };
+            assert!(Decimal::try_from(numeric).is_err());
+        }
+    }
+}
@@ -1,17 +1,10 @@
 use std::io::prelude::*;
 
-use crate::deserialize::{self, FromSql, FromSqlRow};
-use crate::expression::AsExpression;
+use crate::deserialize::{self, FromSql};
 use crate::pg::{Pg, PgValue};
 use crate::serialize::{self, IsNull, Output, ToSql};
 use crate::sql_types::Uuid;
 
-#[derive(AsExpression, FromSqlRow)]
-#[diesel(foreign_derive)]
-#[sql_type = "Uuid"]
-#[allow(dead_code)]
-struct UuidProxy(uuid::Uuid);
-
 impl FromSql<Uuid, Pg> for uuid::Uuid {
     fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
         uuid::Uuid::from_slice(value.as_bytes()).map_err(Into::into)
@@ -44,7 +37,7 @@ fn some_uuid_from_sql() {
 
 #[test]
 fn bad_uuid_from_sql() {
-    let uuid = uuid::Uuid::from_sql(PgValue::for_test(b"boom"));
+    let uuid = <uuid::Uuid as FromSql<Uuid, Pg>>::from_sql(PgValue::for_test(b"boom"));
     assert_eq!(
         uuid.unwrap_err().to_string(),
         "invalid bytes length: expected 16, found 4"
@@ -53,7 +46,7 @@ fn bad_uuid_from_sql() {
 
 #[test]
 fn no_uuid_from_sql() {
-    let uuid = uuid::Uuid::from_nullable_sql(None);
+    let uuid = <uuid::Uuid as FromSql<Uuid, Pg>>::from_nullable_sql(None);
     assert_eq!(
         uuid.unwrap_err().to_string(),
         "Unexpected null for non-null column"
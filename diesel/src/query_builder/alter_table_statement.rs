@@ -0,0 +1,189 @@
+//! An `ALTER TABLE` statement for use in migration code
+
+use super::create_table_statement::ColumnType;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::result::QueryResult;
+
+/// Builds a SQL `ALTER TABLE` statement
+///
+/// Like [`CreateTable`](crate::query_builder::CreateTable) this takes the
+/// table and column names as strings, since migrations usually alter
+/// tables whose [`table!`] definition no longer matches the schema on
+/// disk. Each statement performs a single alteration, which is the only
+/// form supported by all backends.
+///
+/// [`table!`]: crate::table!
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_builder::{AlterTable, ColumnType};
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `ALTER TABLE "users" ADD COLUMN "bio" TEXT DEFAULT ''`
+/// AlterTable::named("users")
+///     .add_column("bio", ColumnType::Text)
+///     .nullable()
+///     .default_value("''")
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AlterTable {
+    name: String,
+}
+
+impl AlterTable {
+    /// Starts an `ALTER TABLE` statement for the table with the given name
+    pub fn named(name: &str) -> Self {
+        AlterTable {
+            name: name.to_owned(),
+        }
+    }
+
+    /// Adds a column with the given name and type to the table
+    ///
+    /// The column is nullable unless [`not_null`](AddColumn::not_null())
+    /// is called on the result.
+    pub fn add_column(self, name: &str, ty: ColumnType) -> AddColumn {
+        AddColumn {
+            table: self.name,
+            column: name.to_owned(),
+            ty,
+            not_null: false,
+            default: None,
+        }
+    }
+
+    /// Renames the table
+    pub fn rename_to(self, new_name: &str) -> RenameTable {
+        RenameTable {
+            table: self.name,
+            new_name: new_name.to_owned(),
+        }
+    }
+}
+
+/// An `ALTER TABLE … ADD COLUMN` statement, ready to be executed
+#[derive(Debug, Clone)]
+pub struct AddColumn {
+    table: String,
+    column: String,
+    ty: ColumnType,
+    not_null: bool,
+    default: Option<String>,
+}
+
+impl AddColumn {
+    /// Adds a `NOT NULL` constraint to the new column
+    ///
+    /// Backends reject this for tables that already contain rows unless
+    /// a default is given via [`default_value`](AddColumn::default_value())
+    /// as well.
+    pub fn not_null(mut self) -> Self {
+        self.not_null = true;
+        self
+    }
+
+    /// Makes the new column nullable, which is also the default
+    pub fn nullable(mut self) -> Self {
+        self.not_null = false;
+        self
+    }
+
+    /// Adds a `DEFAULT` clause to the new column
+    ///
+    /// The given expression is embedded into the statement verbatim, so
+    /// string literals need to include their quotes, e.g. `"''"` for an
+    /// empty string.
+    pub fn default_value(mut self, expression: &str) -> Self {
+        self.default = Some(expression.to_owned());
+        self
+    }
+
+    fn walk_ddl<DB>(
+        &self,
+        mut out: AstPass<DB>,
+        type_name: fn(ColumnType) -> &'static str,
+    ) -> QueryResult<()>
+    where
+        DB: crate::backend::Backend,
+    {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("ALTER TABLE ");
+        out.push_identifier(&self.table)?;
+        out.push_sql(" ADD COLUMN ");
+        out.push_identifier(&self.column)?;
+        out.push_sql(" ");
+        out.push_sql(type_name(self.ty));
+        if self.not_null {
+            out.push_sql(" NOT NULL");
+        }
+        if let Some(ref default) = self.default {
+            out.push_sql(" DEFAULT ");
+            out.push_sql(default);
+        }
+        Ok(())
+    }
+}
+
+impl QueryId for AddColumn {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "postgres")]
+impl QueryFragment<crate::pg::Pg> for AddColumn {
+    fn walk_ast(&self, out: AstPass<crate::pg::Pg>) -> QueryResult<()> {
+        self.walk_ddl(out, ColumnType::pg_type_name)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl QueryFragment<crate::sqlite::Sqlite> for AddColumn {
+    fn walk_ast(&self, out: AstPass<crate::sqlite::Sqlite>) -> QueryResult<()> {
+        self.walk_ddl(out, ColumnType::sqlite_type_name)
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl QueryFragment<crate::mysql::Mysql> for AddColumn {
+    fn walk_ast(&self, out: AstPass<crate::mysql::Mysql>) -> QueryResult<()> {
+        self.walk_ddl(out, ColumnType::mysql_type_name)
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for AddColumn {}
+
+/// An `ALTER TABLE … RENAME TO` statement, ready to be executed
+#[derive(Debug, Clone)]
+pub struct RenameTable {
+    table: String,
+    new_name: String,
+}
+
+impl QueryId for RenameTable {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<DB> QueryFragment<DB> for RenameTable
+where
+    DB: crate::backend::Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("ALTER TABLE ");
+        out.push_identifier(&self.table)?;
+        out.push_sql(" RENAME TO ");
+        out.push_identifier(&self.new_name)
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for RenameTable {}
@@ -47,8 +47,8 @@ impl<DB: Backend + TypeMetadata> RawBytesBindCollector<DB> {
     /// Construct an empty `RawBytesBindCollector`
     pub fn new() -> Self {
         RawBytesBindCollector {
-            metadata: Vec::new(),
-            binds: Vec::new(),
+            metadata: Vec::with_capacity(8),
+            binds: Vec::with_capacity(8),
         }
     }
 }
@@ -0,0 +1,171 @@
+//! A `CREATE INDEX` statement for use in migration code
+
+use std::marker::PhantomData;
+
+use crate::backend::Backend;
+use crate::expression::SelectableExpression;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::query_source::{ColumnNames, Table};
+use crate::result::Error::QueryBuilderError;
+use crate::result::QueryResult;
+
+/// Builds a SQL `CREATE INDEX` statement
+///
+/// The table and the indexed columns are given as the types generated by
+/// [`table!`], so a typo in a column reference or a column of the wrong
+/// table fails to compile. The columns are passed as a tuple, even if the
+/// index only covers a single column.
+///
+/// An index name given via [`named`](CreateIndex::named()) is used as is.
+/// Without one, the statement is generated without a name, leaving the
+/// choice to the database. Note that only PostgreSQL supports unnamed
+/// indexes.
+///
+/// [`table!`]: crate::table!
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_builder::CreateIndex;
+/// # use schema::users;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `CREATE UNIQUE INDEX "users_name_idx" ON "users" ("name")`
+/// CreateIndex::on_table(users::table)
+///     .columns((users::name,))
+///     .unique()
+///     .named("users_name_idx")
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CreateIndex<T, Cols = ()> {
+    table: T,
+    name: Option<&'static str>,
+    unique: bool,
+    concurrently: bool,
+    _columns: PhantomData<Cols>,
+}
+
+impl<T: Table> CreateIndex<T> {
+    /// Starts a `CREATE INDEX` statement for the given table
+    pub fn on_table(table: T) -> Self {
+        CreateIndex {
+            table,
+            name: None,
+            unique: false,
+            concurrently: false,
+            _columns: PhantomData,
+        }
+    }
+}
+
+impl<T: Table, Cols> CreateIndex<T, Cols> {
+    /// Sets the columns covered by the index, given as a tuple
+    pub fn columns<NewCols>(self, _columns: NewCols) -> CreateIndex<T, NewCols>
+    where
+        NewCols: ColumnNames + SelectableExpression<T>,
+    {
+        CreateIndex {
+            table: self.table,
+            name: self.name,
+            unique: self.unique,
+            concurrently: self.concurrently,
+            _columns: PhantomData,
+        }
+    }
+
+    /// Gives the index an explicit name
+    pub fn named(mut self, name: &'static str) -> Self {
+        self.name = Some(name);
+        self
+    }
+
+    /// Makes this a `UNIQUE` index
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+        self
+    }
+
+    /// Creates the index with `CONCURRENTLY`, avoiding locking the table
+    /// against writes while it is built
+    ///
+    /// This is only supported on PostgreSQL. Executing the statement
+    /// against another backend returns an error.
+    pub fn concurrently(mut self) -> Self {
+        self.concurrently = true;
+        self
+    }
+
+    fn walk_ddl<DB>(&self, mut out: AstPass<DB>, concurrently_supported: bool) -> QueryResult<()>
+    where
+        DB: Backend,
+        T: Table,
+        T::FromClause: QueryFragment<DB>,
+        Cols: ColumnNames,
+    {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("CREATE ");
+        if self.unique {
+            out.push_sql("UNIQUE ");
+        }
+        out.push_sql("INDEX ");
+        if self.concurrently {
+            if !concurrently_supported {
+                return Err(QueryBuilderError(
+                    "`CONCURRENTLY` is only supported on PostgreSQL".into(),
+                ));
+            }
+            out.push_sql("CONCURRENTLY ");
+        }
+        if let Some(name) = self.name {
+            out.push_identifier(name)?;
+            out.push_sql(" ");
+        }
+        out.push_sql("ON ");
+        self.table.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" (");
+        for (idx, column) in Cols::column_names().iter().enumerate() {
+            if idx != 0 {
+                out.push_sql(", ");
+            }
+            out.push_identifier(column)?;
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T, Cols> QueryId for CreateIndex<T, Cols> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+macro_rules! create_index_query_fragment {
+    ($backend:ty, $concurrently_supported:expr) => {
+        impl<T, Cols> QueryFragment<$backend> for CreateIndex<T, Cols>
+        where
+            T: Table,
+            T::FromClause: QueryFragment<$backend>,
+            Cols: ColumnNames,
+        {
+            fn walk_ast(&self, out: AstPass<$backend>) -> QueryResult<()> {
+                self.walk_ddl(out, $concurrently_supported)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "postgres")]
+create_index_query_fragment!(crate::pg::Pg, true);
+#[cfg(feature = "sqlite")]
+create_index_query_fragment!(crate::sqlite::Sqlite, false);
+#[cfg(feature = "mysql")]
+create_index_query_fragment!(crate::mysql::Mysql, false);
+
+impl<T, Cols, Conn> RunQueryDsl<Conn> for CreateIndex<T, Cols> {}
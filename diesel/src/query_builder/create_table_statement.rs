@@ -0,0 +1,237 @@
+//! A `CREATE TABLE` statement for use in migration code
+
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::result::QueryResult;
+
+/// The column types supported by [`CreateTable`]
+///
+/// Each variant is rendered as the equivalent type name of the backend the
+/// statement is executed against, e.g. [`Serial`](ColumnType::Serial)
+/// becomes `SERIAL` on PostgreSQL and an auto incrementing `INTEGER` on
+/// SQLite and MySQL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColumnType {
+    /// An auto incrementing integer, typically used for primary keys
+    Serial,
+    /// A 2 byte integer
+    SmallInt,
+    /// A 4 byte integer
+    Integer,
+    /// An 8 byte integer
+    BigInt,
+    /// A variable length string
+    Text,
+    /// A variable length byte string
+    Binary,
+    /// A boolean value
+    Bool,
+    /// A 4 byte floating point number
+    Float,
+    /// An 8 byte floating point number
+    Double,
+    /// A date without a time component
+    Date,
+    /// A time without a date component
+    Time,
+    /// A combined date and time
+    Timestamp,
+}
+
+impl ColumnType {
+    #[cfg(feature = "postgres")]
+    pub(crate) fn pg_type_name(self) -> &'static str {
+        match self {
+            ColumnType::Serial => "SERIAL",
+            ColumnType::SmallInt => "SMALLINT",
+            ColumnType::Integer => "INTEGER",
+            ColumnType::BigInt => "BIGINT",
+            ColumnType::Text => "TEXT",
+            ColumnType::Binary => "BYTEA",
+            ColumnType::Bool => "BOOLEAN",
+            ColumnType::Float => "REAL",
+            ColumnType::Double => "DOUBLE PRECISION",
+            ColumnType::Date => "DATE",
+            ColumnType::Time => "TIME",
+            ColumnType::Timestamp => "TIMESTAMP",
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub(crate) fn sqlite_type_name(self) -> &'static str {
+        match self {
+            ColumnType::Serial => "INTEGER",
+            ColumnType::SmallInt => "SMALLINT",
+            ColumnType::Integer => "INTEGER",
+            ColumnType::BigInt => "BIGINT",
+            ColumnType::Text => "TEXT",
+            ColumnType::Binary => "BLOB",
+            ColumnType::Bool => "BOOLEAN",
+            ColumnType::Float => "FLOAT",
+            ColumnType::Double => "DOUBLE",
+            ColumnType::Date => "DATE",
+            ColumnType::Time => "TIME",
+            ColumnType::Timestamp => "TIMESTAMP",
+        }
+    }
+
+    #[cfg(feature = "mysql")]
+    pub(crate) fn mysql_type_name(self) -> &'static str {
+        match self {
+            ColumnType::Serial => "INTEGER AUTO_INCREMENT",
+            ColumnType::SmallInt => "SMALLINT",
+            ColumnType::Integer => "INTEGER",
+            ColumnType::BigInt => "BIGINT",
+            ColumnType::Text => "TEXT",
+            ColumnType::Binary => "BLOB",
+            ColumnType::Bool => "BOOLEAN",
+            ColumnType::Float => "FLOAT",
+            ColumnType::Double => "DOUBLE",
+            ColumnType::Date => "DATE",
+            ColumnType::Time => "TIME",
+            ColumnType::Timestamp => "DATETIME",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ColumnDefinition {
+    name: String,
+    ty: ColumnType,
+    not_null: bool,
+}
+
+/// Builds a SQL `CREATE TABLE` statement
+///
+/// Unlike most of Diesel's query builder the table does not exist yet when
+/// the statement is constructed, so the table and column names are passed
+/// as strings rather than as the types generated by [`table!`].
+///
+/// [`table!`]: crate::table!
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_builder::{ColumnType, CreateTable};
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// CreateTable::named("todos")
+///     .column("id", ColumnType::Serial)
+///     .column("title", ColumnType::Text)
+///     .not_null()
+///     .column("completed_at", ColumnType::Timestamp)
+///     .primary_key("id")
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CreateTable {
+    name: String,
+    columns: Vec<ColumnDefinition>,
+    primary_key: Option<String>,
+}
+
+impl CreateTable {
+    /// Starts a `CREATE TABLE` statement for a table with the given name
+    pub fn named(name: &str) -> Self {
+        CreateTable {
+            name: name.to_owned(),
+            columns: Vec::new(),
+            primary_key: None,
+        }
+    }
+
+    /// Adds a column with the given name and type to the table
+    pub fn column(mut self, name: &str, ty: ColumnType) -> Self {
+        self.columns.push(ColumnDefinition {
+            name: name.to_owned(),
+            ty,
+            not_null: false,
+        });
+        self
+    }
+
+    /// Adds a `NOT NULL` constraint to the most recently added column
+    ///
+    /// # Panics
+    ///
+    /// Panics if no column was added yet.
+    pub fn not_null(mut self) -> Self {
+        self.columns
+            .last_mut()
+            .expect("`not_null` must follow a `column` call")
+            .not_null = true;
+        self
+    }
+
+    /// Declares the column with the given name as the primary key
+    pub fn primary_key(mut self, column: &str) -> Self {
+        self.primary_key = Some(column.to_owned());
+        self
+    }
+
+    fn walk_ddl<DB>(
+        &self,
+        mut out: AstPass<DB>,
+        type_name: fn(ColumnType) -> &'static str,
+    ) -> QueryResult<()>
+    where
+        DB: crate::backend::Backend,
+    {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("CREATE TABLE ");
+        out.push_identifier(&self.name)?;
+        out.push_sql(" (");
+        for (idx, column) in self.columns.iter().enumerate() {
+            if idx != 0 {
+                out.push_sql(", ");
+            }
+            out.push_identifier(&column.name)?;
+            out.push_sql(" ");
+            out.push_sql(type_name(column.ty));
+            if column.not_null {
+                out.push_sql(" NOT NULL");
+            }
+        }
+        if let Some(ref primary_key) = self.primary_key {
+            out.push_sql(", PRIMARY KEY (");
+            out.push_identifier(primary_key)?;
+            out.push_sql(")");
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl QueryId for CreateTable {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(feature = "postgres")]
+impl QueryFragment<crate::pg::Pg> for CreateTable {
+    fn walk_ast(&self, out: AstPass<crate::pg::Pg>) -> QueryResult<()> {
+        self.walk_ddl(out, ColumnType::pg_type_name)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl QueryFragment<crate::sqlite::Sqlite> for CreateTable {
+    fn walk_ast(&self, out: AstPass<crate::sqlite::Sqlite>) -> QueryResult<()> {
+        self.walk_ddl(out, ColumnType::sqlite_type_name)
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl QueryFragment<crate::mysql::Mysql> for CreateTable {
+    fn walk_ast(&self, out: AstPass<crate::mysql::Mysql>) -> QueryResult<()> {
+        self.walk_ddl(out, ColumnType::mysql_type_name)
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for CreateTable {}
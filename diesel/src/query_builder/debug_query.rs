@@ -27,6 +27,33 @@ impl<'a, T, DB> DebugQuery<'a, T, DB> {
             _marker: PhantomData,
         }
     }
+
+    /// Colorizes the displayed SQL with ANSI escape codes for terminal
+    /// logging
+    ///
+    /// Keywords are shown in blue, string literals in green and bind
+    /// parameter placeholders in yellow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # use diesel::*;
+    /// # use schema::*;
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// let sql = debug_query::<DB, _>(&users.find(1)).colored().to_string();
+    /// assert!(sql.contains("\x1b[34mSELECT\x1b[0m"));
+    /// # }
+    /// ```
+    pub fn colored(self) -> ColoredQuery<'a, T, DB> {
+        ColoredQuery {
+            query: self.query,
+            _marker: PhantomData,
+        }
+    }
 }
 
 impl<'a, T, DB> Display for DebugQuery<'a, T, DB>
@@ -60,6 +87,156 @@ where
     }
 }
 
+/// A variant of [`DebugQuery`] that colorizes the SQL with ANSI escape
+/// codes when displayed
+///
+/// Constructed via [`DebugQuery::colored`](DebugQuery::colored()).
+pub struct ColoredQuery<'a, T: 'a, DB> {
+    query: &'a T,
+    _marker: PhantomData<DB>,
+}
+
+impl<'a, T, DB> Display for ColoredQuery<'a, T, DB>
+where
+    DB: Backend,
+    DB::QueryBuilder: Default,
+    T: QueryFragment<DB>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut query_builder = DB::QueryBuilder::default();
+        QueryFragment::<DB>::to_sql(self.query, &mut query_builder).map_err(|_| fmt::Error)?;
+        let debug_binds = DebugBinds::<_, DB>::new(self.query);
+        write!(
+            f,
+            "{} -- binds: {:?}",
+            colorize_sql(&query_builder.finish()),
+            debug_binds,
+        )
+    }
+}
+
+impl<'a, T, DB> Debug for ColoredQuery<'a, T, DB>
+where
+    DB: Backend,
+    DB::QueryBuilder: Default,
+    T: QueryFragment<DB>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut query_builder = DB::QueryBuilder::default();
+        QueryFragment::<DB>::to_sql(self.query, &mut query_builder).map_err(|_| fmt::Error)?;
+        let debug_binds = DebugBinds::<_, DB>::new(self.query);
+        f.debug_struct("Query")
+            .field("sql", &colorize_sql(&query_builder.finish()))
+            .field("binds", &debug_binds)
+            .finish()
+    }
+}
+
+const KEYWORD_COLOR: &str = "\x1b[34m";
+const STRING_COLOR: &str = "\x1b[32m";
+const PARAM_COLOR: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+pub(crate) const KEYWORDS: &[&str] = &[
+    "ALL",
+    "AND",
+    "AS",
+    "ASC",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASE",
+    "COMMIT",
+    "DEFAULT",
+    "DELETE",
+    "DESC",
+    "DISTINCT",
+    "ELSE",
+    "END",
+    "EXISTS",
+    "FOR",
+    "FROM",
+    "GROUP",
+    "HAVING",
+    "IN",
+    "INNER",
+    "INSERT",
+    "INTO",
+    "IS",
+    "JOIN",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "NOT",
+    "NULL",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "RETURNING",
+    "RIGHT",
+    "SELECT",
+    "SET",
+    "THEN",
+    "UNION",
+    "UPDATE",
+    "VALUES",
+    "WHEN",
+    "WHERE",
+];
+
+fn colorize_sql(sql: &str) -> String {
+    let mut result = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                let mut literal = String::from(c);
+                for c in chars.by_ref() {
+                    literal.push(c);
+                    if c == '\'' {
+                        break;
+                    }
+                }
+                result.push_str(STRING_COLOR);
+                result.push_str(&literal);
+                result.push_str(RESET);
+            }
+            '?' => {
+                result.push_str(PARAM_COLOR);
+                result.push(c);
+                result.push_str(RESET);
+            }
+            '$' if chars.peek().map_or(false, char::is_ascii_digit) => {
+                let mut param = String::from(c);
+                while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+                    param.push(digit);
+                }
+                result.push_str(PARAM_COLOR);
+                result.push_str(&param);
+                result.push_str(RESET);
+            }
+            _ if c.is_ascii_alphabetic() => {
+                let mut word = String::from(c);
+                while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                    word.push(c);
+                }
+                if KEYWORDS.contains(&word.to_uppercase().as_str()) {
+                    result.push_str(KEYWORD_COLOR);
+                    result.push_str(&word);
+                    result.push_str(RESET);
+                } else {
+                    result.push_str(&word);
+                }
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
 /// A struct that implements `fmt::Debug` by walking the given AST and writing
 /// the `fmt::Debug` implementation of each bind parameter.
 pub struct DebugBinds<'a, T: 'a, DB> {
@@ -68,7 +245,7 @@ pub struct DebugBinds<'a, T: 'a, DB> {
 }
 
 impl<'a, T, DB> DebugBinds<'a, T, DB> {
-    fn new(query: &'a T) -> Self {
+    pub(crate) fn new(query: &'a T) -> Self {
         DebugBinds {
             query,
             _marker: PhantomData,
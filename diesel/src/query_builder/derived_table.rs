@@ -0,0 +1,241 @@
+//! A subquery appearing in the `FROM` clause
+
+use std::marker::PhantomData;
+
+use crate::backend::Backend;
+use crate::expression::{is_aggregate, AppearsOnTable};
+use crate::expression::{Expression, SelectableExpression, TypedExpressionType, ValidGrouping};
+use crate::query_builder::{AsQuery, AstPass, Query, QueryFragment, QueryId, SelectStatement};
+use crate::query_dsl::methods::{FilterDsl, SelectDsl};
+use crate::query_dsl::{QueryDsl, RunQueryDsl};
+use crate::query_source::QuerySource;
+use crate::result::QueryResult;
+use crate::sql_types::SqlType;
+
+/// Creates a `FROM (subquery) AS alias` source from a select statement
+///
+/// The returned [`DerivedTable`] can be used like a table: loading it
+/// selects all columns of the subquery (`alias.*`), and further query
+/// builder methods such as `select` and `filter` can be applied to it.
+/// Columns of the subquery are referenced with
+/// [`field`](DerivedTable::field()), which requires the SQL type to be
+/// given explicitly. Note that unlike columns generated by [`table!`],
+/// fields of a derived table are not checked against the subquery at
+/// compile time. Referencing a field which does not exist results in a
+/// runtime error.
+///
+/// [`table!`]: crate::table!
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::from_subquery;
+/// # use diesel::sql_types::Text;
+/// # use schema::users;
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// let sub = from_subquery(users::table.select((users::id, users::name)), "sub");
+/// let name = sub.field::<Text>("name");
+///
+/// let names = sub
+///     .select(name)
+///     .load::<String>(connection)?;
+/// assert_eq!(vec!["Sean", "Tess"], names);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn from_subquery<S>(subquery: S, alias: &'static str) -> DerivedTable<S>
+where
+    S: Query,
+{
+    DerivedTable { subquery, alias }
+}
+
+/// The return type of [`from_subquery(subquery, alias)`](from_subquery())
+#[derive(Debug, Clone, Copy)]
+pub struct DerivedTable<S> {
+    subquery: S,
+    alias: &'static str,
+}
+
+impl<S> DerivedTable<S> {
+    /// References the column with the given name of this subquery
+    ///
+    /// The SQL type of the column needs to be given explicitly, as it
+    /// cannot be inferred from the column name at compile time.
+    pub fn field<ST>(&self, name: &'static str) -> SubqueryField<ST> {
+        SubqueryField {
+            alias: self.alias,
+            name,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S> QuerySource for DerivedTable<S>
+where
+    S: Query + Clone,
+    S::SqlType: TypedExpressionType,
+{
+    type FromClause = Self;
+    type DefaultSelection = DerivedStar<S>;
+
+    fn from_clause(&self) -> Self::FromClause {
+        self.clone()
+    }
+
+    fn default_selection(&self) -> Self::DefaultSelection {
+        DerivedStar {
+            alias: self.alias,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<S> QueryId for DerivedTable<S> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<S, DB> QueryFragment<DB> for DerivedTable<S>
+where
+    DB: Backend,
+    S: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.subquery.walk_ast(out.reborrow())?;
+        out.push_sql(") AS ");
+        out.push_identifier(self.alias)
+    }
+}
+
+impl<S> AsQuery for DerivedTable<S>
+where
+    Self: QuerySource,
+    SelectStatement<Self>: Query,
+{
+    type SqlType = <SelectStatement<Self> as Query>::SqlType;
+    type Query = SelectStatement<Self>;
+
+    fn as_query(self) -> Self::Query {
+        SelectStatement::simple(self)
+    }
+}
+
+impl<S> QueryDsl for DerivedTable<S> {}
+
+impl<S, Conn> RunQueryDsl<Conn> for DerivedTable<S> {}
+
+impl<S, Selection> SelectDsl<Selection> for DerivedTable<S>
+where
+    Selection: Expression,
+    Self: AsQuery,
+    <Self as AsQuery>::Query: SelectDsl<Selection>,
+{
+    type Output = <<Self as AsQuery>::Query as SelectDsl<Selection>>::Output;
+
+    fn select(self, selection: Selection) -> Self::Output {
+        self.as_query().select(selection)
+    }
+}
+
+impl<S, Predicate> FilterDsl<Predicate> for DerivedTable<S>
+where
+    Self: AsQuery,
+    <Self as AsQuery>::Query: FilterDsl<Predicate>,
+{
+    type Output = <<Self as AsQuery>::Query as FilterDsl<Predicate>>::Output;
+
+    fn filter(self, predicate: Predicate) -> Self::Output {
+        self.as_query().filter(predicate)
+    }
+}
+
+/// The default selection of a [`DerivedTable`], equivalent to `alias.*`
+#[derive(Debug, Clone, Copy)]
+pub struct DerivedStar<S> {
+    alias: &'static str,
+    _marker: PhantomData<S>,
+}
+
+impl<S> Expression for DerivedStar<S>
+where
+    S: Query,
+    S::SqlType: TypedExpressionType,
+{
+    type SqlType = S::SqlType;
+}
+
+impl<S> SelectableExpression<DerivedTable<S>> for DerivedStar<S> where Self: Expression {}
+
+impl<S> AppearsOnTable<DerivedTable<S>> for DerivedStar<S> where Self: Expression {}
+
+impl<S, GB> ValidGrouping<GB> for DerivedStar<S> {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<S> QueryId for DerivedStar<S> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<S, DB> QueryFragment<DB> for DerivedStar<S>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_identifier(self.alias)?;
+        out.push_sql(".*");
+        Ok(())
+    }
+}
+
+/// A reference to a column of a [`DerivedTable`], created by
+/// [`field`](DerivedTable::field())
+#[derive(Debug, Clone, Copy)]
+pub struct SubqueryField<ST> {
+    alias: &'static str,
+    name: &'static str,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST> Expression for SubqueryField<ST>
+where
+    ST: SqlType + TypedExpressionType,
+{
+    type SqlType = ST;
+}
+
+impl<ST, S> SelectableExpression<DerivedTable<S>> for SubqueryField<ST> where Self: Expression {}
+
+impl<ST, S> AppearsOnTable<DerivedTable<S>> for SubqueryField<ST> where Self: Expression {}
+
+impl<ST, GB> ValidGrouping<GB> for SubqueryField<ST> {
+    type IsAggregate = is_aggregate::No;
+}
+
+impl<ST> QueryId for SubqueryField<ST> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<ST, DB> QueryFragment<DB> for SubqueryField<ST>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_identifier(self.alias)?;
+        out.push_sql(".");
+        out.push_identifier(self.name)
+    }
+}
@@ -0,0 +1,154 @@
+//! `DROP TABLE` and `DROP COLUMN` statements for use in migration code
+
+use crate::backend::Backend;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::result::QueryResult;
+
+/// Builds a SQL `DROP TABLE` statement
+///
+/// The counterpart of [`CreateTable`](crate::query_builder::CreateTable)
+/// for down migrations. The table name is passed as a string, since after
+/// the migration runs there is no table left for a [`table!`] type to
+/// refer to.
+///
+/// [`table!`]: crate::table!
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_builder::DropTable;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `DROP TABLE IF EXISTS "users"`
+/// DropTable::named("users")
+///     .if_exists()
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DropTable {
+    name: String,
+    if_exists: bool,
+}
+
+impl DropTable {
+    /// Starts a `DROP TABLE` statement for the table with the given name
+    pub fn named(name: &str) -> Self {
+        DropTable {
+            name: name.to_owned(),
+            if_exists: false,
+        }
+    }
+
+    /// Adds `IF EXISTS`, so dropping a missing table is not an error
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl QueryId for DropTable {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<DB: Backend> QueryFragment<DB> for DropTable {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("DROP TABLE ");
+        if self.if_exists {
+            out.push_sql("IF EXISTS ");
+        }
+        out.push_identifier(&self.name)
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for DropTable {}
+
+/// Builds a SQL `ALTER TABLE … DROP COLUMN` statement
+///
+/// The counterpart of
+/// [`AlterTable::add_column`](crate::query_builder::AlterTable::add_column())
+/// for down migrations.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_builder::DropColumn;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `ALTER TABLE "users" DROP COLUMN "bio"`
+/// DropColumn::from_table("users")
+///     .column("bio")
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DropColumn {
+    table: String,
+}
+
+impl DropColumn {
+    /// Starts a `DROP COLUMN` statement for the table with the given name
+    pub fn from_table(table: &str) -> Self {
+        DropColumn {
+            table: table.to_owned(),
+        }
+    }
+
+    /// Completes the statement by naming the column to drop
+    pub fn column(self, column: &str) -> DropColumnStatement {
+        DropColumnStatement {
+            table: self.table,
+            column: column.to_owned(),
+            if_exists: false,
+        }
+    }
+}
+
+/// A fully constructed `DROP COLUMN` statement, ready to be executed
+#[derive(Debug, Clone)]
+pub struct DropColumnStatement {
+    table: String,
+    column: String,
+    if_exists: bool,
+}
+
+impl DropColumnStatement {
+    /// Adds `IF EXISTS`, so dropping a missing column is not an error
+    ///
+    /// This is only supported by PostgreSQL.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl QueryId for DropColumnStatement {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<DB: Backend> QueryFragment<DB> for DropColumnStatement {
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("ALTER TABLE ");
+        out.push_identifier(&self.table)?;
+        out.push_sql(" DROP COLUMN ");
+        if self.if_exists {
+            out.push_sql("IF EXISTS ");
+        }
+        out.push_identifier(&self.column)
+    }
+}
+
+impl<Conn> RunQueryDsl<Conn> for DropColumnStatement {}
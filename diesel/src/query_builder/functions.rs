@@ -1,7 +1,8 @@
 use super::delete_statement::DeleteStatement;
 use super::insert_statement::{Insert, InsertOrIgnore, Replace};
 use super::{
-    IncompleteInsertStatement, IntoUpdateTarget, SelectStatement, SqlQuery, UpdateStatement,
+    IncompleteInsertStatement, IntoUpdateTarget, SelectStatement, SqlQuery, TypedSqlQuery,
+    UpdateStatement,
 };
 use crate::dsl::Select;
 use crate::expression::Expression;
@@ -590,3 +591,45 @@ pub fn replace_into<T>(target: T) -> IncompleteInsertStatement<T, Replace> {
 pub fn sql_query<T: Into<String>>(query: T) -> SqlQuery<()> {
     SqlQuery::new((), query.into())
 }
+
+/// Construct a full SQL query using raw SQL, with a known SQL type.
+///
+/// Unlike [`sql_query`](sql_query()) the types of the returned columns are
+/// given up front as a tuple of SQL types, so the query deserializes by
+/// index like the rest of the query builder. The result can be loaded
+/// into tuples or `#[derive(Queryable)]` structs and a mismatch between
+/// the declared SQL types and the Rust types loaded into is caught at
+/// compile time.
+///
+/// Query parameters can be bound into the raw query using
+/// [`TypedSqlQuery::bind()`](crate::query_builder::TypedSqlQuery::bind()),
+/// using the same placeholder syntax as [`sql_query`](sql_query()).
+///
+/// # Safety
+///
+/// Diesel cannot verify that the declared SQL types actually match the
+/// columns returned by the query. If they do not, the result may have the
+/// wrong value, or return an error.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     use diesel::typed_sql_query;
+/// #     use diesel::sql_types::{Integer, Text};
+/// #
+/// #     let connection = &mut establish_connection();
+/// let users = typed_sql_query::<(Integer, Text)>("SELECT id, name FROM users ORDER BY id")
+///     .load::<(i32, String)>(connection);
+/// let expected_users = vec![
+///     (1, "Sean".into()),
+///     (2, "Tess".into()),
+/// ];
+/// assert_eq!(Ok(expected_users), users);
+/// # }
+/// ```
+pub fn typed_sql_query<ST>(query: impl Into<String>) -> TypedSqlQuery<ST> {
+    TypedSqlQuery::new((), query.into())
+}
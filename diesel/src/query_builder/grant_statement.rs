@@ -0,0 +1,212 @@
+//! `GRANT` and `REVOKE` statements for use in migration code
+
+use crate::backend::Backend;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::query_source::{QuerySource, Table};
+use crate::result::QueryResult;
+
+/// Builds a SQL `GRANT` statement for a table
+///
+/// The table is given as the type generated by [`table!`], so a typo in the
+/// table reference fails to compile. Role names are only known at runtime
+/// and are passed as strings. Note that not all backends support
+/// privileges; most notably SQLite has no `GRANT` statement.
+///
+/// [`table!`]: crate::table!
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_builder::Grant;
+/// # use schema::users;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `GRANT SELECT ON "users" TO "app_user"`
+/// Grant::select_on(users::table)
+///     .to_role("app_user")
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Grant<T> {
+    privilege: &'static str,
+    table: T,
+}
+
+impl<T: Table> Grant<T> {
+    /// Starts a `GRANT SELECT` statement for the given table
+    pub fn select_on(table: T) -> Self {
+        Self::with_privilege("SELECT", table)
+    }
+
+    /// Starts a `GRANT INSERT` statement for the given table
+    pub fn insert_on(table: T) -> Self {
+        Self::with_privilege("INSERT", table)
+    }
+
+    /// Starts a `GRANT UPDATE` statement for the given table
+    pub fn update_on(table: T) -> Self {
+        Self::with_privilege("UPDATE", table)
+    }
+
+    /// Starts a `GRANT DELETE` statement for the given table
+    pub fn delete_on(table: T) -> Self {
+        Self::with_privilege("DELETE", table)
+    }
+
+    /// Starts a `GRANT ALL PRIVILEGES` statement for the given table
+    pub fn all_on(table: T) -> Self {
+        Self::with_privilege("ALL PRIVILEGES", table)
+    }
+
+    fn with_privilege(privilege: &'static str, table: T) -> Self {
+        Grant { privilege, table }
+    }
+
+    /// Completes the statement by naming the role the privilege is
+    /// granted to
+    pub fn to_role(self, role: &str) -> GrantStatement<T> {
+        GrantStatement {
+            privilege: self.privilege,
+            table: self.table,
+            role: role.to_owned(),
+        }
+    }
+}
+
+/// Builds a SQL `REVOKE` statement for a table
+///
+/// The counterpart of [`Grant`], removing a previously granted privilege.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_builder::Revoke;
+/// # use schema::users;
+/// #
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// // Generates `REVOKE SELECT ON "users" FROM "app_user"`
+/// Revoke::select_on(users::table)
+///     .from_role("app_user")
+///     .execute(connection)
+///     .unwrap();
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Revoke<T> {
+    privilege: &'static str,
+    table: T,
+}
+
+impl<T: Table> Revoke<T> {
+    /// Starts a `REVOKE SELECT` statement for the given table
+    pub fn select_on(table: T) -> Self {
+        Self::with_privilege("SELECT", table)
+    }
+
+    /// Starts a `REVOKE INSERT` statement for the given table
+    pub fn insert_on(table: T) -> Self {
+        Self::with_privilege("INSERT", table)
+    }
+
+    /// Starts a `REVOKE UPDATE` statement for the given table
+    pub fn update_on(table: T) -> Self {
+        Self::with_privilege("UPDATE", table)
+    }
+
+    /// Starts a `REVOKE DELETE` statement for the given table
+    pub fn delete_on(table: T) -> Self {
+        Self::with_privilege("DELETE", table)
+    }
+
+    /// Starts a `REVOKE ALL PRIVILEGES` statement for the given table
+    pub fn all_on(table: T) -> Self {
+        Self::with_privilege("ALL PRIVILEGES", table)
+    }
+
+    fn with_privilege(privilege: &'static str, table: T) -> Self {
+        Revoke { privilege, table }
+    }
+
+    /// Completes the statement by naming the role the privilege is
+    /// revoked from
+    pub fn from_role(self, role: &str) -> RevokeStatement<T> {
+        RevokeStatement {
+            privilege: self.privilege,
+            table: self.table,
+            role: role.to_owned(),
+        }
+    }
+}
+
+/// A fully constructed `GRANT` statement, ready to be executed
+#[derive(Debug, Clone)]
+pub struct GrantStatement<T> {
+    privilege: &'static str,
+    table: T,
+    role: String,
+}
+
+impl<T> QueryId for GrantStatement<T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T, DB> QueryFragment<DB> for GrantStatement<T>
+where
+    DB: Backend,
+    T: QuerySource,
+    T::FromClause: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("GRANT ");
+        out.push_sql(self.privilege);
+        out.push_sql(" ON ");
+        self.table.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" TO ");
+        out.push_identifier(&self.role)
+    }
+}
+
+impl<T, Conn> RunQueryDsl<Conn> for GrantStatement<T> {}
+
+/// A fully constructed `REVOKE` statement, ready to be executed
+#[derive(Debug, Clone)]
+pub struct RevokeStatement<T> {
+    privilege: &'static str,
+    table: T,
+    role: String,
+}
+
+impl<T> QueryId for RevokeStatement<T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T, DB> QueryFragment<DB> for RevokeStatement<T>
+where
+    DB: Backend,
+    T: QuerySource,
+    T::FromClause: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        out.push_sql("REVOKE ");
+        out.push_sql(self.privilege);
+        out.push_sql(" ON ");
+        self.table.from_clause().walk_ast(out.reborrow())?;
+        out.push_sql(" FROM ");
+        out.push_identifier(&self.role)
+    }
+}
+
+impl<T, Conn> RunQueryDsl<Conn> for RevokeStatement<T> {}
@@ -0,0 +1,170 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::backend::Backend;
+use crate::expression::bound::Bound;
+use crate::insertable::{CanInsertInSingleQuery, InsertValues, Insertable};
+use crate::query_builder::{AstPass, QueryFragment, QueryId, ValuesClause};
+use crate::query_source::Table;
+use crate::result::QueryResult;
+use crate::serialize::ToSql;
+use crate::sql_types::HasSqlType;
+
+/// An insertable set of column/value pairs which is built at runtime
+///
+/// This is useful when the set of columns to insert is not known at compile
+/// time, for example when importing data with a variable set of fields.
+/// Column names are escaped as identifiers, values are sent as bind
+/// parameters. Since the generated SQL depends on the pushed columns, the
+/// resulting statement is excluded from the prepared statement cache.
+///
+/// Note that unlike inserts built from column references, the column names
+/// used here are not checked against the table at compile time. Using a
+/// column name which does not exist results in a runtime error.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::query_builder::DynamicInsert;
+/// # use diesel::sql_types::{Integer, Text};
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let new_animal = DynamicInsert::new()
+///     .push::<Text, _>("species", "ferret")
+///     .push::<Integer, _>("legs", 4)
+///     .push::<Text, _>("name", "Freddy");
+/// diesel::insert_into(animals)
+///     .values(new_animal)
+///     .execute(connection)?;
+///
+/// let inserted = animals
+///     .filter(species.eq("ferret"))
+///     .select(name)
+///     .first::<Option<String>>(connection)?;
+/// assert_eq!(Some(String::from("Freddy")), inserted);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct DynamicInsert<'a, DB: Backend> {
+    values: Vec<(&'a str, Box<dyn QueryFragment<DB> + 'a>)>,
+}
+
+impl<'a, DB: Backend> DynamicInsert<'a, DB> {
+    /// Constructs a dynamic insert with no columns
+    pub fn new() -> Self {
+        DynamicInsert { values: Vec::new() }
+    }
+
+    /// Adds a value for the column with the given name
+    ///
+    /// The sql type of the column needs to be given explicitly, as it
+    /// cannot be inferred from the column name at compile time.
+    pub fn push<ST, V>(mut self, column: &'a str, value: V) -> Self
+    where
+        DB: HasSqlType<ST>,
+        V: ToSql<ST, DB> + 'a,
+        ST: 'a,
+    {
+        self.values
+            .push((column, Box::new(Bound::<ST, V>::new(value))));
+        self
+    }
+
+    /// The number of columns added so far
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no columns were added yet
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl<'a, DB: Backend> fmt::Debug for DynamicInsert<'a, DB> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list()
+            .entries(self.values.iter().map(|&(name, _)| name))
+            .finish()
+    }
+}
+
+impl<'a, T, DB> Insertable<T> for DynamicInsert<'a, DB>
+where
+    T: Table,
+    DB: Backend,
+{
+    type Values = ValuesClause<DynamicInsertValues<'a, T, DB>, T>;
+
+    fn values(self) -> Self::Values {
+        ValuesClause::new(DynamicInsertValues {
+            values: self.values,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// The `VALUES` clause of a [`DynamicInsert`]
+pub struct DynamicInsertValues<'a, T, DB: Backend> {
+    values: Vec<(&'a str, Box<dyn QueryFragment<DB> + 'a>)>,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T, DB: Backend> QueryId for DynamicInsertValues<'a, T, DB> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<'a, T, DB> InsertValues<T, DB> for DynamicInsertValues<'a, T, DB>
+where
+    T: Table,
+    DB: Backend,
+{
+    fn column_names(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        let mut needs_comma = false;
+        for &(name, _) in &self.values {
+            if needs_comma {
+                out.push_sql(", ");
+            }
+            out.push_identifier(name)?;
+            needs_comma = true;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T, DB> QueryFragment<DB> for DynamicInsertValues<'a, T, DB>
+where
+    DB: Backend,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        let mut needs_comma = false;
+        for &(_, ref value) in &self.values {
+            if needs_comma {
+                out.push_sql(", ");
+            }
+            value.walk_ast(out.reborrow())?;
+            needs_comma = true;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T, DB> CanInsertInSingleQuery<DB> for DynamicInsertValues<'a, T, DB>
+where
+    DB: Backend,
+{
+    fn rows_to_insert(&self) -> Option<usize> {
+        Some(1)
+    }
+}
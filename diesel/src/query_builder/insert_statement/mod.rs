@@ -1,9 +1,11 @@
 mod batch_insert;
 mod column_list;
+mod dynamic_insert;
 mod insert_from_select;
 
 pub(crate) use self::batch_insert::BatchInsert;
 pub(crate) use self::column_list::ColumnList;
+pub use self::dynamic_insert::{DynamicInsert, DynamicInsertValues};
 pub(crate) use self::insert_from_select::InsertFromSelect;
 
 use std::any::*;
@@ -9,14 +9,20 @@ mod query_id;
 #[macro_use]
 mod clause_macro;
 
+pub(crate) mod alter_table_statement;
 mod ast_pass;
 pub mod bind_collector;
 pub(crate) mod combination_clause;
-mod debug_query;
+pub(crate) mod create_index_statement;
+pub(crate) mod create_table_statement;
+pub(crate) mod debug_query;
 mod delete_statement;
-mod distinct_clause;
+pub(crate) mod derived_table;
+pub(crate) mod distinct_clause;
+pub(crate) mod drop_statements;
 #[doc(hidden)]
 pub mod functions;
+pub(crate) mod grant_statement;
 mod group_by_clause;
 mod having_clause;
 mod insert_statement;
@@ -27,22 +33,31 @@ pub(crate) mod locking_clause;
 pub mod nodes;
 pub(crate) mod offset_clause;
 pub(crate) mod order_clause;
+mod pagination;
 mod returning_clause;
 pub(crate) mod select_clause;
 mod select_statement;
 mod sql_query;
-mod update_statement;
+pub(crate) mod update_statement;
 pub(crate) mod upsert;
-mod where_clause;
+pub(crate) mod values_table;
+pub(crate) mod where_clause;
 
+pub use self::alter_table_statement::{AddColumn, AlterTable, RenameTable};
 pub use self::ast_pass::AstPass;
 pub use self::bind_collector::BindCollector;
-pub use self::debug_query::DebugQuery;
+pub use self::create_index_statement::CreateIndex;
+pub use self::create_table_statement::{ColumnType, CreateTable};
+pub use self::debug_query::{ColoredQuery, DebugQuery};
 pub use self::delete_statement::{BoxedDeleteStatement, DeleteStatement};
+pub use self::drop_statements::{DropColumn, DropColumnStatement, DropTable};
+pub use self::grant_statement::{Grant, GrantStatement, Revoke, RevokeStatement};
 #[doc(inline)]
 pub use self::insert_statement::{
-    IncompleteInsertStatement, InsertStatement, UndecoratedInsertRecord, ValuesClause,
+    DynamicInsert, DynamicInsertValues, IncompleteInsertStatement, InsertStatement,
+    UndecoratedInsertRecord, ValuesClause,
 };
+pub use self::pagination::Paginated;
 pub use self::query_id::QueryId;
 #[doc(inline)]
 pub use self::select_clause::{
@@ -50,7 +65,10 @@ pub use self::select_clause::{
 };
 #[doc(hidden)]
 pub use self::select_statement::{BoxedSelectStatement, SelectStatement};
-pub use self::sql_query::{BoxedSqlQuery, SqlQuery};
+pub use self::sql_query::{BoxedSqlQuery, SqlQuery, TypedBind, TypedSqlQuery};
+#[cfg(feature = "serde_json")]
+#[doc(inline)]
+pub use self::update_statement::JsonChangeset;
 #[doc(inline)]
 pub use self::update_statement::{
     AsChangeset, BoxedUpdateStatement, IntoUpdateTarget, UpdateStatement, UpdateTarget,
@@ -70,6 +88,12 @@ use crate::result::QueryResult;
 
 #[doc(hidden)]
 pub type Binds = Vec<Option<Vec<u8>>>;
+/// The initial capacity for the SQL string buffer of a query builder
+///
+/// Most queries fit well within this size, so the builders avoid
+/// growing their buffer repeatedly while the AST is walked.
+pub(crate) const QUERY_BUILDER_INITIAL_CAPACITY: usize = 512;
+
 /// A specialized Result type used with the query builder.
 pub type BuildQueryResult = Result<(), Box<dyn Error + Send + Sync>>;
 
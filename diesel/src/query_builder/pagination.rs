@@ -0,0 +1,69 @@
+//! Types related to the [`paginate`](crate::QueryDsl::paginate()) method
+
+use super::{AstPass, Query, QueryFragment, QueryId};
+use crate::backend::Backend;
+use crate::query_dsl::methods::LoadQuery;
+use crate::query_dsl::RunQueryDsl;
+use crate::result::QueryResult;
+use crate::sql_types::BigInt;
+
+/// A paginated query, returned by [`paginate`](crate::QueryDsl::paginate())
+///
+/// The wrapped query is executed as a subquery with an additional
+/// `COUNT(*) OVER ()` column, so the total number of rows matching the
+/// query is available without issuing a second query.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Paginated<T> {
+    query: T,
+    per_page: i64,
+    offset: i64,
+}
+
+impl<T> Paginated<T> {
+    pub(crate) fn new(query: T, page: i64, per_page: i64) -> Self {
+        // Pages are 1-based; clamping avoids a negative `OFFSET`, which
+        // would be a database error
+        let page = page.max(1);
+        Paginated {
+            query,
+            per_page,
+            offset: (page - 1) * per_page,
+        }
+    }
+
+    /// Loads the requested page together with the total row count
+    ///
+    /// The returned count is the number of rows matching the underlying
+    /// query, not the number of rows on this page.
+    pub fn load_with_count<U, Conn>(self, conn: &mut Conn) -> QueryResult<(Vec<U>, i64)>
+    where
+        Self: LoadQuery<Conn, (U, i64)>,
+    {
+        let results = self.internal_load(conn)?;
+        let total = results.first().map(|&(_, count)| count).unwrap_or(0);
+        let records = results.into_iter().map(|(record, _)| record).collect();
+        Ok((records, total))
+    }
+}
+
+impl<T: Query> Query for Paginated<T> {
+    type SqlType = (T::SqlType, BigInt);
+}
+
+impl<T, Conn> RunQueryDsl<Conn> for Paginated<T> {}
+
+impl<T, DB> QueryFragment<DB> for Paginated<T>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("SELECT *, COUNT(*) OVER () FROM (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(") t LIMIT ");
+        out.push_bind_param::<BigInt, _>(&self.per_page)?;
+        out.push_sql(" OFFSET ");
+        out.push_bind_param::<BigInt, _>(&self.offset)?;
+        Ok(())
+    }
+}
@@ -52,6 +52,22 @@ pub trait QueryId {
     /// cache is a boxed query.
     const HAS_STATIC_QUERY_ID: bool = true;
 
+    /// Can the SQL generated by `Self` be identified by a runtime hash of the
+    /// rendered SQL?
+    ///
+    /// This only matters when `HAS_STATIC_QUERY_ID` is `false`. Types setting
+    /// this to `true` must provide storage for the hash by overriding
+    /// [`dynamic_query_id`] and [`set_dynamic_query_id`], so that the
+    /// statement cache only needs to hash the rendered SQL once per query
+    /// value. The hash only speeds up the cache lookup, the full SQL is
+    /// still compared on a hit. Boxed queries opt into this, as their
+    /// structure is fixed once they are built even though it is not
+    /// reflected in their type.
+    ///
+    /// [`dynamic_query_id`]: QueryId::dynamic_query_id
+    /// [`set_dynamic_query_id`]: QueryId::set_dynamic_query_id
+    const HAS_DYNAMIC_QUERY_ID: bool = false;
+
     /// Returns the type id of `Self::QueryId` if `Self::HAS_STATIC_QUERY_ID`.
     /// Returns `None` otherwise.
     ///
@@ -63,6 +79,20 @@ pub trait QueryId {
             None
         }
     }
+
+    /// Returns the memoized hash of the rendered SQL, if one has been stored
+    /// via [`set_dynamic_query_id`](QueryId::set_dynamic_query_id).
+    ///
+    /// This is only called when `HAS_DYNAMIC_QUERY_ID` is `true`.
+    fn dynamic_query_id(&self) -> Option<u64> {
+        None
+    }
+
+    /// Stores the hash of the rendered SQL so that later executions of the
+    /// same query value can skip hashing the SQL a second time.
+    ///
+    /// This is only called when `HAS_DYNAMIC_QUERY_ID` is `true`.
+    fn set_dynamic_query_id(&self, _hash: u64) {}
 }
 
 #[doc(inline)]
@@ -78,12 +108,30 @@ impl<T: QueryId + ?Sized> QueryId for Box<T> {
     type QueryId = T::QueryId;
 
     const HAS_STATIC_QUERY_ID: bool = T::HAS_STATIC_QUERY_ID;
+    const HAS_DYNAMIC_QUERY_ID: bool = T::HAS_DYNAMIC_QUERY_ID;
+
+    fn dynamic_query_id(&self) -> Option<u64> {
+        (**self).dynamic_query_id()
+    }
+
+    fn set_dynamic_query_id(&self, hash: u64) {
+        (**self).set_dynamic_query_id(hash)
+    }
 }
 
 impl<'a, T: QueryId + ?Sized> QueryId for &'a T {
     type QueryId = T::QueryId;
 
     const HAS_STATIC_QUERY_ID: bool = T::HAS_STATIC_QUERY_ID;
+    const HAS_DYNAMIC_QUERY_ID: bool = T::HAS_DYNAMIC_QUERY_ID;
+
+    fn dynamic_query_id(&self) -> Option<u64> {
+        (**self).dynamic_query_id()
+    }
+
+    fn set_dynamic_query_id(&self, hash: u64) {
+        (**self).set_dynamic_query_id(hash)
+    }
 }
 
 impl<DB> QueryId for dyn QueryFragment<DB> {
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::marker::PhantomData;
 
 use crate::backend::Backend;
@@ -33,6 +34,9 @@ pub struct BoxedSelectStatement<'a, ST, QS, DB, GB = ()> {
     limit_offset: BoxedLimitOffsetClause<'a, DB>,
     group_by: Box<dyn QueryFragment<DB> + Send + 'a>,
     having: Box<dyn QueryFragment<DB> + Send + 'a>,
+    // Memoized hash of the rendered SQL, used as the prepared statement
+    // cache key. Must be cleared whenever the query is modified in place.
+    query_id_hash: Cell<Option<u64>>,
     _marker: PhantomData<(ST, GB)>,
 }
 
@@ -63,6 +67,7 @@ impl<'a, ST, QS, DB, GB> BoxedSelectStatement<'a, ST, QS, DB, GB> {
             limit_offset,
             group_by: Box::new(group_by),
             having,
+            query_id_hash: Cell::new(None),
             _marker: PhantomData,
         }
     }
@@ -151,6 +156,15 @@ impl<'a, ST, QS, DB, GB> QueryId for BoxedSelectStatement<'a, ST, QS, DB, GB> {
     type QueryId = ();
 
     const HAS_STATIC_QUERY_ID: bool = false;
+    const HAS_DYNAMIC_QUERY_ID: bool = true;
+
+    fn dynamic_query_id(&self) -> Option<u64> {
+        self.query_id_hash.get()
+    }
+
+    fn set_dynamic_query_id(&self, hash: u64) {
+        self.query_id_hash.set(Some(hash));
+    }
 }
 
 impl<'a, ST, QS, DB, Rhs, Kind, On, GB> InternalJoinDsl<Rhs, Kind, On>
@@ -170,6 +184,7 @@ where
             limit_offset: self.limit_offset,
             group_by: self.group_by,
             having: self.having,
+            query_id_hash: Cell::new(None),
             _marker: PhantomData,
         }
     }
@@ -184,6 +199,7 @@ where
 
     fn distinct(mut self) -> Self::Output {
         self.distinct = Box::new(DistinctClause);
+        self.query_id_hash.set(None);
         self
     }
 }
@@ -206,6 +222,7 @@ where
             limit_offset: self.limit_offset,
             group_by: self.group_by,
             having: self.having,
+            query_id_hash: Cell::new(None),
             _marker: PhantomData,
         }
     }
@@ -222,6 +239,7 @@ where
 
     fn filter(mut self, predicate: Predicate) -> Self::Output {
         self.where_clause = self.where_clause.and(predicate);
+        self.query_id_hash.set(None);
         self
     }
 }
@@ -237,6 +255,7 @@ where
 
     fn or_filter(mut self, predicate: Predicate) -> Self::Output {
         self.where_clause = self.where_clause.or(predicate);
+        self.query_id_hash.set(None);
         self
     }
 }
@@ -250,6 +269,7 @@ where
 
     fn limit(mut self, limit: i64) -> Self::Output {
         self.limit_offset.limit = Some(Box::new(LimitClause(limit.into_sql::<BigInt>())));
+        self.query_id_hash.set(None);
         self
     }
 }
@@ -263,6 +283,7 @@ where
 
     fn offset(mut self, offset: i64) -> Self::Output {
         self.limit_offset.offset = Some(Box::new(OffsetClause(offset.into_sql::<BigInt>())));
+        self.query_id_hash.set(None);
         self
     }
 }
@@ -276,6 +297,7 @@ where
 
     fn order(mut self, order: Order) -> Self::Output {
         self.order = OrderClause(order).into();
+        self.query_id_hash.set(None);
         self
     }
 }
@@ -292,6 +314,7 @@ where
             Some(old) => Some(Box::new((old, order))),
             None => Some(Box::new(order)),
         };
+        self.query_id_hash.set(None);
         self
     }
 }
@@ -356,6 +379,7 @@ where
             limit_offset: self.limit_offset,
             group_by: self.group_by,
             having: self.having,
+            query_id_hash: Cell::new(None),
             _marker: PhantomData,
         }
     }
@@ -374,6 +398,7 @@ where
 
     fn having(mut self, predicate: Predicate) -> Self::Output {
         self.having = Box::new(HavingClause(predicate));
+        self.query_id_hash.set(None);
         self
     }
 }
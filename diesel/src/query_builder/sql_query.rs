@@ -242,6 +242,120 @@ impl<Q, Value, ST> Query for UncheckedBind<Q, Value, ST> {
 
 impl<Conn, Query, Value, ST> RunQueryDsl<Conn> for UncheckedBind<Query, Value, ST> {}
 
+#[derive(Debug, Clone)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+/// The return value of `typed_sql_query`.
+///
+/// Unlike [`SqlQuery`] this query has the SQL type given at construction,
+/// so it deserializes by index like the rest of the query builder and can
+/// be loaded into tuples or `#[derive(Queryable)]` structs. See
+/// [`typed_sql_query`](crate::typed_sql_query()) for examples.
+pub struct TypedSqlQuery<ST, Inner = ()> {
+    inner: Inner,
+    query: String,
+    _marker: PhantomData<ST>,
+}
+
+impl<ST, Inner> TypedSqlQuery<ST, Inner> {
+    pub(crate) fn new(inner: Inner, query: String) -> Self {
+        TypedSqlQuery {
+            inner,
+            query,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Bind a value for use with this SQL query, like [`SqlQuery::bind()`]
+    ///
+    /// # Safety
+    ///
+    /// Only the types of the returned columns are checked, Diesel cannot
+    /// validate that the bound value is of the right type nor that you
+    /// have passed the correct number of parameters.
+    pub fn bind<BindSt, Value>(self, value: Value) -> TypedBind<ST, Self, Value, BindSt> {
+        TypedBind {
+            query: self,
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ST, DB, Inner> QueryFragment<DB> for TypedSqlQuery<ST, Inner>
+where
+    DB: Backend,
+    Inner: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        self.inner.walk_ast(out.reborrow())?;
+        out.push_sql(&self.query);
+        Ok(())
+    }
+}
+
+impl<ST, Inner> QueryId for TypedSqlQuery<ST, Inner> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<ST, Inner> Query for TypedSqlQuery<ST, Inner> {
+    type SqlType = ST;
+}
+
+impl<ST, Inner, Conn> RunQueryDsl<Conn> for TypedSqlQuery<ST, Inner> {}
+
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+/// Returned by the [`TypedSqlQuery::bind()`] method when binding a value
+/// to a fragment of SQL.
+pub struct TypedBind<ST, Query, Value, BindSt> {
+    query: Query,
+    value: Value,
+    _marker: PhantomData<(ST, BindSt)>,
+}
+
+impl<ST, Query, Value, BindSt> TypedBind<ST, Query, Value, BindSt> {
+    /// Binds another value, like [`TypedSqlQuery::bind()`]
+    pub fn bind<BindSt2, Value2>(self, value: Value2) -> TypedBind<ST, Self, Value2, BindSt2> {
+        TypedBind {
+            query: self,
+            value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<ST, Query, Value, BindSt> QueryId for TypedBind<ST, Query, Value, BindSt>
+where
+    Query: QueryId,
+    BindSt: QueryId,
+{
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<ST, Query, Value, BindSt, DB> QueryFragment<DB> for TypedBind<ST, Query, Value, BindSt>
+where
+    DB: Backend + HasSqlType<BindSt>,
+    Query: QueryFragment<DB>,
+    Value: ToSql<BindSt, DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        self.query.walk_ast(out.reborrow())?;
+        out.push_bind_param_value_only(&self.value)?;
+        Ok(())
+    }
+}
+
+impl<ST, Q, Value, BindSt> Query for TypedBind<ST, Q, Value, BindSt> {
+    type SqlType = ST;
+}
+
+impl<ST, Conn, Query, Value, BindSt> RunQueryDsl<Conn> for TypedBind<ST, Query, Value, BindSt> {}
+
 #[must_use = "Queries are only executed when calling `load`, `get_result`, or similar."]
 /// See [`SqlQuery::into_boxed`].
 ///
@@ -0,0 +1,154 @@
+use std::marker::PhantomData;
+
+use serde_json::Value;
+
+use crate::backend::Backend;
+use crate::query_builder::{AsChangeset, AstPass, QueryFragment, QueryId};
+use crate::query_source::{ColumnNames, Table};
+use crate::result::Error::QueryBuilderError;
+use crate::result::QueryResult;
+use crate::serialize::ToSql;
+use crate::sql_types::{BigInt, Bool, Double, HasSqlType, Text};
+
+/// A changeset built from a JSON object at runtime
+///
+/// This is useful when partial updates arrive as JSON, for example from a
+/// REST `PATCH` handler. Each key of the object names a column to update,
+/// and keys are validated against the columns of `T` at construction time.
+/// Strings, numbers, booleans and `null` are supported as values and are
+/// sent as bind parameters (or a literal `NULL`); nested arrays and objects
+/// are rejected. Since the generated SQL depends on the keys present, the
+/// resulting statement is excluded from the prepared statement cache.
+///
+/// Note that only the column *names* are checked. Whether a value can
+/// actually be stored in its column is decided by the database at runtime.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// # use diesel::query_builder::JsonChangeset;
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let patch = serde_json::json!({"name": "Jumpy", "legs": 3});
+/// let changes = JsonChangeset::<animals>::from_value(&patch)?;
+///
+/// diesel::update(animals.filter(species.eq("spider")))
+///     .set(changes)
+///     .execute(connection)?;
+///
+/// let jumpy = animals
+///     .filter(legs.eq(3))
+///     .select(name)
+///     .first::<Option<String>>(connection)?;
+/// assert_eq!(Some(String::from("Jumpy")), jumpy);
+///
+/// let bad_patch = serde_json::json!({"color": "brown"});
+/// assert!(JsonChangeset::<animals>::from_value(&bad_patch).is_err());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonChangeset<T> {
+    values: Vec<(String, Value)>,
+    _marker: PhantomData<T>,
+}
+
+impl<T> JsonChangeset<T>
+where
+    T: Table,
+    T::AllColumns: ColumnNames,
+{
+    /// Constructs a changeset from a JSON object
+    ///
+    /// Returns an error if `value` is not an object, if any key is not the
+    /// name of a column of `T`, or if any value is an array or an object.
+    pub fn from_value(value: &Value) -> QueryResult<Self> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| QueryBuilderError("Expected a JSON object as the changeset".into()))?;
+        let column_names = T::AllColumns::column_names();
+        let mut values = Vec::with_capacity(object.len());
+        for (key, value) in object {
+            if !column_names.contains(&&**key) {
+                return Err(QueryBuilderError(
+                    format!("`{}` is not a column of the target table", key).into(),
+                ));
+            }
+            if value.is_array() || value.is_object() {
+                return Err(QueryBuilderError(
+                    format!("The value for `{}` must be a scalar value", key).into(),
+                ));
+            }
+            values.push((key.clone(), value.clone()));
+        }
+        Ok(JsonChangeset {
+            values,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<T> AsChangeset for JsonChangeset<T>
+where
+    T: Table,
+{
+    type Target = T;
+    type Changeset = Self;
+
+    fn as_changeset(self) -> Self::Changeset {
+        self
+    }
+}
+
+impl<T> QueryId for JsonChangeset<T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T, DB> QueryFragment<DB> for JsonChangeset<T>
+where
+    DB: Backend,
+    DB: HasSqlType<Text> + HasSqlType<BigInt> + HasSqlType<Double> + HasSqlType<Bool>,
+    String: ToSql<Text, DB>,
+    i64: ToSql<BigInt, DB>,
+    f64: ToSql<Double, DB>,
+    bool: ToSql<Bool, DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.unsafe_to_cache_prepared();
+        let mut needs_comma = false;
+        for &(ref name, ref value) in &self.values {
+            if needs_comma {
+                out.push_sql(", ");
+            }
+            out.push_identifier(name)?;
+            out.push_sql(" = ");
+            match *value {
+                Value::Null => out.push_sql("NULL"),
+                Value::Bool(ref b) => out.push_bind_param::<Bool, _>(b)?,
+                Value::Number(ref n) => {
+                    if let Some(i) = n.as_i64() {
+                        out.push_bind_param::<BigInt, _>(&i)?;
+                    } else {
+                        let f = n.as_f64().expect("A JSON number is an i64 or an f64");
+                        out.push_bind_param::<Double, _>(&f)?;
+                    }
+                }
+                Value::String(ref s) => out.push_bind_param::<Text, _>(s)?,
+                Value::Array(_) | Value::Object(_) => {
+                    unreachable!("Nested values are rejected by `from_value`")
+                }
+            }
+            needs_comma = true;
+        }
+        Ok(())
+    }
+}
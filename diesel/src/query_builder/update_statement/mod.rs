@@ -1,7 +1,11 @@
 pub mod changeset;
+#[cfg(feature = "serde_json")]
+pub mod json_changeset;
 pub mod target;
 
 pub use self::changeset::AsChangeset;
+#[cfg(feature = "serde_json")]
+pub use self::json_changeset::JsonChangeset;
 pub use self::target::{IntoUpdateTarget, UpdateTarget};
 
 use crate::backend::Backend;
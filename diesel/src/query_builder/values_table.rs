@@ -0,0 +1,101 @@
+//! A `VALUES (...)` table value constructor
+
+use std::marker::PhantomData;
+
+use crate::backend::Backend;
+use crate::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDsl;
+use crate::result::QueryResult;
+
+/// Creates a `VALUES (...), (...)` table value constructor
+///
+/// The rows are given as tuples of Rust values, which are bound as
+/// parameters of the corresponding SQL types. The resulting query can be
+/// loaded directly, which is useful for constant lookup tables, test data
+/// and merge style operations.
+///
+/// # Examples
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use diesel::sql_types::{Integer, Text};
+/// #     let connection = &mut establish_connection();
+/// let rows = diesel::dsl::values_table::<(Integer, Text), _, _>(vec![
+///     (1, "a"),
+///     (2, "b"),
+/// ])
+/// .load::<(i32, String)>(connection)?;
+/// assert_eq!(vec![(1, "a".to_owned()), (2, "b".to_owned())], rows);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn values_table<ST, R, I>(rows: I) -> ValuesTable<ST, R::Row>
+where
+    I: IntoIterator<Item = R>,
+    R: AsValueRow<ST>,
+{
+    ValuesTable {
+        rows: rows.into_iter().map(AsValueRow::as_value_row).collect(),
+        _marker: PhantomData,
+    }
+}
+
+/// The return type of [`values_table(rows)`](values_table())
+#[derive(Debug, Clone)]
+pub struct ValuesTable<ST, Row> {
+    rows: Vec<Row>,
+    _marker: PhantomData<ST>,
+}
+
+/// Converts a tuple of Rust values into a tuple of bound expressions
+/// forming a single row of a [`ValuesTable`]
+#[doc(hidden)]
+pub trait AsValueRow<ST> {
+    /// The row tuple of bound expressions
+    type Row;
+
+    fn as_value_row(self) -> Self::Row;
+}
+
+impl<ST, Row> QueryId for ValuesTable<ST, Row> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<ST, Row> Query for ValuesTable<ST, Row> {
+    type SqlType = ST;
+}
+
+impl<ST, Row, Conn> RunQueryDsl<Conn> for ValuesTable<ST, Row> {}
+
+impl<ST, Row, DB> QueryFragment<DB> for ValuesTable<ST, Row>
+where
+    DB: Backend,
+    Row: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        if self.rows.is_empty() {
+            return Err(crate::result::Error::QueryBuilderError(
+                "values_table requires at least one row".into(),
+            ));
+        }
+        out.unsafe_to_cache_prepared();
+        out.push_sql("VALUES ");
+        for (i, row) in self.rows.iter().enumerate() {
+            if i != 0 {
+                out.push_sql(", ");
+            }
+            out.push_sql("(");
+            row.walk_ast(out.reborrow())?;
+            out.push_sql(")");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+/// Constructs a query that finds record(s) associated with other record(s)
+/// through a junction table.
+///
+/// While [`BelongingToDsl`](crate::BelongingToDsl) handles one-to-many
+/// relationships, this trait handles many-to-many relationships. `Through`
+/// is the model of the junction table, which must belong to the parent via
+/// [`BelongsTo`](crate::associations::BelongsTo). The generated query joins
+/// the junction table to this type's table and filters on the junction
+/// table's foreign key, loading all children in a single SQL query.
+///
+/// Since the junction model cannot be inferred from the arguments, it
+/// needs to be named explicitly at the call site.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::query_dsl::BelongingToMany;
+/// # use schema::users;
+/// #
+/// # #[derive(Identifiable, Queryable)]
+/// # pub struct User {
+/// #     id: i32,
+/// #     name: String,
+/// # }
+/// #
+/// table! {
+///     groups {
+///         id -> Integer,
+///         name -> Text,
+///     }
+/// }
+///
+/// table! {
+///     memberships {
+///         id -> Integer,
+///         user_id -> Integer,
+///         group_id -> Integer,
+///     }
+/// }
+///
+/// joinable!(memberships -> groups (group_id));
+/// allow_tables_to_appear_in_same_query!(groups, memberships);
+///
+/// #[derive(Debug, PartialEq)]
+/// #[derive(Identifiable, Queryable)]
+/// pub struct Group {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[derive(Identifiable, Queryable, Associations)]
+/// #[belongs_to(User)]
+/// pub struct Membership {
+///     id: i32,
+///     user_id: i32,
+///     group_id: i32,
+/// }
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// #     use schema::users::dsl::*;
+/// #     diesel::sql_query(
+/// #         "CREATE TABLE groups (id INTEGER PRIMARY KEY, name TEXT NOT NULL)"
+/// #     ).execute(connection)?;
+/// #     diesel::sql_query(
+/// #         "CREATE TABLE memberships (id INTEGER PRIMARY KEY, \
+/// #          user_id INTEGER NOT NULL, group_id INTEGER NOT NULL)"
+/// #     ).execute(connection)?;
+/// #     diesel::sql_query(
+/// #         "INSERT INTO groups (id, name) VALUES (1, 'Admins'), (2, 'Devs')"
+/// #     ).execute(connection)?;
+/// #     diesel::sql_query(
+/// #         "INSERT INTO memberships (id, user_id, group_id) \
+/// #          VALUES (1, 1, 1), (2, 1, 2), (3, 2, 2)"
+/// #     ).execute(connection)?;
+/// let sean = users.filter(name.eq("Sean")).first::<User>(connection)?;
+/// let tess = users.filter(name.eq("Tess")).first::<User>(connection)?;
+///
+/// let seans_groups = <Group as BelongingToMany<_, Membership>>::belonging_to_many(&sean)
+///     .load::<Group>(connection)?;
+/// assert_eq!(
+///     vec![
+///         Group { id: 1, name: "Admins".into() },
+///         Group { id: 2, name: "Devs".into() },
+///     ],
+///     seans_groups,
+/// );
+///
+/// // A vec or slice can be passed as well
+/// let all_groups = <Group as BelongingToMany<_, Membership>>::belonging_to_many(&vec![sean, tess])
+///     .load::<Group>(connection)?;
+/// assert_eq!(3, all_groups.len());
+/// #     Ok(())
+/// # }
+/// ```
+pub trait BelongingToMany<T, Through> {
+    /// The query returned by `belonging_to_many`
+    type Output;
+
+    /// Get the record(s) associated with record(s) `other` through the
+    /// junction table `Through`
+    fn belonging_to_many(other: T) -> Self::Output;
+}
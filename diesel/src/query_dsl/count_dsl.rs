@@ -0,0 +1,89 @@
+use crate::backend::Backend;
+use crate::connection::Connection;
+use crate::query_builder::{AsQuery, AstPass, Query, QueryFragment, QueryId};
+use crate::query_dsl::{LoadQuery, RunQueryDsl};
+use crate::result::QueryResult;
+use crate::sql_types::BigInt;
+
+/// The `count` method for executing a query as a `SELECT COUNT(*)`
+///
+/// Unlike [`QueryDsl::count`](crate::QueryDsl::count()), which merely
+/// replaces the select clause with `COUNT(*)` and returns the query for
+/// further chaining, this runs the query and returns the number of rows
+/// it produces. The original query is wrapped in a subquery, so queries
+/// with a `GROUP BY` or `DISTINCT` clause are counted correctly as well.
+///
+/// This trait is not part of the prelude, since importing it alongside
+/// `QueryDsl` makes calls to `count` ambiguous.
+pub trait CountDsl<Conn: Connection>: Sized {
+    /// Executes the query, returning the number of rows it produces
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use schema::users;
+    /// #     use diesel::query_dsl::methods::CountDsl;
+    /// #     let connection = &mut establish_connection();
+    /// let with_e = CountDsl::count(
+    ///     users::table.filter(users::name.like("%e%")),
+    ///     connection,
+    /// );
+    /// assert_eq!(Ok(2), with_e);
+    /// # }
+    /// ```
+    fn count(self, conn: &mut Conn) -> QueryResult<i64>;
+}
+
+impl<T, Conn> CountDsl<Conn> for T
+where
+    Conn: Connection,
+    T: AsQuery,
+    CountSubquery<T::Query>: LoadQuery<Conn, i64>,
+{
+    fn count(self, conn: &mut Conn) -> QueryResult<i64> {
+        CountSubquery {
+            query: self.as_query(),
+        }
+        .get_result(conn)
+    }
+}
+
+/// A query of the form `SELECT COUNT(*) FROM (subquery) count_subquery`
+///
+/// Constructed via [`CountDsl::count`](CountDsl::count()).
+#[derive(Debug, Clone, Copy)]
+pub struct CountSubquery<Q> {
+    query: Q,
+}
+
+impl<Q> QueryId for CountSubquery<Q>
+where
+    Q: QueryId,
+{
+    type QueryId = CountSubquery<Q::QueryId>;
+
+    const HAS_STATIC_QUERY_ID: bool = Q::HAS_STATIC_QUERY_ID;
+}
+
+impl<Q> Query for CountSubquery<Q> {
+    type SqlType = BigInt;
+}
+
+impl<Q, DB> QueryFragment<DB> for CountSubquery<Q>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("SELECT COUNT(*) FROM (");
+        self.query.walk_ast(out.reborrow())?;
+        // MySQL requires an alias for derived tables
+        out.push_sql(") AS count_subquery");
+        Ok(())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for CountSubquery<Q> {}
@@ -0,0 +1,88 @@
+use crate::backend::Backend;
+use crate::connection::Connection;
+use crate::query_builder::{AsQuery, AstPass, Query, QueryFragment, QueryId};
+use crate::query_dsl::{LoadQuery, RunQueryDsl};
+use crate::result::QueryResult;
+use crate::sql_types::Bool;
+
+/// The `exists` method for checking whether a query produces any rows
+///
+/// This runs the query wrapped in `SELECT EXISTS (…)`, so the database
+/// stops at the first matching row instead of counting all of them. For
+/// using `EXISTS` as an expression inside another query see
+/// [`dsl::exists`](crate::dsl::exists()).
+pub trait ExistsDsl<Conn: Connection>: Sized {
+    /// Executes the query, returning whether it produces any rows
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use schema::users;
+    /// #     use diesel::query_dsl::methods::ExistsDsl;
+    /// #     let connection = &mut establish_connection();
+    /// let any_sean = users::table
+    ///     .filter(users::name.eq("Sean"))
+    ///     .exists(connection);
+    /// assert_eq!(Ok(true), any_sean);
+    ///
+    /// let any_jim = users::table
+    ///     .filter(users::name.eq("Jim"))
+    ///     .exists(connection);
+    /// assert_eq!(Ok(false), any_jim);
+    /// # }
+    /// ```
+    fn exists(self, conn: &mut Conn) -> QueryResult<bool>;
+}
+
+impl<T, Conn> ExistsDsl<Conn> for T
+where
+    Conn: Connection,
+    T: AsQuery,
+    ExistsQuery<T::Query>: LoadQuery<Conn, bool>,
+{
+    fn exists(self, conn: &mut Conn) -> QueryResult<bool> {
+        ExistsQuery {
+            query: self.as_query(),
+        }
+        .get_result(conn)
+    }
+}
+
+/// A query of the form `SELECT EXISTS (subquery)`
+///
+/// Constructed via [`ExistsDsl::exists`](ExistsDsl::exists()).
+#[derive(Debug, Clone, Copy)]
+pub struct ExistsQuery<Q> {
+    query: Q,
+}
+
+impl<Q> QueryId for ExistsQuery<Q>
+where
+    Q: QueryId,
+{
+    type QueryId = ExistsQuery<Q::QueryId>;
+
+    const HAS_STATIC_QUERY_ID: bool = Q::HAS_STATIC_QUERY_ID;
+}
+
+impl<Q> Query for ExistsQuery<Q> {
+    type SqlType = Bool;
+}
+
+impl<Q, DB> QueryFragment<DB> for ExistsQuery<Q>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast(&self, mut out: AstPass<DB>) -> QueryResult<()> {
+        out.push_sql("SELECT EXISTS (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<Q, Conn> RunQueryDsl<Conn> for ExistsQuery<Q> {}
@@ -11,9 +11,13 @@
 //! [expression_methods]: super::expression_methods
 //! [dsl]: super::dsl
 
+use std::collections::HashMap;
+use std::hash::Hash;
+
 use crate::backend::Backend;
 use crate::connection::Connection;
 use crate::expression::count::CountStar;
+use crate::expression::maybe_predicate::MaybePredicate;
 use crate::expression::Expression;
 use crate::helper_types::*;
 use crate::query_builder::locking_clause as lock;
@@ -21,10 +25,13 @@ use crate::query_source::{joins, Table};
 use crate::result::{first_or_not_found, QueryResult};
 
 mod belonging_to_dsl;
+mod belonging_to_many_dsl;
 #[doc(hidden)]
 pub mod boxed_dsl;
 mod combine_dsl;
+pub mod count_dsl;
 mod distinct_dsl;
+pub mod exists_dsl;
 #[doc(hidden)]
 pub mod filter_dsl;
 mod group_by_dsl;
@@ -44,8 +51,10 @@ mod save_changes_dsl;
 #[doc(hidden)]
 pub mod select_dsl;
 mod single_value_dsl;
+pub(crate) mod values_of_dsl;
 
 pub use self::belonging_to_dsl::BelongingToDsl;
+pub use self::belonging_to_many_dsl::BelongingToMany;
 pub use self::combine_dsl::CombineDsl;
 pub use self::join_dsl::{InternalJoinDsl, JoinOnDsl, JoinWithImplicitOnClause};
 #[doc(hidden)]
@@ -53,6 +62,7 @@ pub use self::load_dsl::CompatibleType;
 #[doc(hidden)]
 pub use self::load_dsl::LoadQuery;
 pub use self::save_changes_dsl::{SaveChangesDsl, UpdateAndFetchResults};
+pub use self::values_of_dsl::{ColumnValues, IntoAnyValues, ValuesOfDsl};
 
 /// The traits used by `QueryDsl`.
 ///
@@ -62,7 +72,9 @@ pub use self::save_changes_dsl::{SaveChangesDsl, UpdateAndFetchResults};
 /// these traits.
 pub mod methods {
     pub use super::boxed_dsl::BoxedDsl;
+    pub use super::count_dsl::CountDsl;
     pub use super::distinct_dsl::*;
+    pub use super::exists_dsl::ExistsDsl;
     #[doc(inline)]
     pub use super::filter_dsl::*;
     pub use super::group_by_dsl::GroupByDsl;
@@ -491,6 +503,45 @@ pub trait QueryDsl: Sized {
         self.join_with_implicit_on_clause(rhs, joins::Inner)
     }
 
+    /// Join two tables using a SQL `INNER JOIN` with an explicit `ON` clause.
+    ///
+    /// This is a shorthand for `.inner_join(rhs.on(condition))`. Unlike
+    /// [`inner_join`] without an `ON` clause, it does not require a
+    /// [`joinable!`] foreign key relationship between the two tables, so it
+    /// can be used to join on arbitrary conditions. The tables still need to
+    /// be allowed to appear in the same query via
+    /// [`allow_tables_to_appear_in_same_query!`].
+    ///
+    /// [`inner_join`]: QueryDsl::inner_join()
+    /// [`joinable!`]: crate::joinable!
+    /// [`allow_tables_to_appear_in_same_query!`]: crate::allow_tables_to_appear_in_same_query!
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use schema::{users, posts};
+    /// #
+    /// # fn main() {
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .join_on(
+    ///         posts::table,
+    ///         posts::user_id.eq(users::id).and(posts::title.eq("About Rust")),
+    ///     )
+    ///     .select((users::name, posts::title))
+    ///     .load::<(String, String)>(connection);
+    /// let expected_data = vec![("Sean".to_string(), "About Rust".to_string())];
+    /// assert_eq!(Ok(expected_data), data);
+    /// # }
+    /// ```
+    fn join_on<Rhs, On>(self, rhs: Rhs, on: On) -> ExplicitJoinOn<Self, Rhs, On>
+    where
+        Self: JoinWithImplicitOnClause<joins::OnClauseWrapper<Rhs, On>, joins::Inner>,
+    {
+        self.join_with_implicit_on_clause(joins::OnClauseWrapper::new(rhs, on), joins::Inner)
+    }
+
     /// Join two tables using a SQL `LEFT OUTER JOIN`.
     ///
     /// Behaves similarly to [`inner_join`], but will produce a left join
@@ -611,6 +662,53 @@ pub trait QueryDsl: Sized {
         methods::FilterDsl::filter(self, predicate)
     }
 
+    /// Adds to the `WHERE` clause of a query only if `condition` is true
+    ///
+    /// If `condition` is false, the predicate is replaced by the always-true
+    /// expression `1=1`. As the type of the query does not depend on the
+    /// condition, this avoids mutable variable style query construction or
+    /// boxing when a filter should only be applied conditionally, for
+    /// example based on an optional search parameter.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// fn users_by_name(
+    ///     search: Option<&str>,
+    ///     connection: &mut SqliteConnection,
+    /// ) -> QueryResult<Vec<String>> {
+    ///     users
+    ///         .filter_if(search.is_some(), name.eq(search.unwrap_or_default()))
+    ///         .select(name)
+    ///         .load(connection)
+    /// }
+    ///
+    /// assert_eq!(vec!["Sean"], users_by_name(Some("Sean"), connection)?);
+    /// assert_eq!(vec!["Sean", "Tess"], users_by_name(None, connection)?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "where")]
+    fn filter_if<Predicate>(
+        self,
+        condition: bool,
+        predicate: Predicate,
+    ) -> FilterIf<Self, Predicate>
+    where
+        Self: methods::FilterDsl<MaybePredicate<Predicate>>,
+    {
+        methods::FilterDsl::filter(self, MaybePredicate::new(condition, predicate))
+    }
+
     /// Adds to the `WHERE` clause of a query using `OR`
     ///
     /// If there is already a `WHERE` clause, the result will be `(old OR new)`.
@@ -908,6 +1006,109 @@ pub trait QueryDsl: Sized {
         methods::OffsetDsl::offset(self, offset)
     }
 
+    /// Paginates the results of a query.
+    ///
+    /// This limits the query to `per_page` rows, starting at the given
+    /// 1-based `page`. Pages below 1 are treated as the first page.
+    /// The wrapped query additionally selects the total
+    /// number of rows matching the query via `COUNT(*) OVER ()`, which
+    /// [`load_with_count`](crate::query_builder::Paginated::load_with_count())
+    /// returns alongside the records.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use schema::users;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let (page, total) = users
+    ///     .select(name)
+    ///     .order(id)
+    ///     .paginate(1, 1)
+    ///     .load_with_count::<String, _>(connection)?;
+    ///
+    /// assert_eq!(vec!["Sean"], page);
+    /// assert_eq!(2, total);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn paginate(self, page: i64, per_page: i64) -> crate::query_builder::Paginated<Self>
+    where
+        Self: Sized,
+    {
+        crate::query_builder::Paginated::new(self, page, per_page)
+    }
+
+    /// Restricts the query to rows after the given key, for keyset
+    /// pagination.
+    ///
+    /// In contrast to [`paginate`](QueryDsl::paginate()) this stays fast on
+    /// large tables, since the database can use the index on the key column
+    /// instead of counting skipped rows. The query should be ordered by the
+    /// key column, and the key of the last row of the previous page is
+    /// passed to fetch the next page. This is equivalent to
+    /// `.filter(key.gt(value))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use schema::users;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let first_page = users
+    ///     .select((id, name))
+    ///     .order(id)
+    ///     .limit(1)
+    ///     .load::<(i32, String)>(connection)?;
+    /// let last_seen_id = first_page.last().map(|&(user_id, _)| user_id).unwrap();
+    ///
+    /// let next_page = users
+    ///     .select(name)
+    ///     .order(id)
+    ///     .after_key(id, last_seen_id)
+    ///     .limit(1)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Tess"], next_page);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn after_key<Col, V>(self, key: Col, value: V) -> KeysetPaginated<Self, Col, V>
+    where
+        Col: crate::expression_methods::ExpressionMethods,
+        Col::SqlType: crate::sql_types::SqlType,
+        V: crate::expression::AsExpression<Col::SqlType>,
+        Self: methods::FilterDsl<Gt<Col, V>>,
+    {
+        methods::FilterDsl::filter(self, key.gt(value))
+    }
+
+    /// Filters out soft deleted rows.
+    ///
+    /// This is equivalent to `.filter(deleted_at.is_null())`. See
+    /// [`soft_deletes`](crate::soft_deletes) for how to mark rows as soft
+    /// deleted in the first place.
+    fn excluding_deleted<Col>(self, deleted_at: Col) -> ExcludingDeleted<Self, Col>
+    where
+        Col: crate::expression_methods::ExpressionMethods,
+        Self: methods::FilterDsl<IsNull<Col>>,
+    {
+        methods::FilterDsl::filter(self, deleted_at.is_null())
+    }
+
     /// Sets the `group by` clause of a query.
     ///
     /// **Note:** Queries having a `group by` clause require a custom select clause.
@@ -1407,6 +1608,174 @@ pub trait RunQueryDsl<Conn>: Sized {
         self.internal_load(conn)
     }
 
+    /// Executes the given query, collecting the result into a [`HashMap`]
+    ///
+    /// The query needs to return a pair of a key and a value, typically by
+    /// selecting two columns. If the same key is returned more than once,
+    /// later values overwrite earlier ones. Use
+    /// [`load_as_multimap`](RunQueryDsl::load_as_multimap()) to keep all
+    /// values instead.
+    ///
+    /// [`HashMap`]: std::collections::HashMap
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let names_by_id = users
+    ///     .select((id, name))
+    ///     .load_as_map::<i32, String>(connection)?;
+    /// assert_eq!(2, names_by_id.len());
+    /// assert_eq!(Some(&String::from("Sean")), names_by_id.get(&1));
+    /// assert_eq!(Some(&String::from("Tess")), names_by_id.get(&2));
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn load_as_map<K, V>(self, conn: &mut Conn) -> QueryResult<HashMap<K, V>>
+    where
+        Self: LoadQuery<Conn, (K, V)>,
+        K: std::cmp::Eq + Hash,
+    {
+        Ok(self.internal_load(conn)?.into_iter().collect())
+    }
+
+    /// Executes the given query, grouping the values by key into a [`HashMap`]
+    ///
+    /// The query needs to return a pair of a key and a value, typically by
+    /// selecting two columns. In contrast to
+    /// [`load_as_map`](RunQueryDsl::load_as_map()), all values of a key are
+    /// kept, in the order they are returned by the query.
+    ///
+    /// [`HashMap`]: std::collections::HashMap
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::posts::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let titles_by_user = posts
+    ///     .select((user_id, title))
+    ///     .load_as_multimap::<i32, String>(connection)?;
+    /// assert_eq!(2, titles_by_user.len());
+    /// assert_eq!(
+    ///     Some(&vec![
+    ///         String::from("My first post"),
+    ///         String::from("About Rust"),
+    ///     ]),
+    ///     titles_by_user.get(&1),
+    /// );
+    /// assert_eq!(
+    ///     Some(&vec![String::from("My first post too")]),
+    ///     titles_by_user.get(&2),
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn load_as_multimap<K, V>(self, conn: &mut Conn) -> QueryResult<HashMap<K, Vec<V>>>
+    where
+        Self: LoadQuery<Conn, (K, V)>,
+        K: std::cmp::Eq + Hash,
+    {
+        let mut map = HashMap::new();
+        for (key, value) in self.internal_load(conn)? {
+            map.entry(key).or_insert_with(Vec::new).push(value);
+        }
+        Ok(map)
+    }
+
+    /// Executes the given query, returning whether any row matches the
+    /// given Rust predicate
+    ///
+    /// This is an existence check for conditions that cannot be expressed
+    /// in SQL. Evaluation of the predicate stops at the first match, but
+    /// the result set is still fetched from the database as a whole, so
+    /// consider narrowing the query with [`filter`](QueryDsl::filter())
+    /// or [`limit`](QueryDsl::limit()) first. For predicates expressible
+    /// in SQL, [`ExistsDsl`](methods::ExistsDsl) lets the database stop
+    /// at the first matching row instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let any_palindrome = users
+    ///     .select(name)
+    ///     .any_matching::<String, _>(connection, |n| {
+    ///         n.to_lowercase().chars().rev().eq(n.to_lowercase().chars())
+    ///     })?;
+    /// assert!(!any_palindrome);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn any_matching<U, F>(self, conn: &mut Conn, mut predicate: F) -> QueryResult<bool>
+    where
+        Self: LoadQuery<Conn, U>,
+        F: FnMut(&U) -> bool,
+    {
+        Ok(self.internal_load(conn)?.iter().any(|row| predicate(row)))
+    }
+
+    /// Renders the query as the SQL string that would be sent to the
+    /// database, without executing it
+    ///
+    /// The connection is only used to determine the backend, so the SQL
+    /// uses the backend's placeholder syntax (`$1` for PostgreSQL, `?`
+    /// otherwise) and identifier quoting. This is a more discoverable
+    /// shorthand for [`debug_query`](crate::debug_query()), without the
+    /// trailing bind comment.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let sql = users.select(name).to_sql_string(connection).unwrap();
+    /// # if cfg!(feature = "postgres") {
+    /// #     assert_eq!(sql, r#"SELECT "users"."name" FROM "users""#);
+    /// # } else {
+    /// assert_eq!(sql, "SELECT `users`.`name` FROM `users`");
+    /// # }
+    /// # }
+    /// ```
+    fn to_sql_string(&self, _conn: &mut Conn) -> QueryResult<String>
+    where
+        Conn: Connection,
+        Self: crate::query_builder::QueryFragment<Conn::Backend>,
+        <Conn::Backend as Backend>::QueryBuilder: Default,
+    {
+        use crate::query_builder::QueryBuilder;
+
+        let mut query_builder = <Conn::Backend as Backend>::QueryBuilder::default();
+        self.to_sql(&mut query_builder)?;
+        Ok(query_builder.finish())
+    }
+
     /// Runs the command, and returns the affected row.
     ///
     /// `Err(NotFound)` will be returned if the query affected 0 rows. You can
@@ -0,0 +1,128 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::query_dsl::load_dsl::LoadQuery;
+use crate::query_source::{ColumnNames, Table};
+use crate::result::QueryResult;
+
+/// The `values_of` method
+///
+/// This trait should not be relied on directly by most apps. Its behavior is
+/// provided by [`values_of`](ValuesOfDsl::values_of()). However, you may need
+/// a where clause on this trait to call `values_of` from generic code.
+pub trait ValuesOfDsl<Conn>: Sized {
+    /// Loads this query, pairing each value with the name of its column
+    ///
+    /// The rows are loaded as `U`, which needs to be given explicitly just
+    /// like for [`load`](crate::RunQueryDsl::load()). Each row is then
+    /// returned as a [`ColumnValues`], which pairs the values with the names
+    /// of the table's columns and erases their types, so they can be
+    /// processed uniformly without matching on the shape of the row.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::query_dsl::ValuesOfDsl;
+    /// # use schema::users;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let connection = &mut establish_connection();
+    /// let rows = users::table.values_of::<(i32, String)>(connection)?;
+    ///
+    /// let mut described = Vec::new();
+    /// for row in rows {
+    ///     for (column, value) in row {
+    ///         if let Some(id) = value.downcast_ref::<i32>() {
+    ///             described.push(format!("{} = {}", column, id));
+    ///         } else if let Some(name) = value.downcast_ref::<String>() {
+    ///             described.push(format!("{} = {}", column, name));
+    ///         }
+    ///     }
+    /// }
+    /// let expected = vec!["id = 1", "name = Sean", "id = 2", "name = Tess"];
+    /// assert_eq!(expected, described);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn values_of<U>(self, conn: &mut Conn) -> QueryResult<Vec<ColumnValues>>
+    where
+        Self: LoadQuery<Conn, U>,
+        U: IntoAnyValues;
+}
+
+impl<T, Conn> ValuesOfDsl<Conn> for T
+where
+    T: Table,
+    T::AllColumns: ColumnNames,
+{
+    fn values_of<U>(self, conn: &mut Conn) -> QueryResult<Vec<ColumnValues>>
+    where
+        Self: LoadQuery<Conn, U>,
+        U: IntoAnyValues,
+    {
+        let names = <T::AllColumns as ColumnNames>::column_names();
+        let rows = self.internal_load(conn)?;
+        Ok(rows
+            .into_iter()
+            .map(|row| ColumnValues {
+                names,
+                values: row.into_any_values(),
+            })
+            .collect())
+    }
+}
+
+/// A single row returned by [`values_of`](ValuesOfDsl::values_of()),
+/// pairing each value with the name of its column
+pub struct ColumnValues {
+    names: &'static [&'static str],
+    values: Vec<Box<dyn Any>>,
+}
+
+impl ColumnValues {
+    /// The names of the columns of this row, in order
+    pub fn names(&self) -> &'static [&'static str] {
+        self.names
+    }
+
+    /// Returns the value of the column with the given name, if it exists
+    /// and has the given type
+    pub fn get<V: Any>(&self, name: &str) -> Option<&V> {
+        let idx = self.names.iter().position(|n| *n == name)?;
+        self.values.get(idx)?.downcast_ref()
+    }
+}
+
+impl fmt::Debug for ColumnValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ColumnValues")
+            .field("names", &self.names)
+            .finish_non_exhaustive()
+    }
+}
+
+impl IntoIterator for ColumnValues {
+    type Item = (&'static str, Box<dyn Any>);
+    type IntoIter = std::iter::Zip<
+        std::iter::Copied<std::slice::Iter<'static, &'static str>>,
+        std::vec::IntoIter<Box<dyn Any>>,
+    >;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.names.iter().copied().zip(self.values)
+    }
+}
+
+/// A row whose values can be type erased to [`Any`], implemented for tuples
+///
+/// This trait is used by [`values_of`](ValuesOfDsl::values_of()) to build
+/// [`ColumnValues`] from loaded rows.
+pub trait IntoAnyValues {
+    /// Converts each value of this row into a boxed [`Any`]
+    fn into_any_values(self) -> Vec<Box<dyn Any>>;
+}
@@ -46,6 +46,16 @@ pub trait Column: Expression {
     const NAME: &'static str;
 }
 
+/// A tuple of columns whose names are known at compile time
+///
+/// This trait is implemented for tuples of types implementing [`Column`],
+/// and is used to validate column names provided at runtime, for example by
+/// [`JsonChangeset`](crate::query_builder::JsonChangeset).
+pub trait ColumnNames {
+    /// The names of the columns in this tuple, in order
+    fn column_names() -> &'static [&'static str];
+}
+
 /// A SQL database table. Types which implement this trait should have been
 /// generated by the [`table!` macro](crate::table!).
 pub trait Table: QuerySource + AsQuery + Sized {
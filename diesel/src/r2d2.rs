@@ -231,6 +231,167 @@ where
     }
 }
 
+/// Applies a statement timeout to every connection of a pool
+///
+/// This customizer calls
+/// [`set_statement_timeout`](crate::connection::StatementTimeout::set_statement_timeout())
+/// when a connection is established, so every connection handed out by
+/// the pool aborts statements running longer than the given duration.
+/// Individual queries can still lower the timeout for the current
+/// transaction via `SET LOCAL statement_timeout` on PostgreSQL; the
+/// session level value set here applies again afterwards.
+///
+/// ```rust,no_run
+/// # use diesel::prelude::*;
+/// # use std::time::Duration;
+/// use diesel::r2d2::{ConnectionManager, Pool, StatementTimeoutCustomizer};
+///
+/// let manager = ConnectionManager::<PgConnection>::new("postgres://localhost/mydb");
+/// let pool = Pool::builder()
+///     .connection_customizer(Box::new(StatementTimeoutCustomizer::new(
+///         Duration::from_secs(5),
+///     )))
+///     .build(manager)
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct StatementTimeoutCustomizer {
+    timeout: std::time::Duration,
+}
+
+impl StatementTimeoutCustomizer {
+    /// Creates a customizer applying the given statement timeout
+    pub fn new(timeout: std::time::Duration) -> Self {
+        StatementTimeoutCustomizer { timeout }
+    }
+}
+
+impl<C> CustomizeConnection<C, Error> for StatementTimeoutCustomizer
+where
+    C: crate::connection::StatementTimeout,
+{
+    fn on_acquire(&self, conn: &mut C) -> Result<(), Error> {
+        conn.set_statement_timeout(self.timeout)
+            .map_err(Error::QueryError)
+    }
+}
+
+#[cfg(feature = "prometheus")]
+pub use self::metrics::PrometheusConnectionCustomizer;
+
+#[cfg(feature = "prometheus")]
+mod metrics {
+    extern crate prometheus;
+
+    use std::fmt;
+
+    use self::prometheus::{Histogram, HistogramOpts, IntCounter, IntGauge, Registry};
+    use super::r2d2::event::{CheckinEvent, CheckoutEvent, TimeoutEvent};
+    use super::r2d2::{CustomizeConnection, HandleEvent};
+
+    /// Exposes pool state as Prometheus metrics.
+    ///
+    /// This registers the following metrics with the given registry:
+    ///
+    /// * `diesel_r2d2_connections`: the number of currently established
+    ///   connections
+    /// * `diesel_r2d2_connections_in_use`: the number of connections
+    ///   currently checked out of the pool
+    /// * `diesel_r2d2_checkout_wait_seconds`: a histogram of the time spent
+    ///   waiting for a connection to be checked out
+    /// * `diesel_r2d2_checkout_timeouts_total`: the number of checkout
+    ///   attempts which timed out
+    ///
+    /// The customizer needs to be registered with the pool builder twice, as
+    /// establishing connections and pool events are reported through
+    /// separate traits:
+    ///
+    /// ```rust,no_run
+    /// # use diesel::prelude::*;
+    /// use diesel::r2d2::{ConnectionManager, Pool, PrometheusConnectionCustomizer};
+    ///
+    /// let registry = prometheus::Registry::new();
+    /// let metrics = PrometheusConnectionCustomizer::new(&registry).unwrap();
+    /// let manager = ConnectionManager::<SqliteConnection>::new(":memory:");
+    /// let pool = Pool::builder()
+    ///     .connection_customizer(Box::new(metrics.clone()))
+    ///     .event_handler(Box::new(metrics))
+    ///     .build(manager)
+    ///     .unwrap();
+    /// ```
+    #[derive(Clone)]
+    pub struct PrometheusConnectionCustomizer {
+        connections: IntGauge,
+        connections_in_use: IntGauge,
+        checkout_wait: Histogram,
+        checkout_timeouts: IntCounter,
+    }
+
+    impl PrometheusConnectionCustomizer {
+        /// Creates the pool metrics and registers them with the given registry
+        pub fn new(registry: &Registry) -> Result<Self, prometheus::Error> {
+            let connections = IntGauge::new(
+                "diesel_r2d2_connections",
+                "Number of currently established connections",
+            )?;
+            let connections_in_use = IntGauge::new(
+                "diesel_r2d2_connections_in_use",
+                "Number of connections currently checked out of the pool",
+            )?;
+            let checkout_wait = Histogram::with_opts(HistogramOpts::new(
+                "diesel_r2d2_checkout_wait_seconds",
+                "Time spent waiting for a connection to be checked out",
+            ))?;
+            let checkout_timeouts = IntCounter::new(
+                "diesel_r2d2_checkout_timeouts_total",
+                "Number of checkout attempts which timed out",
+            )?;
+            registry.register(Box::new(connections.clone()))?;
+            registry.register(Box::new(connections_in_use.clone()))?;
+            registry.register(Box::new(checkout_wait.clone()))?;
+            registry.register(Box::new(checkout_timeouts.clone()))?;
+            Ok(PrometheusConnectionCustomizer {
+                connections,
+                connections_in_use,
+                checkout_wait,
+                checkout_timeouts,
+            })
+        }
+    }
+
+    impl fmt::Debug for PrometheusConnectionCustomizer {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "PrometheusConnectionCustomizer")
+        }
+    }
+
+    impl<C, E> CustomizeConnection<C, E> for PrometheusConnectionCustomizer {
+        fn on_acquire(&self, _conn: &mut C) -> Result<(), E> {
+            self.connections.inc();
+            Ok(())
+        }
+
+        fn on_release(&self, _conn: C) {
+            self.connections.dec();
+        }
+    }
+
+    impl HandleEvent for PrometheusConnectionCustomizer {
+        fn handle_checkout(&self, event: CheckoutEvent) {
+            self.connections_in_use.inc();
+            self.checkout_wait.observe(event.duration().as_secs_f64());
+        }
+
+        fn handle_timeout(&self, _event: TimeoutEvent) {
+            self.checkout_timeouts.inc();
+        }
+
+        fn handle_checkin(&self, _event: CheckinEvent) {
+            self.connections_in_use.dec();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::mpsc;
@@ -79,6 +79,15 @@ pub enum Error {
     /// Attempted to perform an operation that cannot be done inside a transaction
     /// when a transaction was already open.
     AlreadyInTransaction,
+
+    /// An update guarded by an optimistic lock affected no rows.
+    ///
+    /// This variant is returned by
+    /// [`save`](crate::versioning::VersionedSaveDsl::save()) when the
+    /// record's `version` column no longer matches the value it had when
+    /// the record was loaded, meaning it was modified or deleted
+    /// concurrently.
+    StaleObjectError,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -301,6 +310,11 @@ impl Display for Error {
                 f,
                 "Cannot perform this operation while a transaction is open",
             ),
+            Error::StaleObjectError => write!(
+                f,
+                "Attempted to update a stale record. The record was modified \
+                 or deleted since it was loaded",
+            ),
         }
     }
 }
@@ -348,6 +362,7 @@ impl PartialEq for Error {
             (&Error::NotFound, &Error::NotFound) => true,
             (&Error::RollbackTransaction, &Error::RollbackTransaction) => true,
             (&Error::AlreadyInTransaction, &Error::AlreadyInTransaction) => true,
+            (&Error::StaleObjectError, &Error::StaleObjectError) => true,
             _ => false,
         }
     }
@@ -0,0 +1,120 @@
+//! Types and traits related to soft deleting records
+//!
+//! Instead of removing rows, soft deletes mark them as deleted by setting
+//! a nullable `deleted_at` timestamp column. Deriving
+//! [`SoftDeletable`](derive@crate::SoftDeletable) for a model whose table
+//! has such a column enables [`soft_delete`](SoftDeleteDsl::soft_delete())
+//! and [`soft_restore`](SoftDeleteDsl::soft_restore()), while
+//! [`excluding_deleted`](crate::QueryDsl::excluding_deleted()) filters
+//! soft deleted rows out of queries.
+
+use crate::connection::Connection;
+use crate::dsl;
+use crate::expression::sql_literal::SqlLiteral;
+use crate::expression_methods::{ExpressionMethods, NullableExpressionMethods};
+use crate::query_builder::{AsQuery, IntoUpdateTarget};
+use crate::query_dsl::methods::ExecuteDsl;
+use crate::query_dsl::RunQueryDsl;
+use crate::query_source::Column;
+use crate::result::QueryResult;
+use crate::sql_types::{Nullable, Timestamp};
+
+/// The changeset used by [`SoftDeleteDsl::soft_delete()`]
+pub type SetDeletedAt<C> = dsl::Eq<C, dsl::Nullable<dsl::now>>;
+
+/// The changeset used by [`SoftDeleteDsl::soft_restore()`]
+pub type ClearDeletedAt<C> = dsl::Eq<C, SqlLiteral<Nullable<Timestamp>>>;
+
+/// A model type whose table has a nullable `deleted_at` timestamp column
+///
+/// This trait is normally implemented by
+/// [`#[derive(SoftDeletable)]`](derive@crate::SoftDeletable), which
+/// requires the struct to have a `deleted_at` field of type
+/// `Option<...>`.
+pub trait SoftDeletable {
+    /// The `deleted_at` column of the corresponding table
+    type DeletedAt: Column<SqlType = Nullable<Timestamp>> + Default;
+}
+
+#[doc(inline)]
+pub use diesel_derives::SoftDeletable;
+
+impl<'a, T: SoftDeletable> SoftDeletable for &'a T {
+    type DeletedAt = T::DeletedAt;
+}
+
+/// Methods for soft deleting and restoring single records
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// # use diesel::soft_deletes::SoftDeleteDsl;
+/// #
+/// table! {
+///     sessions (id) {
+///         id -> Integer,
+///         deleted_at -> Nullable<Timestamp>,
+///     }
+/// }
+///
+/// #[derive(Identifiable, SoftDeletable)]
+/// #[table_name = "sessions"]
+/// struct Session {
+///     id: i32,
+///     deleted_at: Option<String>,
+/// }
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use self::sessions::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// #     diesel::sql_query(
+/// #         "CREATE TABLE sessions (id INTEGER PRIMARY KEY, deleted_at TIMESTAMP)"
+/// #     ).execute(connection)?;
+/// #     diesel::insert_into(sessions).values(id.eq(1)).execute(connection)?;
+/// let session = Session { id: 1, deleted_at: None };
+///
+/// session.soft_delete(connection)?;
+/// let active = sessions.excluding_deleted(deleted_at).count().get_result::<i64>(connection)?;
+/// assert_eq!(0, active);
+///
+/// session.soft_restore(connection)?;
+/// let active = sessions.excluding_deleted(deleted_at).count().get_result::<i64>(connection)?;
+/// assert_eq!(1, active);
+/// #     Ok(())
+/// # }
+/// ```
+pub trait SoftDeleteDsl<Conn>: Sized {
+    /// Marks this record as deleted by setting its `deleted_at` column to
+    /// the current time
+    fn soft_delete(self, conn: &mut Conn) -> QueryResult<usize>;
+
+    /// Restores this record by setting its `deleted_at` column back to
+    /// `NULL`
+    fn soft_restore(self, conn: &mut Conn) -> QueryResult<usize>;
+}
+
+impl<T, Conn> SoftDeleteDsl<Conn> for T
+where
+    T: SoftDeletable + IntoUpdateTarget,
+    T::DeletedAt: Column<Table = T::Table>,
+    Conn: Connection,
+    dsl::Update<T, SetDeletedAt<T::DeletedAt>>: AsQuery + ExecuteDsl<Conn>,
+    dsl::Update<T, ClearDeletedAt<T::DeletedAt>>: AsQuery + ExecuteDsl<Conn>,
+{
+    fn soft_delete(self, conn: &mut Conn) -> QueryResult<usize> {
+        crate::update(self)
+            .set(T::DeletedAt::default().eq(dsl::now.nullable()))
+            .execute(conn)
+    }
+
+    fn soft_restore(self, conn: &mut Conn) -> QueryResult<usize> {
+        crate::update(self)
+            .set(T::DeletedAt::default().eq(dsl::sql::<Nullable<Timestamp>>("NULL")))
+            .execute(conn)
+    }
+}
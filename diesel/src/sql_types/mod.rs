@@ -267,6 +267,29 @@ pub type Varbinary = Binary;
 #[doc(hidden)]
 pub type Bit = Binary;
 
+/// The `UUID` SQL type. This type can only be used with `feature = "uuid"`
+///
+/// On PostgreSQL this corresponds to the native `UUID` type. MySQL has no
+/// native UUID type, so this type maps to a `BINARY(16)` column there. When
+/// loading from MySQL, values stored as 36 character `CHAR(36)` text are
+/// also accepted.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`uuid::Uuid`][Uuid]
+///
+/// ### [`FromSql`] impls
+///
+/// - [`uuid::Uuid`][Uuid]
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+/// [Uuid]: https://docs.rs/uuid/*/uuid/struct.Uuid.html
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[postgres(oid = "2950", array_oid = "2951")]
+#[mysql_type = "Blob"]
+pub struct Uuid;
+
 /// The date SQL type.
 ///
 /// ### [`ToSql`](crate::serialize::ToSql) impls
@@ -349,6 +372,55 @@ pub struct Time;
 #[mysql_type = "Timestamp"]
 pub struct Timestamp;
 
+/// The "timestamp with time zone" SQL type.
+///
+/// On PostgreSQL this corresponds to `TIMESTAMP WITH TIME ZONE`
+/// (abbreviated to `timestamptz`). MySQL stores the value as `DATETIME`
+/// normalized to UTC, and SQLite stores an ISO 8601 text string. Values are
+/// always transmitted in UTC; the offset of a timezone aware value is applied
+/// before it is sent to the backend.
+///
+/// ### [`ToSql`] impls
+///
+/// - [`PgTimestamp`] with `feature = "postgres"`
+/// - [`chrono::NaiveDateTime`] with `feature = "chrono"`
+/// - [`chrono::DateTime`] with `feature = "chrono"`
+///
+/// ### [`FromSql`] impls
+///
+/// - [`PgTimestamp`] with `feature = "postgres"`
+/// - [`chrono::NaiveDateTime`] with `feature = "chrono"`
+/// - [`chrono::DateTime`] with `feature = "chrono"`
+///
+/// [`ToSql`]: crate::serialize::ToSql
+/// [`FromSql`]: crate::deserialize::FromSql
+#[cfg_attr(
+    feature = "chrono",
+    doc = " [`chrono::NaiveDateTime`]: chrono::naive::NaiveDateTime"
+)]
+#[cfg_attr(
+    not(feature = "chrono"),
+    doc = " [`chrono::NaiveDateTime`]: https://docs.rs/chrono/0.4.19/chrono/naive/struct.NaiveDateTime.html"
+)]
+#[cfg_attr(feature = "chrono", doc = " [`chrono::DateTime`]: chrono::DateTime")]
+#[cfg_attr(
+    not(feature = "chrono"),
+    doc = " [`chrono::DateTime`]: https://docs.rs/chrono/0.4.19/chrono/struct.DateTime.html"
+)]
+#[cfg_attr(
+    feature = "postgres",
+    doc = " [`PgTimestamp`]: crate::pg::data_types::PgTimestamp"
+)]
+#[cfg_attr(
+    not(feature = "postgres"),
+    doc = " [`PgTimestamp`]: https://docs.rs/diesel/2.0.0/diesel/pg/data_types/struct.PgTimestamp.html"
+)]
+#[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+#[postgres(oid = "1184", array_oid = "1185")]
+#[sqlite_type = "Text"]
+#[mysql_type = "DateTime"]
+pub struct Timestamptz;
+
 /// The JSON SQL type.  This type can only be used with `feature =
 /// "serde_json"`
 ///
@@ -368,6 +440,7 @@ pub struct Timestamp;
 /// [`serde_json::Value`]: /../serde_json/value/enum.Value.html
 #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
 #[postgres(oid = "114", array_oid = "199")]
+#[sqlite_type = "Text"]
 #[mysql_type = "String"]
 pub struct Json;
 
@@ -0,0 +1,207 @@
+extern crate libsqlite3_sys as ffi;
+
+/// The decision an authorizer callback returns for an action
+///
+/// See [`SqliteConnection::set_authorizer`] for details.
+///
+/// [`SqliteConnection::set_authorizer`]: super::SqliteConnection::set_authorizer()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteAuthorizerDecision {
+    /// Allow the action
+    Allow,
+    /// Reject the whole statement with an authorization error
+    Deny,
+    /// Allow the statement, but treat the action as a no-op: denied
+    /// columns read as `NULL`, denied writes are silently skipped
+    Ignore,
+}
+
+/// An action SQLite asks the authorizer callback to approve
+///
+/// Temporary objects are reported with the same variants as their
+/// persistent counterparts. Action codes without a dedicated variant
+/// are reported as [`Other`](SqliteAuthorizeAction::Other).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SqliteAuthorizeAction<'a> {
+    /// A column is read, e.g. by `SELECT` or in a `WHERE` clause
+    Read {
+        /// The table the column belongs to
+        table: &'a str,
+        /// The column being read
+        column: &'a str,
+    },
+    /// A `SELECT` statement is executed
+    Select,
+    /// Rows are inserted into a table
+    Insert {
+        /// The table being inserted into
+        table: &'a str,
+    },
+    /// A column is updated
+    Update {
+        /// The table being updated
+        table: &'a str,
+        /// The column being assigned to
+        column: &'a str,
+    },
+    /// Rows are deleted from a table
+    Delete {
+        /// The table being deleted from
+        table: &'a str,
+    },
+    /// A table is created
+    CreateTable {
+        /// The name of the new table
+        table: &'a str,
+    },
+    /// A table is dropped
+    DropTable {
+        /// The table being dropped
+        table: &'a str,
+    },
+    /// An index is created
+    CreateIndex {
+        /// The name of the new index
+        index: &'a str,
+        /// The table being indexed
+        table: &'a str,
+    },
+    /// An index is dropped
+    DropIndex {
+        /// The index being dropped
+        index: &'a str,
+        /// The table the index belongs to
+        table: &'a str,
+    },
+    /// A view is created
+    CreateView {
+        /// The name of the new view
+        view: &'a str,
+    },
+    /// A view is dropped
+    DropView {
+        /// The view being dropped
+        view: &'a str,
+    },
+    /// A trigger is created
+    CreateTrigger {
+        /// The name of the new trigger
+        trigger: &'a str,
+        /// The table the trigger belongs to
+        table: &'a str,
+    },
+    /// A trigger is dropped
+    DropTrigger {
+        /// The trigger being dropped
+        trigger: &'a str,
+        /// The table the trigger belongs to
+        table: &'a str,
+    },
+    /// A table is altered
+    AlterTable {
+        /// The database containing the table
+        database: &'a str,
+        /// The table being altered
+        table: &'a str,
+    },
+    /// A `PRAGMA` is executed
+    Pragma {
+        /// The name of the pragma
+        name: &'a str,
+        /// The value it is set to, if any
+        value: Option<&'a str>,
+    },
+    /// A transaction is begun, committed or rolled back
+    Transaction {
+        /// `BEGIN`, `COMMIT` or `ROLLBACK`
+        operation: &'a str,
+    },
+    /// A savepoint is created, released or rolled back to
+    Savepoint {
+        /// `BEGIN`, `RELEASE` or `ROLLBACK`
+        operation: &'a str,
+        /// The name of the savepoint
+        name: &'a str,
+    },
+    /// A database is attached
+    Attach {
+        /// The file being attached
+        filename: &'a str,
+    },
+    /// A database is detached
+    Detach {
+        /// The schema name of the database being detached
+        database: &'a str,
+    },
+    /// A SQL function is invoked
+    Function {
+        /// The name of the function
+        function: &'a str,
+    },
+    /// An action without a dedicated variant
+    Other {
+        /// The raw `SQLITE_*` action code
+        code: i32,
+    },
+}
+
+impl<'a> SqliteAuthorizeAction<'a> {
+    pub(super) fn from_raw(code: i32, arg1: Option<&'a str>, arg2: Option<&'a str>) -> Self {
+        use self::SqliteAuthorizeAction::*;
+
+        let arg1 = arg1.unwrap_or_default();
+        match code {
+            ffi::SQLITE_READ => Read {
+                table: arg1,
+                column: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_SELECT => Select,
+            ffi::SQLITE_INSERT => Insert { table: arg1 },
+            ffi::SQLITE_UPDATE => Update {
+                table: arg1,
+                column: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_DELETE => Delete { table: arg1 },
+            ffi::SQLITE_CREATE_TABLE | ffi::SQLITE_CREATE_TEMP_TABLE => CreateTable { table: arg1 },
+            ffi::SQLITE_DROP_TABLE | ffi::SQLITE_DROP_TEMP_TABLE => DropTable { table: arg1 },
+            ffi::SQLITE_CREATE_INDEX | ffi::SQLITE_CREATE_TEMP_INDEX => CreateIndex {
+                index: arg1,
+                table: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_DROP_INDEX | ffi::SQLITE_DROP_TEMP_INDEX => DropIndex {
+                index: arg1,
+                table: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_CREATE_VIEW | ffi::SQLITE_CREATE_TEMP_VIEW => CreateView { view: arg1 },
+            ffi::SQLITE_DROP_VIEW | ffi::SQLITE_DROP_TEMP_VIEW => DropView { view: arg1 },
+            ffi::SQLITE_CREATE_TRIGGER | ffi::SQLITE_CREATE_TEMP_TRIGGER => CreateTrigger {
+                trigger: arg1,
+                table: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_DROP_TRIGGER | ffi::SQLITE_DROP_TEMP_TRIGGER => DropTrigger {
+                trigger: arg1,
+                table: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_ALTER_TABLE => AlterTable {
+                database: arg1,
+                table: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_PRAGMA => Pragma {
+                name: arg1,
+                value: arg2,
+            },
+            ffi::SQLITE_TRANSACTION => Transaction { operation: arg1 },
+            ffi::SQLITE_SAVEPOINT => Savepoint {
+                operation: arg1,
+                name: arg2.unwrap_or_default(),
+            },
+            ffi::SQLITE_ATTACH => Attach { filename: arg1 },
+            ffi::SQLITE_DETACH => Detach { database: arg1 },
+            ffi::SQLITE_FUNCTION => Function {
+                function: arg2.unwrap_or_default(),
+            },
+            code => Other { code },
+        }
+    }
+}
@@ -1,5 +1,6 @@
 extern crate libsqlite3_sys as ffi;
 
+mod authorizer;
 mod functions;
 #[doc(hidden)]
 pub mod raw;
@@ -8,6 +9,7 @@ mod sqlite_value;
 mod statement_iterator;
 mod stmt;
 
+pub use self::authorizer::{SqliteAuthorizeAction, SqliteAuthorizerDecision};
 pub use self::sqlite_value::SqliteValue;
 
 use std::os::raw as libc;
@@ -175,6 +177,157 @@ impl SqliteConnection {
         self.transaction_sql(f, "BEGIN EXCLUSIVE")
     }
 
+    /// Attaches another database file to this connection
+    ///
+    /// This executes `ATTACH DATABASE`, making the tables of the given
+    /// file available under the given schema name. Tables of the
+    /// attached database can be declared via the schema syntax of
+    /// [`table!`](crate::table!), e.g.
+    /// `table! { other.users (id) { ... } }`, and then queried together
+    /// with tables of the main database.
+    ///
+    /// Use [`detach_database`](SqliteConnection::detach_database()) to
+    /// detach it again.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     other.users (id) {
+    /// #         id -> Integer,
+    /// #         name -> Text,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::users::dsl::*;
+    /// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.attach_database(":memory:", "other")?;
+    /// conn.execute("CREATE TABLE other.users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)")?;
+    ///
+    /// diesel::insert_into(users)
+    ///     .values(name.eq("Sean"))
+    ///     .execute(conn)?;
+    /// let names = users.select(name).load::<String>(conn)?;
+    /// assert_eq!(vec![String::from("Sean")], names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn attach_database(&mut self, path: &str, schema_name: &str) -> QueryResult<()> {
+        self.execute(&format!(
+            "ATTACH DATABASE '{}' AS {}",
+            path.replace('\'', "''"),
+            quoted_identifier(schema_name),
+        ))
+        .map(|_| ())
+    }
+
+    /// Detaches a database previously attached with
+    /// [`attach_database`](SqliteConnection::attach_database())
+    pub fn detach_database(&mut self, schema_name: &str) -> QueryResult<()> {
+        self.execute(&format!(
+            "DETACH DATABASE {}",
+            quoted_identifier(schema_name),
+        ))
+        .map(|_| ())
+    }
+
+    /// Installs an authorizer callback which is consulted for every
+    /// action a statement attempts while it is being compiled
+    ///
+    /// This wraps `sqlite3_set_authorizer`. The callback decides per
+    /// action whether to [`Allow`] it, [`Deny`] the whole statement, or
+    /// [`Ignore`] the action (denied columns read as `NULL`, denied
+    /// writes are skipped). This is useful to sandbox SQL from less
+    /// trusted sources, e.g. in multi-tenant applications.
+    ///
+    /// A previously installed authorizer is replaced; use
+    /// [`clear_authorizer`](SqliteConnection::clear_authorizer()) to
+    /// remove it again.
+    ///
+    /// [`Allow`]: SqliteAuthorizerDecision::Allow
+    /// [`Deny`]: SqliteAuthorizerDecision::Deny
+    /// [`Ignore`]: SqliteAuthorizerDecision::Ignore
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use diesel::sqlite::{SqliteAuthorizeAction, SqliteAuthorizerDecision};
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")?;
+    ///
+    /// conn.set_authorizer(|action| match action {
+    ///     SqliteAuthorizeAction::Delete { .. } => SqliteAuthorizerDecision::Deny,
+    ///     _ => SqliteAuthorizerDecision::Allow,
+    /// })?;
+    ///
+    /// assert!(conn.execute("DELETE FROM users").is_err());
+    /// assert!(conn.execute("SELECT * FROM users").is_ok());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_authorizer<F>(&mut self, authorizer: F) -> QueryResult<()>
+    where
+        F: Fn(SqliteAuthorizeAction<'_>) -> SqliteAuthorizerDecision + Send + 'static,
+    {
+        self.raw_connection.set_authorizer(authorizer)
+    }
+
+    /// Removes an authorizer installed with
+    /// [`set_authorizer`](SqliteConnection::set_authorizer())
+    pub fn clear_authorizer(&mut self) -> QueryResult<()> {
+        self.raw_connection.clear_authorizer()
+    }
+
+    /// Loads a SQLite extension, such as `spatialite`, from the given
+    /// shared library
+    ///
+    /// This calls `sqlite3_load_extension` with the extension's default
+    /// entry point. Extension loading is only enabled for the duration
+    /// of the call, and only for the C API, so the SQL
+    /// `load_extension()` function stays disabled and SQL from
+    /// untrusted sources cannot load code.
+    ///
+    /// The file extension of the library (`.so`, `.dylib`, `.dll`) may
+    /// be omitted; SQLite appends the platform's convention itself.
+    ///
+    /// Requires the `sqlite-loadable-extensions` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// # use std::path::Path;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.load_extension(Path::new("/usr/lib/mod_spatialite"))?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlite-loadable-extensions")]
+    pub fn load_extension(&mut self, path: &std::path::Path) -> QueryResult<()> {
+        let path = std::ffi::CString::new(path.to_string_lossy().as_bytes())?;
+        self.raw_connection.load_extension(&path)
+    }
+
     fn transaction_sql<T, E, F>(&mut self, f: F, sql: &str) -> Result<T, E>
     where
         F: FnOnce(&mut Self) -> Result<T, E>,
@@ -335,6 +488,10 @@ fn error_message(err_code: libc::c_int) -> &'static str {
     ffi::code_to_str(err_code)
 }
 
+fn quoted_identifier(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -361,6 +518,14 @@ mod tests {
         assert_eq!(0, connection.statement_cache.len());
     }
 
+    #[test]
+    #[cfg(feature = "sqlite-loadable-extensions")]
+    fn load_extension_reports_missing_extensions() {
+        let connection = &mut SqliteConnection::establish(":memory:").unwrap();
+        let result = connection.load_extension(std::path::Path::new("/no/such/extension/anywhere"));
+        assert!(result.is_err());
+    }
+
     #[test]
     fn queries_containing_sql_literal_nodes_are_not_cached() {
         let connection = &mut SqliteConnection::establish(":memory:").unwrap();
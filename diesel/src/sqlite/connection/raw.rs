@@ -1,11 +1,12 @@
 extern crate libsqlite3_sys as ffi;
 
-use std::ffi::{CString, NulError};
+use std::ffi::{CStr, CString, NulError};
 use std::io::{stderr, Write};
 use std::os::raw as libc;
 use std::ptr::NonNull;
 use std::{mem, ptr, slice, str};
 
+use super::authorizer::{SqliteAuthorizeAction, SqliteAuthorizerDecision};
 use super::functions::{build_sql_function_args, process_sql_function_result};
 use super::serialized_value::SerializedValue;
 use super::stmt::ensure_sqlite_ok;
@@ -29,9 +30,16 @@ macro_rules! assert_fail {
     };
 }
 
+type BoxedAuthorizer =
+    Box<dyn Fn(SqliteAuthorizeAction<'_>) -> SqliteAuthorizerDecision + Send + 'static>;
+
 #[allow(missing_debug_implementations, missing_copy_implementations)]
 pub struct RawConnection {
     pub(crate) internal_connection: NonNull<ffi::sqlite3>,
+    // The registered authorizer callback, kept alive for as long as
+    // SQLite may invoke it. `AssertUnwindSafe` as the callback is only
+    // ever invoked behind its own `catch_unwind`
+    authorizer: Option<std::panic::AssertUnwindSafe<Box<BoxedAuthorizer>>>,
 }
 
 impl RawConnection {
@@ -53,6 +61,7 @@ impl RawConnection {
                 let conn_pointer = unsafe { NonNull::new_unchecked(conn_pointer) };
                 Ok(RawConnection {
                     internal_connection: conn_pointer,
+                    authorizer: None,
                 })
             }
             err_code => {
@@ -79,10 +88,94 @@ impl RawConnection {
         ensure_sqlite_ok(result, self.internal_connection.as_ptr())
     }
 
+    #[cfg(feature = "sqlite-loadable-extensions")]
+    pub fn load_extension(&self, path: &CStr) -> QueryResult<()> {
+        // The bundled bindings predate this constant
+        const SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION: libc::c_int = 1005;
+
+        // Extension loading is enabled only for the C API, not for the
+        // `load_extension()` SQL function, and only for the duration of
+        // this call, so SQL from untrusted sources cannot load code
+        let result = unsafe {
+            ffi::sqlite3_db_config(
+                self.internal_connection.as_ptr(),
+                SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION,
+                1 as libc::c_int,
+                ptr::null_mut::<libc::c_int>(),
+            )
+        };
+        ensure_sqlite_ok(result, self.internal_connection.as_ptr())?;
+
+        let mut err_msg = ptr::null_mut();
+        let result = unsafe {
+            ffi::sqlite3_load_extension(
+                self.internal_connection.as_ptr(),
+                path.as_ptr(),
+                ptr::null(),
+                &mut err_msg,
+            )
+        };
+        let load_result = if result == ffi::SQLITE_OK {
+            Ok(())
+        } else {
+            let message = if err_msg.is_null() {
+                super::error_message(result).to_owned()
+            } else {
+                unsafe { CStr::from_ptr(err_msg) }
+                    .to_string_lossy()
+                    .into_owned()
+            };
+            Err(DatabaseError(DatabaseErrorKind::Unknown, Box::new(message)))
+        };
+        if !err_msg.is_null() {
+            unsafe { ffi::sqlite3_free(err_msg as *mut libc::c_void) };
+        }
+
+        let result = unsafe {
+            ffi::sqlite3_db_config(
+                self.internal_connection.as_ptr(),
+                SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION,
+                0 as libc::c_int,
+                ptr::null_mut::<libc::c_int>(),
+            )
+        };
+        ensure_sqlite_ok(result, self.internal_connection.as_ptr())?;
+        load_result
+    }
+
     pub fn rows_affected_by_last_query(&self) -> usize {
         unsafe { ffi::sqlite3_changes(self.internal_connection.as_ptr()) as usize }
     }
 
+    pub fn set_authorizer<F>(&mut self, authorizer: F) -> QueryResult<()>
+    where
+        F: Fn(SqliteAuthorizeAction<'_>) -> SqliteAuthorizerDecision + Send + 'static,
+    {
+        let boxed: Box<BoxedAuthorizer> = Box::new(Box::new(authorizer));
+        let user_ptr = &*boxed as *const BoxedAuthorizer as *mut libc::c_void;
+        let result = unsafe {
+            ffi::sqlite3_set_authorizer(
+                self.internal_connection.as_ptr(),
+                Some(run_authorizer),
+                user_ptr,
+            )
+        };
+        ensure_sqlite_ok(result, self.internal_connection.as_ptr())?;
+        // The previous authorizer cannot be invoked anymore, so it can
+        // be dropped now
+        self.authorizer = Some(std::panic::AssertUnwindSafe(boxed));
+        Ok(())
+    }
+
+    pub fn clear_authorizer(&mut self) -> QueryResult<()> {
+        let result = unsafe {
+            ffi::sqlite3_set_authorizer(self.internal_connection.as_ptr(), None, ptr::null_mut())
+        };
+        ensure_sqlite_ok(result, self.internal_connection.as_ptr())?;
+        self.authorizer = None;
+        Ok(())
+    }
+
     pub fn register_sql_function<F>(
         &self,
         fn_name: &str,
@@ -284,6 +377,7 @@ extern "C" fn run_custom_function<F>(
         // Drop impl of `RawConnection` as this would close the connection
         Some(conn) => mem::ManuallyDrop::new(RawConnection {
             internal_connection: conn,
+            authorizer: None,
         }),
         None => {
             unsafe { context_error_str(ctx, NULL_CONN_ERR) };
@@ -568,6 +662,48 @@ where
     }
 }
 
+extern "C" fn run_authorizer(
+    user_ptr: *mut libc::c_void,
+    code: libc::c_int,
+    arg1: *const libc::c_char,
+    arg2: *const libc::c_char,
+    _db_name: *const libc::c_char,
+    _trigger_name: *const libc::c_char,
+) -> libc::c_int {
+    let callback = unsafe { (user_ptr as *const BoxedAuthorizer).as_ref() };
+
+    let decision = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let callback = callback?;
+        let arg1 = unsafe { cstr_argument(arg1) };
+        let arg2 = unsafe { cstr_argument(arg2) };
+        Some(callback(SqliteAuthorizeAction::from_raw(
+            code as i32,
+            arg1,
+            arg2,
+        )))
+    }));
+
+    match decision {
+        Ok(Some(SqliteAuthorizerDecision::Allow)) => ffi::SQLITE_OK,
+        Ok(Some(SqliteAuthorizerDecision::Deny)) | Ok(None) => ffi::SQLITE_DENY,
+        Ok(Some(SqliteAuthorizerDecision::Ignore)) => ffi::SQLITE_IGNORE,
+        Err(_) => {
+            // Denying is the safe default; the panic resurfaces as an
+            // authorization error on the triggering statement
+            eprintln!("Authorizer callback panicked, denying the action");
+            ffi::SQLITE_DENY
+        }
+    }
+}
+
+unsafe fn cstr_argument<'a>(ptr: *const libc::c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        CStr::from_ptr(ptr).to_str().ok()
+    }
+}
+
 extern "C" fn destroy_boxed<F>(data: *mut libc::c_void) {
     let ptr = data as *mut F;
     unsafe { Box::from_raw(ptr) };
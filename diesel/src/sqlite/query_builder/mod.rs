@@ -1,7 +1,7 @@
 //! The SQLite query builder
 
 use super::backend::Sqlite;
-use crate::query_builder::QueryBuilder;
+use crate::query_builder::{QueryBuilder, QUERY_BUILDER_INITIAL_CAPACITY};
 use crate::result::QueryResult;
 
 mod limit_offset;
@@ -15,8 +15,13 @@ pub struct SqliteQueryBuilder {
 
 impl SqliteQueryBuilder {
     /// Construct a new query builder with an empty query
+    ///
+    /// The internal buffer is pre-sized to avoid repeated reallocation
+    /// while the query is built up.
     pub fn new() -> Self {
-        SqliteQueryBuilder::default()
+        SqliteQueryBuilder {
+            sql: String::with_capacity(QUERY_BUILDER_INITIAL_CAPACITY),
+        }
     }
 }
 
@@ -1,12 +1,14 @@
 extern crate chrono;
 
-use self::chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use self::chrono::{
+    DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc,
+};
 use std::io::Write;
 
 use crate::backend;
 use crate::deserialize::{self, FromSql};
 use crate::serialize::{self, Output, ToSql};
-use crate::sql_types::{Date, Text, Time, Timestamp};
+use crate::sql_types::{Date, Text, Time, Timestamp, Timestamptz};
 use crate::sqlite::Sqlite;
 
 const SQLITE_DATE_FORMAT: &str = "%F";
@@ -53,52 +55,117 @@ impl ToSql<Time, Sqlite> for NaiveTime {
     }
 }
 
+fn parse_naive_date_time(text: &str) -> deserialize::Result<NaiveDateTime> {
+    let sqlite_datetime_formats = &[
+        // Most likely format
+        "%F %T%.f",
+        // Other formats in order of appearance in docs
+        "%F %R",
+        "%F %RZ",
+        "%F %R%:z",
+        "%F %T%.fZ",
+        "%F %T%.f%:z",
+        "%FT%R",
+        "%FT%RZ",
+        "%FT%R%:z",
+        "%FT%T%.f",
+        "%FT%T%.fZ",
+        "%FT%T%.f%:z",
+    ];
+
+    for format in sqlite_datetime_formats {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(text, format) {
+            return Ok(dt);
+        }
+    }
+
+    if let Ok(julian_days) = text.parse::<f64>() {
+        let epoch_in_julian_days = 2_440_587.5;
+        let seconds_in_day = 86400.0;
+        let timestamp = (julian_days - epoch_in_julian_days) * seconds_in_day;
+        let seconds = timestamp as i64;
+        let nanos = (timestamp.fract() * 1E9) as u32;
+        if let Some(timestamp) = NaiveDateTime::from_timestamp_opt(seconds, nanos) {
+            return Ok(timestamp);
+        }
+    }
+
+    Err(format!("Invalid datetime {}", text).into())
+}
+
 impl FromSql<Timestamp, Sqlite> for NaiveDateTime {
     fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
         let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
         let text = unsafe { &*text_ptr };
+        parse_naive_date_time(text)
+    }
+}
+
+impl ToSql<Timestamp, Sqlite> for NaiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = self.format("%F %T%.f").to_string();
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
 
-        let sqlite_datetime_formats = &[
+impl FromSql<Timestamptz, Sqlite> for NaiveDateTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let datetime = <DateTime<FixedOffset> as FromSql<Timestamptz, Sqlite>>::from_sql(value)?;
+        Ok(datetime.naive_utc())
+    }
+}
+
+impl ToSql<Timestamptz, Sqlite> for NaiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = self.format("%F %T%.f+00:00").to_string();
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Timestamptz, Sqlite> for DateTime<FixedOffset> {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+
+        let sqlite_datetime_offset_formats = &[
             // Most likely format
-            "%F %T%.f",
+            "%F %T%.f%:z",
             // Other formats in order of appearance in docs
-            "%F %R",
-            "%F %RZ",
             "%F %R%:z",
-            "%F %T%.fZ",
-            "%F %T%.f%:z",
-            "%FT%R",
-            "%FT%RZ",
             "%FT%R%:z",
-            "%FT%T%.f",
-            "%FT%T%.fZ",
             "%FT%T%.f%:z",
         ];
 
-        for format in sqlite_datetime_formats {
+        for format in sqlite_datetime_offset_formats {
             if let Ok(dt) = Self::parse_from_str(text, format) {
                 return Ok(dt);
             }
         }
 
-        if let Ok(julian_days) = text.parse::<f64>() {
-            let epoch_in_julian_days = 2_440_587.5;
-            let seconds_in_day = 86400.0;
-            let timestamp = (julian_days - epoch_in_julian_days) * seconds_in_day;
-            let seconds = timestamp as i64;
-            let nanos = (timestamp.fract() * 1E9) as u32;
-            if let Some(timestamp) = Self::from_timestamp_opt(seconds, nanos) {
-                return Ok(timestamp);
-            }
-        }
+        // Fall back to the formats accepted for `Timestamp`, interpreting
+        // the value as UTC
+        let naive = parse_naive_date_time(text)?;
+        Ok(FixedOffset::east(0).from_utc_datetime(&naive))
+    }
+}
 
-        Err(format!("Invalid datetime {}", text).into())
+impl FromSql<Timestamptz, Sqlite> for DateTime<Utc> {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let datetime = <DateTime<FixedOffset> as FromSql<Timestamptz, Sqlite>>::from_sql(value)?;
+        Ok(datetime.with_timezone(&Utc))
     }
 }
 
-impl ToSql<Timestamp, Sqlite> for NaiveDateTime {
+impl FromSql<Timestamptz, Sqlite> for DateTime<Local> {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let datetime = <DateTime<FixedOffset> as FromSql<Timestamptz, Sqlite>>::from_sql(value)?;
+        Ok(datetime.with_timezone(&Local))
+    }
+}
+
+impl<TZ: TimeZone> ToSql<Timestamptz, Sqlite> for DateTime<TZ> {
     fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
-        let s = self.format("%F %T%.f").to_string();
+        let s = self.with_timezone(&Utc).format("%F %T%.f%:z").to_string();
         ToSql::<Text, Sqlite>::to_sql(&s, out)
     }
 }
@@ -0,0 +1,172 @@
+extern crate jiff;
+
+use self::jiff::civil::{Date as CivilDate, DateTime as CivilDateTime, Time as CivilTime};
+use self::jiff::fmt::strtime;
+use self::jiff::tz::Offset;
+use self::jiff::Timestamp as JiffTimestamp;
+use std::io::Write;
+
+use crate::backend;
+use crate::deserialize::{self, FromSql};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::{Date, Text, Time, Timestamp, Timestamptz};
+use crate::sqlite::Sqlite;
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+const ENCODE_TIME_FORMAT: &str = "%H:%M:%S%.f";
+
+const TIME_FORMATS: [&str; 9] = [
+    // Most likely formats
+    "%H:%M:%S%.f",
+    "%H:%M:%S",
+    // All other valid formats in order of documentation
+    "%H:%M",
+    "%H:%MZ",
+    "%H:%M%:z",
+    "%H:%M:%SZ",
+    "%H:%M:%S%:z",
+    "%H:%M:%S%.fZ",
+    "%H:%M:%S%.f%:z",
+];
+
+const ENCODE_DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+const ENCODE_DATETIME_TZ_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f+00:00";
+
+const DATETIME_FORMATS: [&str; 12] = [
+    // Most likely formats
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S",
+    // All other valid formats in order of documentation
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d %H:%MZ",
+    "%Y-%m-%d %H:%M%:z",
+    "%Y-%m-%d %H:%M:%SZ",
+    "%Y-%m-%d %H:%M:%S%:z",
+    "%Y-%m-%d %H:%M:%S%.fZ",
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S%.f",
+];
+
+const TIMESTAMP_FORMATS: [&str; 4] = [
+    // Most likely format
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    // All other valid formats in order of documentation
+    "%Y-%m-%d %H:%M%:z",
+    "%Y-%m-%d %H:%M:%S%:z",
+    "%Y-%m-%dT%H:%M:%S%.f%:z",
+];
+
+fn parse_civil_date_time(text: &str) -> deserialize::Result<CivilDateTime> {
+    for format in &DATETIME_FORMATS {
+        if let Ok(dt) = CivilDateTime::strptime(format, text) {
+            return Ok(dt);
+        }
+    }
+
+    if let Ok(julian_days) = text.parse::<f64>() {
+        let epoch_in_julian_days = 2_440_587.5;
+        let seconds_in_day = 86400.0;
+        let timestamp = (julian_days - epoch_in_julian_days) * seconds_in_day;
+        if let Ok(timestamp) = JiffTimestamp::from_microsecond((timestamp * 1E6) as i64) {
+            return Ok(Offset::UTC.to_datetime(timestamp));
+        }
+    }
+
+    Err(format!("Invalid datetime {}", text).into())
+}
+
+impl FromSql<Date, Sqlite> for CivilDate {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+        Self::strptime(DATE_FORMAT, text).map_err(Into::into)
+    }
+}
+
+impl ToSql<Date, Sqlite> for CivilDate {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = strtime::format(DATE_FORMAT, *self)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Time, Sqlite> for CivilTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+
+        for format in &TIME_FORMATS {
+            if let Ok(time) = Self::strptime(format, text) {
+                return Ok(time);
+            }
+        }
+
+        Err(format!("Invalid time {}", text).into())
+    }
+}
+
+impl ToSql<Time, Sqlite> for CivilTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = strtime::format(ENCODE_TIME_FORMAT, *self)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Timestamp, Sqlite> for CivilDateTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+        parse_civil_date_time(text)
+    }
+}
+
+impl ToSql<Timestamp, Sqlite> for CivilDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = strtime::format(ENCODE_DATETIME_FORMAT, *self)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Timestamptz, Sqlite> for CivilDateTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let timestamp = <JiffTimestamp as FromSql<Timestamptz, Sqlite>>::from_sql(value)?;
+        Ok(Offset::UTC.to_datetime(timestamp))
+    }
+}
+
+impl ToSql<Timestamptz, Sqlite> for CivilDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = strtime::format(ENCODE_DATETIME_TZ_FORMAT, *self)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Timestamptz, Sqlite> for JiffTimestamp {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+
+        for format in &TIMESTAMP_FORMATS {
+            if let Ok(timestamp) = Self::strptime(format, text) {
+                return Ok(timestamp);
+            }
+        }
+
+        // Fall back to the formats accepted for `Timestamp`, interpreting
+        // the value as UTC
+        let naive = parse_civil_date_time(text)?;
+        Offset::UTC.to_timestamp(naive).map_err(Into::into)
+    }
+}
+
+impl ToSql<Timestamptz, Sqlite> for JiffTimestamp {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let datetime = Offset::UTC.to_datetime(*self);
+        let s = strtime::format(ENCODE_DATETIME_TZ_FORMAT, datetime)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
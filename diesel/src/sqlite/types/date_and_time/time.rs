@@ -0,0 +1,196 @@
+extern crate time;
+
+use self::time::format_description::FormatItem;
+use self::time::macros::format_description;
+use self::time::{
+    Date as NaiveDate, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime, UtcOffset,
+};
+use std::io::Write;
+
+use crate::backend;
+use crate::deserialize::{self, FromSql};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::{Date, Text, Time, Timestamp, Timestamptz};
+use crate::sqlite::Sqlite;
+
+const DATE_FORMAT: &[FormatItem<'_>] = format_description!("[year]-[month]-[day]");
+
+const ENCODE_TIME_FORMAT: &[FormatItem<'_>] =
+    format_description!("[hour]:[minute]:[second].[subsecond]");
+
+const TIME_FORMATS: [&[FormatItem<'_>]; 9] = [
+    // Most likely formats
+    format_description!("[hour]:[minute]:[second].[subsecond]"),
+    format_description!("[hour]:[minute]:[second]"),
+    // All other valid formats in order of documentation
+    format_description!("[hour]:[minute]"),
+    format_description!("[hour]:[minute]Z"),
+    format_description!("[hour]:[minute][offset_hour sign:mandatory]:[offset_minute]"),
+    format_description!("[hour]:[minute]:[second]Z"),
+    format_description!("[hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"),
+    format_description!("[hour]:[minute]:[second].[subsecond]Z"),
+    format_description!(
+        "[hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory]:[offset_minute]"
+    ),
+];
+
+const ENCODE_PRIMITIVE_DATETIME_FORMAT: &[FormatItem<'_>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]");
+
+const ENCODE_NAIVE_DATETIME_TZ_FORMAT: &[FormatItem<'_>] =
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]+00:00");
+
+const ENCODE_OFFSET_DATETIME_FORMAT: &[FormatItem<'_>] = format_description!(
+    "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory]:[offset_minute]"
+);
+
+const PRIMITIVE_DATETIME_FORMATS: [&[FormatItem<'_>]; 12] = [
+    // Most likely formats
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]"),
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second]"),
+    // All other valid formats in order of documentation
+    format_description!("[year]-[month]-[day] [hour]:[minute]"),
+    format_description!("[year]-[month]-[day] [hour]:[minute]Z"),
+    format_description!(
+        "[year]-[month]-[day] [hour]:[minute][offset_hour sign:mandatory]:[offset_minute]"
+    ),
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second]Z"),
+    format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+    ),
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond]Z"),
+    format_description!("[year]-[month]-[day] [hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory]:[offset_minute]"),
+    format_description!("[year]-[month]-[day]T[hour]:[minute]"),
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"),
+    format_description!("[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond]"),
+];
+
+const OFFSET_DATETIME_FORMATS: [&[FormatItem<'_>]; 4] = [
+    // Most likely format
+    format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory]:[offset_minute]"
+    ),
+    // All other valid formats in order of documentation
+    format_description!(
+        "[year]-[month]-[day] [hour]:[minute][offset_hour sign:mandatory]:[offset_minute]"
+    ),
+    format_description!(
+        "[year]-[month]-[day] [hour]:[minute]:[second][offset_hour sign:mandatory]:[offset_minute]"
+    ),
+    format_description!(
+        "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour sign:mandatory]:[offset_minute]"
+    ),
+];
+
+fn parse_primitive_date_time(text: &str) -> deserialize::Result<PrimitiveDateTime> {
+    for format in &PRIMITIVE_DATETIME_FORMATS {
+        if let Ok(dt) = PrimitiveDateTime::parse(text, format) {
+            return Ok(dt);
+        }
+    }
+
+    if let Ok(julian_days) = text.parse::<f64>() {
+        let epoch_in_julian_days = 2_440_587.5;
+        let seconds_in_day = 86400.0;
+        let timestamp = (julian_days - epoch_in_julian_days) * seconds_in_day;
+        if let Ok(datetime) = OffsetDateTime::from_unix_timestamp_nanos((timestamp * 1E9) as i128) {
+            return Ok(PrimitiveDateTime::new(datetime.date(), datetime.time()));
+        }
+    }
+
+    Err(format!("Invalid datetime {}", text).into())
+}
+
+impl FromSql<Date, Sqlite> for NaiveDate {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+        Self::parse(text, DATE_FORMAT).map_err(Into::into)
+    }
+}
+
+impl ToSql<Date, Sqlite> for NaiveDate {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = self.format(DATE_FORMAT)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Time, Sqlite> for NaiveTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+
+        for format in &TIME_FORMATS {
+            if let Ok(time) = Self::parse(text, format) {
+                return Ok(time);
+            }
+        }
+
+        Err(format!("Invalid time {}", text).into())
+    }
+}
+
+impl ToSql<Time, Sqlite> for NaiveTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = self.format(ENCODE_TIME_FORMAT)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Timestamp, Sqlite> for PrimitiveDateTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+        parse_primitive_date_time(text)
+    }
+}
+
+impl ToSql<Timestamp, Sqlite> for PrimitiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = self.format(ENCODE_PRIMITIVE_DATETIME_FORMAT)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Timestamptz, Sqlite> for PrimitiveDateTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let datetime = <OffsetDateTime as FromSql<Timestamptz, Sqlite>>::from_sql(value)?;
+        let utc = datetime.to_offset(UtcOffset::UTC);
+        Ok(PrimitiveDateTime::new(utc.date(), utc.time()))
+    }
+}
+
+impl ToSql<Timestamptz, Sqlite> for PrimitiveDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = self.format(ENCODE_NAIVE_DATETIME_TZ_FORMAT)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
+
+impl FromSql<Timestamptz, Sqlite> for OffsetDateTime {
+    fn from_sql(value: backend::RawValue<Sqlite>) -> deserialize::Result<Self> {
+        let text_ptr = <*const str as FromSql<Date, Sqlite>>::from_sql(value)?;
+        let text = unsafe { &*text_ptr };
+
+        for format in &OFFSET_DATETIME_FORMATS {
+            if let Ok(dt) = Self::parse(text, format) {
+                return Ok(dt);
+            }
+        }
+
+        // Fall back to the formats accepted for `Timestamp`, interpreting
+        // the value as UTC
+        let naive = parse_primitive_date_time(text)?;
+        Ok(naive.assume_utc())
+    }
+}
+
+impl ToSql<Timestamptz, Sqlite> for OffsetDateTime {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        let s = self
+            .to_offset(UtcOffset::UTC)
+            .format(ENCODE_OFFSET_DATETIME_FORMAT)?;
+        ToSql::<Text, Sqlite>::to_sql(&s, out)
+    }
+}
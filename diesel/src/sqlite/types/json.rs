@@ -0,0 +1,63 @@
+use crate::deserialize::{self, FromSql};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types;
+use crate::sqlite::connection::SqliteValue;
+use crate::sqlite::Sqlite;
+use std::io::prelude::*;
+
+impl FromSql<sql_types::Json, Sqlite> for serde_json::Value {
+    fn from_sql(value: SqliteValue<'_>) -> deserialize::Result<Self> {
+        serde_json::from_str(value.read_text()).map_err(|_| "Invalid Json".into())
+    }
+}
+
+impl ToSql<sql_types::Json, Sqlite> for serde_json::Value {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Sqlite>) -> serialize::Result {
+        serde_json::to_writer(out, self)
+            .map(|_| IsNull::No)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dsl::sql;
+    use crate::prelude::*;
+    use crate::select;
+    use crate::serialize::{Output, ToSql};
+    use crate::sql_types::Json;
+    use crate::test_helpers::connection;
+
+    #[test]
+    fn json_to_sql() {
+        let mut bytes = Output::test();
+        let test_json = serde_json::Value::Bool(true);
+        ToSql::<Json, crate::sqlite::Sqlite>::to_sql(&test_json, &mut bytes).unwrap();
+        assert_eq!(bytes, b"true");
+    }
+
+    #[test]
+    fn json_from_sql() {
+        let connection = &mut connection();
+        let query = select(sql::<Json>("'true'"));
+        let result = query.get_result::<serde_json::Value>(connection);
+        assert_eq!(Ok(serde_json::Value::Bool(true)), result);
+    }
+
+    #[test]
+    fn bad_json_from_sql() {
+        let connection = &mut connection();
+        let query = select(sql::<Json>("'boom'"));
+        let result = query.get_result::<serde_json::Value>(connection);
+        assert_eq!(result.unwrap_err().to_string(), "Invalid Json");
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let connection = &mut connection();
+        let test_json = serde_json::json!({"a": [1, 2, 3], "b": "hello"});
+        let query = select(sql::<Json>("").bind::<Json, _>(&test_json));
+        let result = query.get_result::<serde_json::Value>(connection);
+        assert_eq!(Ok(test_json), result);
+    }
+}
@@ -1,4 +1,6 @@
 mod date_and_time;
+#[cfg(feature = "serde_json")]
+mod json;
 mod numeric;
 
 use std::io::prelude::*;
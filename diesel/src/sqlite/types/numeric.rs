@@ -1,15 +1,35 @@
-#![cfg(feature = "bigdecimal")]
+#[cfg(feature = "bigdecimal")]
+mod bigdecimal {
+    use bigdecimal::{BigDecimal, FromPrimitive};
 
-use bigdecimal::{BigDecimal, FromPrimitive};
+    use crate::deserialize::{self, FromSql};
+    use crate::sql_types::{Double, Numeric};
+    use crate::sqlite::connection::SqliteValue;
+    use crate::sqlite::Sqlite;
 
-use crate::deserialize::{self, FromSql};
-use crate::sql_types::{Double, Numeric};
-use crate::sqlite::connection::SqliteValue;
-use crate::sqlite::Sqlite;
+    impl FromSql<Numeric, Sqlite> for BigDecimal {
+        fn from_sql(bytes: SqliteValue<'_>) -> deserialize::Result<Self> {
+            let x = <f64 as FromSql<Double, Sqlite>>::from_sql(bytes)?;
+            BigDecimal::from_f64(x)
+                .ok_or_else(|| format!("{} is not valid decimal number ", x).into())
+        }
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    use rust_decimal::prelude::FromPrimitive;
+    use rust_decimal::Decimal;
+
+    use crate::deserialize::{self, FromSql};
+    use crate::sql_types::{Double, Numeric};
+    use crate::sqlite::connection::SqliteValue;
+    use crate::sqlite::Sqlite;
 
-impl FromSql<Numeric, Sqlite> for BigDecimal {
-    fn from_sql(bytes: SqliteValue<'_>) -> deserialize::Result<Self> {
-        let x = <f64 as FromSql<Double, Sqlite>>::from_sql(bytes)?;
-        BigDecimal::from_f64(x).ok_or_else(|| format!("{} is not valid decimal number ", x).into())
+    impl FromSql<Numeric, Sqlite> for Decimal {
+        fn from_sql(bytes: SqliteValue<'_>) -> deserialize::Result<Self> {
+            let x = <f64 as FromSql<Double, Sqlite>>::from_sql(bytes)?;
+            Decimal::from_f64(x).ok_or_else(|| format!("{} is not valid decimal number ", x).into())
+        }
     }
 }
@@ -0,0 +1,103 @@
+//! Helpers for writing tests which run against a real database
+
+use crate::backend::Backend;
+use crate::query_builder::debug_query::{DebugBinds, KEYWORDS};
+use crate::query_builder::{QueryBuilder, QueryFragment};
+
+#[doc(inline)]
+pub use diesel_derives::test_proc as test;
+
+/// Asserts that two queries render to the same SQL and bind parameters
+///
+/// The rendered SQL is compared with runs of whitespace collapsed and
+/// keywords lowercased, so queries built through different code paths
+/// compare equal as long as they send the same statement to the
+/// database. Bind parameters are compared structurally via their `Debug`
+/// representation, in order.
+///
+/// # Panics
+///
+/// Panics if the queries differ, pointing at the first difference.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// #
+/// # use diesel::testing::assert_queries_equal;
+/// # use schema::users;
+/// #
+/// # fn main() {
+/// let via_find = users::table.find(1);
+/// let via_filter = users::table.filter(users::id.eq(1));
+/// assert_queries_equal::<DB, _, _>(&via_find, &via_filter);
+/// # }
+/// ```
+pub fn assert_queries_equal<DB, Left, Right>(left: &Left, right: &Right)
+where
+    DB: Backend,
+    DB::QueryBuilder: Default,
+    Left: QueryFragment<DB>,
+    Right: QueryFragment<DB>,
+{
+    let left_sql = normalized_sql(left);
+    let right_sql = normalized_sql(right);
+    if left_sql != right_sql {
+        let diff_at = left_sql
+            .chars()
+            .zip(right_sql.chars())
+            .take_while(|(l, r)| l == r)
+            .count();
+        panic!(
+            "queries are not equal, differing from position {}:\n  left: {}\n right: {}",
+            diff_at, left_sql, right_sql,
+        );
+    }
+
+    let left_binds = format!("{:?}", DebugBinds::<_, DB>::new(left));
+    let right_binds = format!("{:?}", DebugBinds::<_, DB>::new(right));
+    assert_eq!(
+        left_binds, right_binds,
+        "queries render the same SQL but their bind parameters differ",
+    );
+}
+
+fn normalized_sql<DB, T>(query: &T) -> String
+where
+    DB: Backend,
+    DB::QueryBuilder: Default,
+    T: QueryFragment<DB>,
+{
+    let mut query_builder = DB::QueryBuilder::default();
+    query
+        .to_sql(&mut query_builder)
+        .expect("failed to render the query as SQL");
+    normalize(&query_builder.finish())
+}
+
+pub(crate) fn normalize(sql: &str) -> String {
+    let mut result = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_whitespace() {
+            while chars.next_if(|c| c.is_whitespace()).is_some() {}
+            if !result.is_empty() && chars.peek().is_some() {
+                result.push(' ');
+            }
+        } else if c.is_ascii_alphabetic() {
+            let mut word = String::from(c);
+            while let Some(c) = chars.next_if(|c| c.is_ascii_alphanumeric() || *c == '_') {
+                word.push(c);
+            }
+            if KEYWORDS.contains(&word.to_uppercase().as_str()) {
+                result.push_str(&word.to_lowercase());
+            } else {
+                result.push_str(&word);
+            }
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
@@ -0,0 +1,97 @@
+//! Types and traits for updating `updated_at` timestamps
+//!
+//! Models commonly track the time of their last modification in an
+//! `updated_at` column. Deriving [`Touchable`](derive@crate::Touchable)
+//! for a model whose table has such a column enables
+//! [`touch`](TouchDsl::touch()), which sets the column to the current
+//! time using the database's clock.
+
+use crate::connection::Connection;
+use crate::dsl;
+use crate::expression_methods::ExpressionMethods;
+use crate::query_builder::{AsQuery, IntoUpdateTarget};
+use crate::query_dsl::methods::ExecuteDsl;
+use crate::query_dsl::RunQueryDsl;
+use crate::query_source::Column;
+use crate::result::QueryResult;
+use crate::sql_types::Timestamp;
+
+/// The changeset used by [`TouchDsl::touch()`]
+pub type SetUpdatedAt<C> = dsl::Eq<C, dsl::now>;
+
+/// A model type whose table has an `updated_at` timestamp column
+///
+/// This trait is normally implemented by
+/// [`#[derive(Touchable)]`](derive@crate::Touchable), which requires the
+/// struct to have an `updated_at` field.
+pub trait Touchable {
+    /// The `updated_at` column of the corresponding table
+    type UpdatedAt: Column<SqlType = Timestamp> + Default;
+}
+
+#[doc(inline)]
+pub use diesel_derives::Touchable;
+
+impl<'a, T: Touchable> Touchable for &'a T {
+    type UpdatedAt = T::UpdatedAt;
+}
+
+/// The `touch` method
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// # use diesel::touchable::TouchDsl;
+/// #
+/// table! {
+///     documents (id) {
+///         id -> Integer,
+///         updated_at -> Timestamp,
+///     }
+/// }
+///
+/// #[derive(Identifiable, Touchable)]
+/// #[table_name = "documents"]
+/// struct Document {
+///     id: i32,
+///     updated_at: String,
+/// }
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use self::documents::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// #     diesel::sql_query(
+/// #         "CREATE TABLE documents (id INTEGER PRIMARY KEY, \
+/// #          updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP)"
+/// #     ).execute(connection)?;
+/// #     diesel::insert_into(documents).values(id.eq(1)).execute(connection)?;
+/// let document = Document { id: 1, updated_at: String::new() };
+/// let updated_rows = document.touch(connection)?;
+/// assert_eq!(1, updated_rows);
+/// #     Ok(())
+/// # }
+/// ```
+pub trait TouchDsl<Conn>: Sized {
+    /// Sets this record's `updated_at` column to the current time,
+    /// using the database's clock
+    fn touch(self, conn: &mut Conn) -> QueryResult<usize>;
+}
+
+impl<T, Conn> TouchDsl<Conn> for T
+where
+    T: Touchable + IntoUpdateTarget,
+    T::UpdatedAt: Column<Table = T::Table>,
+    Conn: Connection,
+    dsl::Update<T, SetUpdatedAt<T::UpdatedAt>>: AsQuery + ExecuteDsl<Conn>,
+{
+    fn touch(self, conn: &mut Conn) -> QueryResult<usize> {
+        crate::update(self)
+            .set(T::UpdatedAt::default().eq(dsl::now))
+            .execute(conn)
+    }
+}
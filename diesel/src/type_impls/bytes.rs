@@ -0,0 +1,42 @@
+use std::io::Write;
+
+use crate::backend::{Backend, BinaryRawValue};
+use crate::deserialize::{self, FromSql};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::Binary;
+
+#[allow(dead_code)]
+mod foreign_impls {
+    use super::*;
+    use crate::deserialize::FromSqlRow;
+    use crate::expression::AsExpression;
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Binary"]
+    struct BytesProxy(bytes::Bytes);
+}
+
+/// Unlike the `Vec<u8>` impl, this copies the raw value into a reference
+/// counted buffer exactly once. Cloning the resulting `Bytes` or slicing it
+/// into smaller chunks does not copy the data again. A fully zero-copy
+/// deserialization is not possible, as the backing buffer is owned by the
+/// database driver and is reused for subsequent rows.
+impl<DB> FromSql<Binary, DB> for bytes::Bytes
+where
+    DB: Backend + for<'a> BinaryRawValue<'a>,
+{
+    fn from_sql(value: crate::backend::RawValue<DB>) -> deserialize::Result<Self> {
+        Ok(bytes::Bytes::copy_from_slice(DB::as_bytes(value)))
+    }
+}
+
+impl<DB> ToSql<Binary, DB> for bytes::Bytes
+where
+    DB: Backend,
+    [u8]: ToSql<Binary, DB>,
+{
+    fn to_sql<W: Write>(&self, out: &mut Output<W, DB>) -> serialize::Result {
+        self.as_ref().to_sql(out)
+    }
+}
@@ -30,12 +30,75 @@ mod chrono {
     #[derive(AsExpression, FromSqlRow)]
     #[diesel(foreign_derive)]
     #[sql_type = "Timestamp"]
-    #[cfg_attr(feature = "postgres", sql_type = "crate::sql_types::Timestamptz")]
+    #[sql_type = "crate::sql_types::Timestamptz"]
     #[cfg_attr(feature = "mysql", sql_type = "crate::sql_types::Datetime")]
     struct NaiveDateTimeProxy(NaiveDateTime);
 
     #[derive(AsExpression, FromSqlRow)]
     #[diesel(foreign_derive)]
-    #[cfg_attr(feature = "postgres", sql_type = "crate::sql_types::Timestamptz")]
+    #[sql_type = "crate::sql_types::Timestamptz"]
     struct DateTimeProxy<Tz: TimeZone>(DateTime<Tz>);
 }
+
+#[cfg(feature = "jiff")]
+mod jiff {
+    extern crate jiff;
+    use self::jiff::civil::{Date as CivilDate, DateTime as CivilDateTime, Time as CivilTime};
+    use self::jiff::Timestamp as JiffTimestamp;
+    use crate::deserialize::FromSqlRow;
+    use crate::expression::AsExpression;
+    use crate::sql_types::{Date, Time, Timestamp};
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Date"]
+    struct DateProxy(CivilDate);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Time"]
+    struct TimeProxy(CivilTime);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Timestamp"]
+    #[sql_type = "crate::sql_types::Timestamptz"]
+    #[cfg_attr(feature = "mysql", sql_type = "crate::sql_types::Datetime")]
+    struct DateTimeProxy(CivilDateTime);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "crate::sql_types::Timestamptz"]
+    struct TimestampProxy(JiffTimestamp);
+}
+
+#[cfg(feature = "time")]
+mod time {
+    extern crate time;
+    use self::time::{Date as NaiveDate, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime};
+    use crate::deserialize::FromSqlRow;
+    use crate::expression::AsExpression;
+    use crate::sql_types::{Date, Time, Timestamp};
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Date"]
+    struct DateProxy(NaiveDate);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Time"]
+    struct TimeProxy(NaiveTime);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Timestamp"]
+    #[sql_type = "crate::sql_types::Timestamptz"]
+    #[cfg_attr(feature = "mysql", sql_type = "crate::sql_types::Datetime")]
+    struct PrimitiveDateTimeProxy(PrimitiveDateTime);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "crate::sql_types::Timestamptz"]
+    struct OffsetDateTimeProxy(OffsetDateTime);
+}
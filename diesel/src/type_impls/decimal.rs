@@ -13,3 +13,16 @@ mod bigdecimal {
     #[sql_type = "Numeric"]
     struct BigDecimalProxy(BigDecimal);
 }
+
+#[cfg(feature = "rust_decimal")]
+mod rust_decimal {
+    use crate::deserialize::FromSqlRow;
+    use crate::expression::AsExpression;
+    use crate::sql_types::Numeric;
+    use rust_decimal::Decimal;
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[sql_type = "Numeric"]
+    struct DecimalProxy(Decimal);
+}
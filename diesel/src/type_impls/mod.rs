@@ -1,9 +1,13 @@
+#[cfg(feature = "bytes")]
+mod bytes;
 mod date_and_time;
 mod decimal;
 pub mod floats;
 mod integers;
-#[cfg(all(feature = "serde_json", any(feature = "postgres", feature = "mysql")))]
+#[cfg(feature = "serde_json")]
 mod json;
 pub mod option;
 mod primitives;
 pub(crate) mod tuples;
+#[cfg(all(feature = "uuid", any(feature = "postgres", feature = "mysql")))]
+mod uuid;
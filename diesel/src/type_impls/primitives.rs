@@ -200,6 +200,9 @@ where
     }
 }
 
+/// Together with the blanket `ToSql` impl for references this allows binding
+/// `&[u8]` (and `&Vec<u8>`) directly, so inserting binary data does not
+/// require allocating an owned `Vec<u8>` first.
 impl<DB: Backend> ToSql<sql_types::Binary, DB> for [u8] {
     fn to_sql<W: Write>(&self, out: &mut Output<W, DB>) -> serialize::Result {
         out.write_all(self)
@@ -3,14 +3,17 @@ use crate::backend::Backend;
 use crate::deserialize::{
     self, FromSqlRow, FromStaticSqlRow, Queryable, SqlTypeOrSelectable, StaticallySizedRow,
 };
+use crate::expression::grouping_sets::GroupingSetCollection;
 use crate::expression::{
     is_contained_in_group_by, AppearsOnTable, AsExpression, AsExpressionList, Expression,
     IsContainedInGroupBy, QueryMetadata, Selectable, SelectableExpression, TypedExpressionType,
     ValidGrouping,
 };
 use crate::insertable::{CanInsertInSingleQuery, InsertValues, Insertable};
+use crate::query_builder::values_table::AsValueRow;
 use crate::query_builder::*;
 use crate::query_dsl::load_dsl::CompatibleType;
+use crate::query_dsl::values_of_dsl::IntoAnyValues;
 use crate::query_source::*;
 use crate::result::QueryResult;
 use crate::row::*;
@@ -90,6 +93,35 @@ macro_rules! tuple_impls {
                 }
             }
 
+            impl<$($T,)+ $($ST,)+> AsValueRow<($($ST,)+)> for ($($T,)+)
+            where
+                $($T: AsExpression<$ST>,)+
+                $($ST: SqlType + TypedExpressionType,)+
+            {
+                type Row = ($($T::Expression,)+);
+
+                fn as_value_row(self) -> Self::Row {
+                    ($(self.$idx.as_expression(),)+)
+                }
+            }
+
+            impl<$($T: QueryFragment<__DB>),+, __DB: Backend> GroupingSetCollection<__DB> for ($($T,)+) {
+                #[allow(unused_assignments)]
+                fn walk_sets(&self, mut out: AstPass<__DB>) -> QueryResult<()> {
+                    let mut needs_comma = false;
+                    $(
+                        if needs_comma {
+                            out.push_sql(", ");
+                        }
+                        out.push_sql("(");
+                        self.$idx.walk_ast(out.reborrow())?;
+                        out.push_sql(")");
+                        needs_comma = true;
+                    )+
+                    Ok(())
+                }
+            }
+
             impl<$($T,)+ Tab> ColumnList for ($($T,)+)
             where
                 $($T: ColumnList<Table = Tab>,)+
@@ -183,6 +215,24 @@ macro_rules! tuple_impls {
                 }
             }
 
+            impl<$($T,)+> ColumnNames for ($($T,)+)
+            where
+                $($T: Column,)+
+            {
+                fn column_names() -> &'static [&'static str] {
+                    &[$(<$T as Column>::NAME,)+]
+                }
+            }
+
+            impl<$($T,)+> IntoAnyValues for ($($T,)+)
+            where
+                $($T: std::any::Any,)+
+            {
+                fn into_any_values(self) -> Vec<Box<dyn std::any::Any>> {
+                    vec![$(Box::new(self.$idx) as Box<dyn std::any::Any>,)+]
+                }
+            }
+
             impl<$($T,)+ QS> SelectableExpression<QS> for ($($T,)+) where
                 $($T: SelectableExpression<QS>,)+
                 ($($T,)+): AppearsOnTable<QS>,
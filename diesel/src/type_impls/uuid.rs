@@ -0,0 +1,10 @@
+#![allow(dead_code)]
+
+use crate::deserialize::FromSqlRow;
+use crate::expression::AsExpression;
+use crate::sql_types::Uuid;
+
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[sql_type = "Uuid"]
+struct UuidProxy(uuid::Uuid);
@@ -8,6 +8,13 @@
 //! See [the methods on `InsertStatement`](crate::query_builder::InsertStatement#impl-2)
 //! for usage examples.
 //!
+//! Upserts also work for batch inserts: passing a slice or vector to
+//! [`.values()`](crate::query_builder::IncompleteInsertStatement::values())
+//! before `.on_conflict(...)` emits a single `INSERT ... ON CONFLICT`
+//! statement with a multi-row `VALUES` clause, which is much faster than
+//! upserting row by row. Combine this with [`excluded`] to take the
+//! conflicting values from the proposed rows.
+//!
 //! Constructing an upsert statement from an existing select statement
 //! requires a where clause on sqlite due to a ambiguity in their
 //! parser. See [the corresponding documentation](https://www.sqlite.org/lang_UPSERT.html)
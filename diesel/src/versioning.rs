@@ -0,0 +1,147 @@
+//! Types and traits implementing optimistic locking
+//!
+//! Optimistic locking detects concurrent modifications without holding a
+//! database lock. Models track an integer `version` column which is
+//! incremented on every update, and updates are guarded by the version the
+//! record had when it was loaded. Deriving
+//! [`VersionedModel`](derive@crate::VersionedModel) for a model whose table
+//! has such a column enables [`save`](VersionedSaveDsl::save()), which
+//! fails with [`Error::StaleObjectError`] if the record was modified or
+//! deleted concurrently.
+//!
+//! [`Error::StaleObjectError`]: crate::result::Error::StaleObjectError
+
+use crate::associations::HasTable;
+use crate::connection::Connection;
+use crate::expression::bound::Bound;
+use crate::expression::grouped::Grouped;
+use crate::expression::operators::Eq;
+use crate::expression::AppearsOnTable;
+use crate::expression_methods::ExpressionMethods;
+use crate::query_builder::update_statement::changeset::Assign;
+use crate::query_builder::where_clause::WhereAnd;
+use crate::query_builder::{AsQuery, IntoUpdateTarget, UpdateStatement};
+use crate::query_dsl::methods::ExecuteDsl;
+use crate::query_dsl::RunQueryDsl;
+use crate::query_source::Column;
+use crate::result::{Error, QueryResult};
+use crate::sql_types::Integer;
+
+/// The changeset used by [`VersionedSaveDsl::save()`]
+pub type IncrementVersion<C> = Assign<C, Bound<Integer, i32>>;
+
+/// The where clause guarding the update in [`VersionedSaveDsl::save()`]
+pub type VersionGuard<C> = Grouped<Eq<C, Bound<Integer, i32>>>;
+
+/// The update statement executed by [`VersionedSaveDsl::save()`]
+pub type VersionedUpdate<T, C> = UpdateStatement<
+    <T as HasTable>::Table,
+    <<T as IntoUpdateTarget>::WhereClause as WhereAnd<VersionGuard<C>>>::Output,
+    IncrementVersion<C>,
+>;
+
+/// A model type whose table has an integer `version` column used for
+/// optimistic locking
+///
+/// This trait is normally implemented by
+/// [`#[derive(VersionedModel)]`](derive@crate::VersionedModel), which
+/// requires the struct to have a `version: i32` field.
+pub trait VersionedModel {
+    /// The `version` column of the corresponding table
+    type Version: Column<SqlType = Integer> + Default;
+
+    /// The version this record had when it was loaded
+    fn version(&self) -> i32;
+}
+
+#[doc(inline)]
+pub use diesel_derives::VersionedModel;
+
+impl<'a, T: VersionedModel> VersionedModel for &'a T {
+    type Version = T::Version;
+
+    fn version(&self) -> i32 {
+        (**self).version()
+    }
+}
+
+/// The `save` method
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// # use diesel::result::Error;
+/// # use diesel::versioning::VersionedSaveDsl;
+/// #
+/// table! {
+///     pages (id) {
+///         id -> Integer,
+///         version -> Integer,
+///     }
+/// }
+///
+/// #[derive(Identifiable, VersionedModel)]
+/// #[table_name = "pages"]
+/// struct Page {
+///     id: i32,
+///     version: i32,
+/// }
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use self::pages::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// #     diesel::sql_query(
+/// #         "CREATE TABLE pages (id INTEGER PRIMARY KEY, version INTEGER NOT NULL)"
+/// #     ).execute(connection)?;
+/// #     diesel::insert_into(pages)
+/// #         .values((id.eq(1), version.eq(1)))
+/// #         .execute(connection)?;
+/// let page = Page { id: 1, version: 1 };
+/// let stale_copy = Page { id: 1, version: 1 };
+///
+/// page.save(connection)?;
+/// assert_eq!(Err(Error::StaleObjectError), stale_copy.save(connection));
+/// #     Ok(())
+/// # }
+/// ```
+pub trait VersionedSaveDsl<Conn>: Sized {
+    /// Increments this record's `version` column, guarded by the version
+    /// the record had when it was loaded
+    ///
+    /// Returns [`Error::StaleObjectError`] if no row was updated, meaning
+    /// the record was modified or deleted concurrently. The version wraps
+    /// around at `i32::MAX`; only inequality between two versions of the
+    /// record matters for the lock.
+    ///
+    /// [`Error::StaleObjectError`]: crate::result::Error::StaleObjectError
+    fn save(self, conn: &mut Conn) -> QueryResult<usize>;
+}
+
+impl<T, Conn> VersionedSaveDsl<Conn> for T
+where
+    T: VersionedModel + IntoUpdateTarget,
+    T::Version: Column<Table = T::Table>,
+    Conn: Connection,
+    T::WhereClause: WhereAnd<VersionGuard<T::Version>>,
+    VersionGuard<T::Version>: AppearsOnTable<T::Table>,
+    UpdateStatement<T::Table, T::WhereClause, IncrementVersion<T::Version>>: AsQuery,
+    VersionedUpdate<T, T::Version>: AsQuery + ExecuteDsl<Conn>,
+{
+    fn save(self, conn: &mut Conn) -> QueryResult<usize> {
+        let version = self.version();
+        let rows = crate::update(self)
+            .set(T::Version::default().eq(version.wrapping_add(1)))
+            .filter(T::Version::default().eq(version))
+            .execute(conn)?;
+        if rows == 0 {
+            Err(Error::StaleObjectError)
+        } else {
+            Ok(rows)
+        }
+    }
+}
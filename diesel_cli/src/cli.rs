@@ -139,10 +139,22 @@ pub fn build_cli() -> App<'static, 'static> {
             "Creates the database specified in your DATABASE_URL, \
              and then runs any existing migrations.",
         ))
-        .subcommand(SubCommand::with_name("reset").about(
-            "Resets your database by dropping the database specified \
-             in your DATABASE_URL and then running `diesel database setup`.",
-        ))
+        .subcommand(
+            SubCommand::with_name("reset")
+                .about(
+                    "Resets your database by dropping the database specified \
+                     in your DATABASE_URL and then running `diesel database setup`.",
+                )
+                .arg(
+                    Arg::with_name("SEED_FILE")
+                        .long("seed-file")
+                        .takes_value(true)
+                        .help(
+                            "A SQL file to run against the database after \
+                             all migrations have completed.",
+                        ),
+                ),
+        )
         .subcommand(
             SubCommand::with_name("drop")
                 .about("Drops the database specified in your DATABASE_URL.")
@@ -155,6 +167,10 @@ pub fn build_cli() -> App<'static, 'static> {
 
     let generate_completions_subcommand = SubCommand::with_name("completions")
         .about("Generate shell completion scripts for the diesel command.")
+        .long_about(
+            "Generate shell completion scripts for the diesel command.\n\n\
+             Example: `diesel completions bash > /etc/bash_completion.d/diesel`",
+        )
         .arg(
             Arg::with_name("SHELL")
                 .index(1)
@@ -223,6 +239,56 @@ pub fn build_cli() -> App<'static, 'static> {
             Arg::with_name("generate-custom-type-definitions")
                 .long("no-generate-missing-sql-type-definitions")
                 .help("Generate SQL type definitions for types not provided by diesel"),
+        )
+        .arg(
+            Arg::with_name("include-views")
+                .long("include-views")
+                .help("Also generate table definitions for database views."),
+        )
+        .arg(
+            Arg::with_name("include-materialized-views")
+                .long("include-materialized-views")
+                .help(
+                    "Also generate table definitions for materialized views \
+                     (PostgreSQL only).",
+                ),
+        )
+        .arg(
+            Arg::with_name("allow-tables-regex")
+                .long("allow-tables-regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .conflicts_with_all(&["deny-tables-regex", "only-tables", "except-tables"])
+                .help("Only include tables whose name matches the given regex."),
+        )
+        .arg(
+            Arg::with_name("deny-tables-regex")
+                .long("deny-tables-regex")
+                .takes_value(true)
+                .multiple(true)
+                .number_of_values(1)
+                .conflicts_with_all(&["only-tables", "except-tables"])
+                .help("Exclude tables whose name matches the given regex."),
+        )
+        .arg(
+            Arg::with_name("sqlite-integer-primary-key-is-rowid")
+                .long("sqlite-integer-primary-key-is-rowid")
+                .help(
+                    "Treat SQLite `INTEGER PRIMARY KEY` columns as aliases \
+                     for the 64 bit `rowid`, generating them as non-nullable \
+                     `BigInt` (SQLite only).",
+                ),
+        )
+        .arg(
+            Arg::with_name("diff")
+                .long("diff")
+                .takes_value(true)
+                .value_name("SCHEMA_FILE")
+                .help(
+                    "Compare the generated schema against the given schema file \
+                     and print a report of the changes instead of the schema itself.",
+                ),
         );
 
     let config_arg = Arg::with_name("CONFIG_FILE")
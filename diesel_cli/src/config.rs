@@ -62,6 +62,10 @@ pub struct PrintSchema {
     #[serde(default)]
     pub column_sorting: ColumnSorting,
     #[serde(default)]
+    pub include_views: bool,
+    #[serde(default)]
+    pub include_materialized_views: bool,
+    #[serde(default)]
     pub schema: Option<String>,
     #[serde(default)]
     pub patch_file: Option<PathBuf>,
@@ -69,6 +73,8 @@ pub struct PrintSchema {
     pub import_types: Option<Vec<String>>,
     #[serde(default)]
     pub generate_missing_sql_type_definitions: Option<bool>,
+    #[serde(default)]
+    pub sqlite_integer_primary_key_is_rowid: bool,
 }
 
 impl PrintSchema {
@@ -102,13 +108,35 @@ impl PrintSchema {
 #[derive(Default, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct MigrationsDirectory {
-    pub dir: PathBuf,
+    #[serde(deserialize_with = "one_path_or_many")]
+    pub dir: Vec<PathBuf>,
+}
+
+/// Allows `dir` to be given either as a single path or as an array of
+/// paths, which are processed in declaration order.
+fn one_path_or_many<'de, D>(deserializer: D) -> Result<Vec<PathBuf>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OnePathOrMany {
+        One(PathBuf),
+        Many(Vec<PathBuf>),
+    }
+
+    Ok(match OnePathOrMany::deserialize(deserializer)? {
+        OnePathOrMany::One(dir) => vec![dir],
+        OnePathOrMany::Many(dirs) => dirs,
+    })
 }
 
 impl MigrationsDirectory {
     fn set_relative_path_base(&mut self, base: &Path) {
-        if self.dir.is_relative() {
-            self.dir = base.join(&self.dir);
+        for dir in &mut self.dir {
+            if dir.is_relative() {
+                *dir = base.join(&*dir);
+            }
         }
     }
 }
@@ -10,11 +10,12 @@ use crate::database_error::{DatabaseError, DatabaseResult};
 
 use std::env;
 use std::error::Error;
+use std::fs;
 #[cfg(feature = "postgres")]
-use std::fs::{self, File};
+use std::fs::File;
 #[cfg(feature = "postgres")]
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 pub enum Backend {
     #[cfg(feature = "postgres")]
@@ -146,17 +147,39 @@ macro_rules! call_with_conn {
     };
 }
 
-pub fn reset_database(args: &ArgMatches, migrations_dir: &Path) -> DatabaseResult<()> {
+pub fn reset_database(args: &ArgMatches, migrations_dirs: &[PathBuf]) -> DatabaseResult<()> {
     drop_database(&database_url(args))?;
-    setup_database(args, migrations_dir)
+    setup_database(args, migrations_dirs)?;
+    if let Some(seed_file) = args.value_of("SEED_FILE") {
+        run_seed_file(args, Path::new(seed_file))?;
+    }
+    Ok(())
+}
+
+/// Runs the SQL statements from the given seed file against the
+/// freshly set up database.
+fn run_seed_file(args: &ArgMatches, seed_file: &Path) -> DatabaseResult<()> {
+    let database_url = database_url(args);
+    let sql = fs::read_to_string(seed_file)?;
+    println!("Running seed file {}", seed_file.display());
+    call_with_conn!(database_url, run_seed_sql(&sql))
 }
 
-pub fn setup_database(args: &ArgMatches, migrations_dir: &Path) -> DatabaseResult<()> {
+fn run_seed_sql<Conn: Connection>(conn: &mut Conn, sql: &str) -> DatabaseResult<()> {
+    conn.batch_execute(sql)?;
+    Ok(())
+}
+
+pub fn setup_database(args: &ArgMatches, migrations_dirs: &[PathBuf]) -> DatabaseResult<()> {
     let database_url = database_url(args);
 
     create_database_if_needed(&database_url)?;
-    create_default_migration_if_needed(&database_url, migrations_dir)?;
-    create_schema_table_and_run_migrations_if_needed(&database_url, migrations_dir)?;
+    if let Some(primary_dir) = migrations_dirs.first() {
+        create_default_migration_if_needed(&database_url, primary_dir)?;
+    }
+    for migrations_dir in migrations_dirs {
+        create_schema_table_and_run_migrations_if_needed(&database_url, migrations_dir)?;
+    }
     Ok(())
 }
 
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::error::Error;
 
 use diesel::result::Error::NotFound;
@@ -77,6 +78,71 @@ pub fn load_table_names(
     }
 }
 
+pub fn load_view_names(
+    database_url: &str,
+    schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error + Send + Sync + 'static>> {
+    let mut connection = InferConnection::establish(database_url)?;
+
+    match connection {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(ref mut c) => super::sqlite::load_view_names(c, schema_name),
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(ref mut c) => {
+            super::information_schema::load_view_names(c, schema_name)
+        }
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(ref mut c) => {
+            super::information_schema::load_view_names(c, schema_name)
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "postgres"), allow(unused_variables, unused_mut))]
+pub fn load_materialized_view_names(
+    database_url: &str,
+    schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error + Send + Sync + 'static>> {
+    let mut connection = InferConnection::establish(database_url)?;
+
+    match connection {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => {
+            Err("materialized views are only supported on PostgreSQL".into())
+        }
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(ref mut c) => super::pg::load_materialized_view_names(c, schema_name),
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(_) => {
+            Err("materialized views are only supported on PostgreSQL".into())
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "postgres"), allow(unused_variables))]
+pub fn load_enum_variants(
+    database_url: &str,
+    types: &[ColumnType],
+) -> Result<HashMap<String, Vec<String>>, Box<dyn Error + Send + Sync + 'static>> {
+    let mut variants = HashMap::new();
+
+    #[cfg(feature = "postgres")]
+    if let InferConnection::Pg(ref mut conn) = InferConnection::establish(database_url)? {
+        use crate::infer_schema_internals::information_schema::UsesInformationSchema;
+
+        let default_schema = diesel::pg::Pg::default_schema(conn)?;
+        for ty in types {
+            let schema = ty.schema.as_deref().unwrap_or(&default_schema);
+            let labels = super::pg::load_enum_variants(conn, schema, &ty.sql_name)?;
+            if !labels.is_empty() {
+                variants.insert(ty.rust_name.clone(), labels);
+            }
+        }
+    }
+
+    Ok(variants)
+}
+
 fn get_column_information(
     conn: &mut InferConnection,
     table: &TableName,
@@ -193,6 +259,7 @@ pub fn load_table_data(
     database_url: &str,
     name: TableName,
     column_sorting: &ColumnSorting,
+    sqlite_integer_primary_key_is_rowid: bool,
 ) -> Result<TableData, Box<dyn Error + Send + Sync + 'static>> {
     let mut connection = InferConnection::establish(database_url)?;
     let docs = doc_comment!(
@@ -205,25 +272,82 @@ pub fn load_table_data(
     let primary_key = primary_key
         .iter()
         .map(|k| rust_name_for_sql_name(&k))
-        .collect();
+        .collect::<Vec<_>>();
+
+    let is_sqlite = {
+        #[cfg(feature = "sqlite")]
+        {
+            matches!(connection, InferConnection::Sqlite(_))
+        }
+        #[cfg(not(feature = "sqlite"))]
+        {
+            false
+        }
+    };
+
+    // PostgreSQL identity columns behave like `SERIAL`: their value is
+    // generated automatically, and explicit inserts are rejected for
+    // `GENERATED ALWAYS` unless `OVERRIDING SYSTEM VALUE` is specified
+    let identity_columns = {
+        #[cfg(feature = "postgres")]
+        {
+            match connection {
+                InferConnection::Pg(ref mut c) => super::pg::get_identity_columns(c, &name)?,
+                #[allow(unreachable_patterns)]
+                _ => Vec::new(),
+            }
+        }
+        #[cfg(not(feature = "postgres"))]
+        {
+            Vec::<String>::new()
+        }
+    };
 
     let column_data = get_column_information(&mut connection, &name, column_sorting)?
         .into_iter()
         .map(|c| {
-            let ty = determine_column_type(&c, &mut connection)?;
+            let mut ty = determine_column_type(&c, &mut connection)?;
             let rust_name = rust_name_for_sql_name(&c.column_name);
 
-            Ok(ColumnDefinition {
-                docs: doc_comment!(
-                    "The `{}` column of the `{}` table.
+            // On SQLite a column declared as exactly `INTEGER PRIMARY KEY`
+            // is an alias for the 64 bit `rowid`, with implicit NOT NULL
+            // and auto-increment semantics
+            let is_rowid_alias = sqlite_integer_primary_key_is_rowid
+                && is_sqlite
+                && c.type_name.eq_ignore_ascii_case("integer")
+                && primary_key.len() == 1
+                && primary_key[0] == rust_name;
+            if is_rowid_alias {
+                ty.rust_name = String::from("BigInt");
+                ty.sql_name = String::from("BigInt");
+                ty.is_nullable = false;
+            }
+            let mut docs = doc_comment!(
+                "The `{}` column of the `{}` table.
 
-                    Its SQL type is `{}`.
+                Its SQL type is `{}`.
 
-                    (Automatically generated by Diesel.)",
-                    c.column_name,
-                    name.full_sql_name(),
-                    ty
-                ),
+                (Automatically generated by Diesel.)",
+                c.column_name,
+                name.full_sql_name(),
+                ty
+            );
+            if is_rowid_alias {
+                docs.push_str(
+                    "\n\nThis column is an alias for SQLite's `rowid` \
+                     and is assigned automatically on insert.",
+                );
+            }
+            if identity_columns.contains(&c.column_name) {
+                docs.push_str(
+                    "\n\nThis column is a generated identity column and \
+                     is assigned automatically on insert unless \
+                     `OVERRIDING SYSTEM VALUE` is specified.",
+                );
+            }
+
+            Ok(ColumnDefinition {
+                docs,
                 sql_name: c.column_name,
                 ty,
                 rust_name,
@@ -321,6 +321,52 @@ where
         .collect())
 }
 
+pub fn load_view_names<'a, Conn>(
+    connection: &mut Conn,
+    schema_name: Option<&'a str>,
+) -> Result<Vec<TableName>, Box<dyn Error + Send + Sync + 'static>>
+where
+    Conn: Connection,
+    Conn::Backend: UsesInformationSchema,
+    String: FromSql<sql_types::Text, Conn::Backend>,
+    Filter<
+        Filter<
+            Filter<
+                Select<tables::table, tables::table_name>,
+                Eq<tables::table_schema, Cow<'a, str>>,
+            >,
+            NotLike<tables::table_name, &'static str>,
+        >,
+        Like<tables::table_type, &'static str>,
+    >: QueryFragment<Conn::Backend>,
+    Conn::Backend: QueryMetadata<sql_types::Text>,
+{
+    use self::information_schema::tables::dsl::*;
+
+    let default_schema = Conn::Backend::default_schema(connection)?;
+    let db_schema_name = schema_name
+        .map(Cow::Borrowed)
+        .unwrap_or_else(|| Cow::Owned(default_schema.clone()));
+
+    let mut view_names = tables
+        .select(table_name)
+        .filter(table_schema.eq(db_schema_name))
+        .filter(table_name.not_like("\\_\\_%"))
+        .filter(table_type.like("VIEW"))
+        .load::<String>(connection)?;
+    view_names.sort_unstable();
+    Ok(view_names
+        .into_iter()
+        .map(|name| TableName {
+            rust_name: inference::rust_name_for_sql_name(&name),
+            sql_name: name,
+            schema: schema_name
+                .filter(|&schema| schema != default_schema)
+                .map(|schema| schema.to_owned()),
+        })
+        .collect())
+}
+
 #[allow(clippy::similar_names)]
 #[cfg(feature = "postgres")]
 pub fn load_foreign_key_constraints(
@@ -1,4 +1,8 @@
+use diesel::prelude::*;
+
 use super::data_structures::*;
+use super::inference;
+use super::table_data::TableName;
 use heck::CamelCase;
 use std::error::Error;
 use std::io::{stderr, Write};
@@ -45,3 +49,126 @@ pub fn determine_column_type(
         is_unsigned: false,
     })
 }
+
+table! {
+    pg_matviews (matviewname) {
+        schemaname -> VarChar,
+        matviewname -> VarChar,
+    }
+}
+
+table! {
+    pg_type (oid) {
+        oid -> Oid,
+        typname -> VarChar,
+        typnamespace -> Oid,
+    }
+}
+
+table! {
+    pg_namespace (oid) {
+        oid -> Oid,
+        nspname -> VarChar,
+    }
+}
+
+table! {
+    pg_enum (oid) {
+        oid -> Oid,
+        enumtypid -> Oid,
+        enumsortorder -> Float,
+        enumlabel -> VarChar,
+    }
+}
+
+mod identity_columns {
+    diesel::table! {
+        information_schema.columns (table_schema, table_name, column_name) {
+            table_schema -> VarChar,
+            table_name -> VarChar,
+            column_name -> VarChar,
+            is_identity -> VarChar,
+        }
+    }
+}
+
+pub fn get_identity_columns(
+    connection: &mut PgConnection,
+    table: &TableName,
+) -> QueryResult<Vec<String>> {
+    use self::identity_columns::columns::dsl::*;
+    use super::information_schema::UsesInformationSchema;
+
+    let schema_name = match table.schema {
+        Some(ref name) => name.clone(),
+        None => diesel::pg::Pg::default_schema(connection)?,
+    };
+    columns
+        .select(column_name)
+        .filter(table_name.eq(&table.sql_name))
+        .filter(table_schema.eq(schema_name))
+        .filter(is_identity.eq("YES"))
+        .load(connection)
+}
+
+pub fn load_enum_variants(
+    connection: &mut PgConnection,
+    schema_name: &str,
+    type_name: &str,
+) -> Result<Vec<String>, Box<dyn Error + Send + Sync + 'static>> {
+    let namespace_oid = pg_namespace::table
+        .select(pg_namespace::oid)
+        .filter(pg_namespace::nspname.eq(schema_name))
+        .first::<u32>(connection)
+        .optional()?;
+    let namespace_oid = match namespace_oid {
+        Some(oid) => oid,
+        None => return Ok(Vec::new()),
+    };
+
+    let type_oid = pg_type::table
+        .select(pg_type::oid)
+        .filter(pg_type::typname.eq(type_name))
+        .filter(pg_type::typnamespace.eq(namespace_oid))
+        .first::<u32>(connection)
+        .optional()?;
+    let type_oid = match type_oid {
+        Some(oid) => oid,
+        None => return Ok(Vec::new()),
+    };
+
+    // Types which are not enums simply have no `pg_enum` entries, so
+    // this returns an empty list for them.
+    Ok(pg_enum::table
+        .select(pg_enum::enumlabel)
+        .filter(pg_enum::enumtypid.eq(type_oid))
+        .order(pg_enum::enumsortorder)
+        .load::<String>(connection)?)
+}
+
+pub fn load_materialized_view_names(
+    connection: &mut PgConnection,
+    schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error + Send + Sync + 'static>> {
+    use self::pg_matviews::dsl::*;
+
+    let default_schema = "public";
+    let db_schema_name = schema_name.unwrap_or(default_schema);
+
+    let mut view_names = pg_matviews
+        .select(matviewname)
+        .filter(schemaname.eq(db_schema_name))
+        .filter(matviewname.not_like("\\_\\_%"))
+        .load::<String>(connection)?;
+    view_names.sort_unstable();
+    Ok(view_names
+        .into_iter()
+        .map(|name| TableName {
+            rust_name: inference::rust_name_for_sql_name(&name),
+            sql_name: name,
+            schema: schema_name
+                .filter(|&schema| schema != default_schema)
+                .map(|schema| schema.to_owned()),
+        })
+        .collect())
+}
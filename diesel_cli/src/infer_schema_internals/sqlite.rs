@@ -61,6 +61,30 @@ pub fn load_table_names(
         .collect())
 }
 
+pub fn load_view_names(
+    connection: &mut SqliteConnection,
+    schema_name: Option<&str>,
+) -> Result<Vec<TableName>, Box<dyn Error + Send + Sync + 'static>> {
+    use self::sqlite_master::dsl::*;
+
+    if schema_name.is_some() {
+        return Err("sqlite cannot infer schema for databases other than the \
+                    main database"
+            .into());
+    }
+
+    Ok(sqlite_master
+        .select(name)
+        .filter(name.not_like("\\_\\_%").escape('\\'))
+        .filter(name.not_like("sqlite%"))
+        .filter(sql::<sql_types::Bool>("type='view'"))
+        .order(name)
+        .load::<String>(connection)?
+        .into_iter()
+        .map(TableName::from_name)
+        .collect())
+}
+
 pub fn load_foreign_key_constraints(
     connection: &mut SqliteConnection,
     schema_name: Option<&str>,
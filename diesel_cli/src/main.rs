@@ -68,17 +68,25 @@ fn run_migration_command(
     match matches.subcommand() {
         ("run", Some(_)) => {
             let database_url = database::database_url(matches);
-            let dir = migrations_dir(matches).unwrap_or_else(handle_error);
-            let dir = FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error);
-            call_with_conn!(database_url, run_migrations_with_output(dir))?;
+            for dir in migrations_dirs(matches).unwrap_or_else(handle_error) {
+                let dir = FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error);
+                call_with_conn!(database_url, run_migrations_with_output(dir))?;
+            }
             regenerate_schema_if_file_specified(matches)?;
         }
         ("revert", Some(args)) => {
             let database_url = database::database_url(matches);
-            let dir = migrations_dir(matches).unwrap_or_else(handle_error);
-            let dir = FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error);
+            let dirs = migrations_dirs(matches)
+                .unwrap_or_else(handle_error)
+                .into_iter()
+                .map(|dir| FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error))
+                .collect::<Vec<_>>();
             if args.is_present("REVERT_ALL") {
-                call_with_conn!(database_url, revert_all_migrations_with_output(dir))?;
+                // Directories are reverted in the reverse of their
+                // declaration order.
+                for dir in dirs.iter().rev() {
+                    call_with_conn!(database_url, revert_all_migrations_with_output(dir.clone()))?;
+                }
             } else {
                 // TODO : remove this logic when upgrading to clap 3.0.
                 // We handle the default_value here instead of doing it
@@ -86,21 +94,35 @@ fn run_migration_command(
                 // values conflict even if not used.
                 // See https://github.com/clap-rs/clap/issues/1605
                 let number = args.value_of("REVERT_NUMBER").unwrap_or("1");
-                for _ in 0..number.parse::<u64>().expect("Unable to parse the value of the --number argument. A positive integer is expected.") {
-                        match call_with_conn!(
-                            database_url,
-                            revert_migration_with_output(dir.clone())
-                        ) {
-                            Ok(_) => {}
-                            Err(e) if e.is::<MigrationError>() => {
-                                match e.downcast_ref::<MigrationError>() {
-                                    // If n is larger then the actual number of migrations,
-                                    // just stop reverting them
-                                    Some(MigrationError::NoMigrationRun) => break,
-                                    _ => return Err(e),
+                'revert: for _ in 0..number.parse::<u64>().expect("Unable to parse the value of the --number argument. A positive integer is expected.") {
+                        let mut unknown_version_err = None;
+                        for dir in dirs.iter().rev() {
+                            match call_with_conn!(
+                                database_url,
+                                revert_migration_with_output(dir.clone())
+                            ) {
+                                Ok(_) => {
+                                    unknown_version_err = None;
+                                    break;
+                                }
+                                Err(e) if e.is::<MigrationError>() => {
+                                    match e.downcast_ref::<MigrationError>() {
+                                        // If n is larger then the actual number of migrations,
+                                        // just stop reverting them
+                                        Some(MigrationError::NoMigrationRun) => break 'revert,
+                                        // The migration to revert may live in one of the
+                                        // other configured directories.
+                                        Some(MigrationError::UnknownMigrationVersion(_)) => {
+                                            unknown_version_err = Some(e)
+                                        }
+                                        _ => return Err(e),
+                                    }
                                 }
+                                Err(e) => return Err(e),
                             }
-                            Err(e) => return Err(e),
+                        }
+                        if let Some(e) = unknown_version_err {
+                            return Err(e);
                         }
                     }
             }
@@ -109,23 +131,31 @@ fn run_migration_command(
         }
         ("redo", Some(args)) => {
             let database_url = database::database_url(matches);
-            let dir = migrations_dir(matches).unwrap_or_else(handle_error);
-            let dir = FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error);
-            call_with_conn!(database_url, redo_migrations(dir, args));
+            let dirs = migrations_dirs(matches)
+                .unwrap_or_else(handle_error)
+                .into_iter()
+                .map(|dir| FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error))
+                .collect::<Vec<_>>();
+            call_with_conn!(database_url, redo_migrations(dirs, args));
             regenerate_schema_if_file_specified(matches)?;
         }
         ("list", Some(_)) => {
             let database_url = database::database_url(matches);
-            let dir = migrations_dir(matches).unwrap_or_else(handle_error);
-            let dir = FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error);
-            call_with_conn!(database_url, list_migrations(dir))?;
+            let dirs = migrations_dirs(matches)
+                .unwrap_or_else(handle_error)
+                .into_iter()
+                .map(|dir| FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error))
+                .collect::<Vec<_>>();
+            call_with_conn!(database_url, list_migrations(dirs))?;
         }
         ("pending", Some(_)) => {
             let database_url = database::database_url(matches);
-            let dir = migrations_dir(matches).unwrap_or_else(handle_error);
-            let dir = FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error);
-            let result =
-                call_with_conn!(database_url, MigrationHarness::has_pending_migration(dir))?;
+            let mut result = false;
+            for dir in migrations_dirs(matches).unwrap_or_else(handle_error) {
+                let dir = FileBasedMigrations::from_path(dir).unwrap_or_else(handle_error);
+                result = result
+                    || call_with_conn!(database_url, MigrationHarness::has_pending_migration(dir))?;
+            }
             println!("{:?}", result);
         }
         ("generate", Some(args)) => {
@@ -233,7 +263,7 @@ where
 
 fn list_migrations<Conn, DB>(
     conn: &mut Conn,
-    migrations: FileBasedMigrations,
+    migrations_dirs: Vec<FileBasedMigrations>,
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>>
 where
     Conn: MigrationHarness<DB> + Connection<Backend = DB> + 'static,
@@ -244,7 +274,13 @@ where
         .into_iter()
         .collect::<HashSet<_>>();
 
-    let mut migrations = MigrationSource::<DB>::migrations(&migrations)?;
+    let mut migrations = migrations_dirs
+        .iter()
+        .map(|dir| MigrationSource::<DB>::migrations(dir))
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
     migrations.sort_unstable_by(|a, b| a.name().version().cmp(&b.name().version()));
     println!("Migrations:");
     for migration in migrations {
@@ -257,39 +293,59 @@ where
     Ok(())
 }
 
-/// Checks for a migrations folder in the following order :
+/// Checks for migrations folders in the following order :
 /// 1. From the CLI arguments
 /// 2. From the MIGRATION_DIRECTORY environment variable
-/// 3. From `diesel.toml` in the `migrations_directory` section
+/// 3. From `diesel.toml` in the `migrations_directory` section, which
+///    may list several directories that are processed in declaration
+///    order
 ///
 /// Else try to find the migrations directory with the
 /// `find_migrations_directory` in the diesel_migrations crate.
 ///
 /// Returns a `MigrationError::MigrationDirectoryNotFound` if
-/// no path to the migration directory is found.
-fn migrations_dir(matches: &ArgMatches) -> Result<PathBuf, MigrationError> {
-    let migrations_dir = migrations_dir_from_cli(matches)
-        .or_else(|| env::var("MIGRATION_DIRECTORY").map(PathBuf::from).ok())
+/// no path to a migration directory is found.
+fn migrations_dirs(matches: &ArgMatches) -> Result<Vec<PathBuf>, MigrationError> {
+    let migrations_dirs = migrations_dir_from_cli(matches)
+        .map(|dir| vec![dir])
         .or_else(|| {
-            Some(
-                Config::read(matches)
-                    .unwrap_or_else(handle_error)
-                    .migrations_directory?
-                    .dir,
-            )
+            env::var("MIGRATION_DIRECTORY")
+                .map(|dir| vec![PathBuf::from(dir)])
+                .ok()
+        })
+        .or_else(|| {
+            let dirs = Config::read(matches)
+                .unwrap_or_else(handle_error)
+                .migrations_directory?
+                .dir;
+            if dirs.is_empty() {
+                None
+            } else {
+                Some(dirs)
+            }
         });
 
-    match migrations_dir {
-        Some(dir) => Ok(dir),
-        None => FileBasedMigrations::find_migrations_directory().map(|p| p.path().to_path_buf()),
+    match migrations_dirs {
+        Some(dirs) => Ok(dirs),
+        None => {
+            FileBasedMigrations::find_migrations_directory().map(|p| vec![p.path().to_path_buf()])
+        }
     }
 }
 
+/// Returns the primary migrations directory. Commands that create new
+/// files (`setup` and `migration generate`) always target the first
+/// configured directory.
+fn migrations_dir(matches: &ArgMatches) -> Result<PathBuf, MigrationError> {
+    migrations_dirs(matches).map(|mut dirs| dirs.remove(0))
+}
+
 fn run_setup_command(matches: &ArgMatches) {
     create_config_file(matches).unwrap_or_else(handle_error);
-    let migrations_dir = create_migrations_dir(matches).unwrap_or_else(handle_error);
+    create_migrations_dir(matches).unwrap_or_else(handle_error);
+    let migrations_dirs = migrations_dirs(matches).unwrap_or_else(handle_error);
 
-    database::setup_database(matches, &migrations_dir).unwrap_or_else(handle_error);
+    database::setup_database(matches, &migrations_dirs).unwrap_or_else(handle_error);
 }
 
 /// Checks if the migration directory exists, else creates it.
@@ -346,13 +402,13 @@ fn run_database_command(
 ) -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
     match matches.subcommand() {
         ("setup", Some(args)) => {
-            let migrations_dir = migrations_dir(matches).unwrap_or_else(handle_error);
-            database::setup_database(args, &migrations_dir)?;
+            let migrations_dirs = migrations_dirs(matches).unwrap_or_else(handle_error);
+            database::setup_database(args, &migrations_dirs)?;
             regenerate_schema_if_file_specified(matches)?;
         }
         ("reset", Some(args)) => {
-            let migrations_dir = migrations_dir(matches).unwrap_or_else(handle_error);
-            database::reset_database(args, &migrations_dir)?;
+            let migrations_dirs = migrations_dirs(matches).unwrap_or_else(handle_error);
+            database::reset_database(args, &migrations_dirs)?;
             regenerate_schema_if_file_specified(matches)?;
         }
         ("drop", Some(args)) => database::drop_database_command(args)?,
@@ -413,7 +469,7 @@ fn search_for_directory_containing_file(path: &Path, file: &str) -> DatabaseResu
 /// the transaction is not committed.
 fn redo_migrations<Conn, DB>(
     conn: &mut Conn,
-    migrations_dir: FileBasedMigrations,
+    migrations_dirs: Vec<FileBasedMigrations>,
     args: &ArgMatches,
 ) where
     DB: Backend,
@@ -421,7 +477,18 @@ fn redo_migrations<Conn, DB>(
 {
     let migrations_inner = |harness: &mut HarnessWithOutput<Conn, _>| -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
         let reverted_versions = if args.is_present("REDO_ALL") {
-            harness.revert_all_migrations(migrations_dir.clone())?
+            // Directories are reverted in the reverse of their
+            // declaration order.
+            let mut reverted_versions = Vec::new();
+            for dir in migrations_dirs.iter().rev() {
+                reverted_versions.extend(
+                    harness
+                        .revert_all_migrations(dir.clone())?
+                        .into_iter()
+                        .map(|v| v.as_owned()),
+                );
+            }
+            reverted_versions
         } else {
             // TODO : remove this logic when upgrading to clap 3.0.
             // We handle the default_value here instead of doing it
@@ -430,30 +497,45 @@ fn redo_migrations<Conn, DB>(
             // See https://github.com/clap-rs/clap/issues/1605
             let number = args.value_of("REDO_NUMBER").unwrap_or("1");
 
-            (0..number.parse::<u64>().expect("Unable to parse the value of the --number argument. A positive integer is expected."))
-                .filter_map(|_|{
-                    match harness.revert_last_migration(migrations_dir.clone()) {
+            let mut reverted_versions = Vec::new();
+            'redo: for _ in 0..number.parse::<u64>().expect("Unable to parse the value of the --number argument. A positive integer is expected.") {
+                let mut unknown_version_err = None;
+                for dir in migrations_dirs.iter().rev() {
+                    match harness.revert_last_migration(dir.clone()) {
                         Ok(v) => {
-                            Some(Ok(v))
+                            reverted_versions.push(v.as_owned());
+                            unknown_version_err = None;
+                            break;
                         }
                         Err(e) if e.is::<MigrationError>() => {
                             match e.downcast_ref::<MigrationError>() {
                                 // If n is larger then the actual number of migrations,
                                 // just stop reverting them
-                                Some(MigrationError::NoMigrationRun) => None,
-                                _ => Some(Err(e)),
+                                Some(MigrationError::NoMigrationRun) => break 'redo,
+                                // The migration to revert may live in one of the
+                                // other configured directories.
+                                Some(MigrationError::UnknownMigrationVersion(_)) => {
+                                    unknown_version_err = Some(e)
+                                }
+                                _ => return Err(e),
                             }
                         }
-                        Err(e) => {
-                            Some(Err(e))
-                        }
+                        Err(e) => return Err(e),
                     }
-                })
-                .collect::<Result<Vec<_>, _>>()?
+                }
+                if let Some(e) = unknown_version_err {
+                    return Err(e);
+                }
+            }
+            reverted_versions
         };
 
-        let mut migrations = MigrationSource::<DB>::migrations(&migrations_dir)?
+        let mut migrations = migrations_dirs
+            .iter()
+            .map(|dir| MigrationSource::<DB>::migrations(dir))
+            .collect::<Result<Vec<_>, _>>()?
             .into_iter()
+            .flatten()
             .map(|m| (m.name().version().as_owned(), m))
             .collect::<HashMap<_, _>>();
 
@@ -535,10 +617,31 @@ fn run_infer_schema(matches: &ArgMatches) -> Result<(), Box<dyn Error + Send + S
         config.filter = Filtering::ExceptTables(filter?)
     }
 
+    let parse_filter_patterns = |patterns: clap::Values<'_>| {
+        patterns
+            .map(|pattern| Regex::new(pattern).map(Into::into))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("invalid argument for table filtering regex: {}", e))
+    };
+
+    if let Some(patterns) = matches.values_of("allow-tables-regex") {
+        config.filter = Filtering::OnlyTables(parse_filter_patterns(patterns)?);
+    } else if let Some(patterns) = matches.values_of("deny-tables-regex") {
+        config.filter = Filtering::ExceptTables(parse_filter_patterns(patterns)?);
+    }
+
     if matches.is_present("with-docs") {
         config.with_docs = true;
     }
 
+    if matches.is_present("include-views") {
+        config.include_views = true;
+    }
+
+    if matches.is_present("include-materialized-views") {
+        config.include_materialized_views = true;
+    }
+
     if let Some(sorting) = matches.value_of("column-sorting") {
         match sorting {
             "ordinal_position" => config.column_sorting = ColumnSorting::OrdinalPosition,
@@ -560,6 +663,21 @@ fn run_infer_schema(matches: &ArgMatches) -> Result<(), Box<dyn Error + Send + S
         config.generate_missing_sql_type_definitions = Some(false);
     }
 
+    if matches.is_present("sqlite-integer-primary-key-is-rowid") {
+        config.sqlite_integer_primary_key_is_rowid = true;
+    }
+
+    if let Some(path) = matches.value_of("diff") {
+        let old_schema = fs::read_to_string(path)
+            .map_err(|e| format!("Error reading schema file {}: {}", path, e))?;
+        let new_schema = output_schema(&database_url, &config)?;
+        match schema_diff(&old_schema, &new_schema)? {
+            Some(report) => print!("{}", report),
+            None => println!("No schema changes detected."),
+        }
+        return Ok(());
+    }
+
     run_print_schema(&database_url, &config, &mut stdout())?;
     Ok(())
 }
@@ -5,7 +5,7 @@ use crate::infer_schema_internals::*;
 use serde::de::{self, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_regex::Serde as RegexWrapper;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::fmt::{self, Display, Formatter, Write};
 use std::io::Write as IoWrite;
@@ -158,7 +158,25 @@ pub fn output_schema(
     database_url: &str,
     config: &config::PrintSchema,
 ) -> Result<String, Box<dyn Error + Send + Sync + 'static>> {
-    let table_names = load_table_names(database_url, config.schema_name())?
+    let mut table_names = load_table_names(database_url, config.schema_name())?;
+    let mut view_names = HashSet::new();
+    let mut materialized_view_names = HashSet::new();
+    if config.include_views {
+        for view in load_view_names(database_url, config.schema_name())? {
+            view_names.insert(view.sql_name.clone());
+            table_names.push(view);
+        }
+    }
+    if config.include_materialized_views {
+        for view in load_materialized_view_names(database_url, config.schema_name())? {
+            materialized_view_names.insert(view.sql_name.clone());
+            table_names.push(view);
+        }
+    }
+    if config.include_views || config.include_materialized_views {
+        table_names.sort_unstable_by(|a, b| a.sql_name.cmp(&b.sql_name));
+    }
+    let table_names = table_names
         .into_iter()
         .filter(|t| !config.filter.should_ignore_table(t))
         .collect::<Vec<_>>();
@@ -167,7 +185,14 @@ pub fn output_schema(
         remove_unsafe_foreign_keys_for_codegen(database_url, &foreign_keys, &table_names);
     let table_data = table_names
         .into_iter()
-        .map(|t| load_table_data(database_url, t, &config.column_sorting))
+        .map(|t| {
+            load_table_data(
+                database_url,
+                t,
+                &config.column_sorting,
+                config.sqlite_integer_primary_key_is_rowid,
+            )
+        })
         .collect::<Result<Vec<_>, Box<dyn Error + Send + Sync + 'static>>>()?;
 
     let mut out = String::new();
@@ -199,13 +224,22 @@ pub fn output_schema(
         Vec::new()
     };
 
+    let enum_variants = if custom_types_sorted.is_empty() {
+        HashMap::new()
+    } else {
+        load_enum_variants(database_url, &custom_types_sorted)?
+    };
+
     let definitions = TableDefinitions {
         tables: table_data,
         fk_constraints: foreign_keys,
+        view_names,
+        materialized_view_names,
         include_docs: config.with_docs,
         custom_type_defs: CustomTypeList {
             backend,
             types_sorted: custom_types_sorted,
+            enum_variants,
             with_docs: config.with_docs,
         },
         import_types: config.import_types(),
@@ -231,6 +265,7 @@ pub fn output_schema(
 struct CustomTypeList {
     backend: Backend,
     types_sorted: Vec<ColumnType>,
+    enum_variants: HashMap<String, Vec<String>>,
     with_docs: bool,
 }
 
@@ -283,6 +318,11 @@ impl Display for CustomTypeList {
                         writeln!(out, "#[postgres(type_name = \"{}\")]", t.sql_name)?;
                     }
                     writeln!(out, "pub struct {};", t.rust_name)?;
+
+                    if let Some(variants) = self.enum_variants.get(&t.rust_name) {
+                        writeln!(out)?;
+                        write_enum_definition(&mut out, t, variants, self.with_docs)?;
+                    }
                 }
 
                 writeln!(f, "}}\n")?;
@@ -318,6 +358,104 @@ impl Display for CustomTypeList {
     }
 }
 
+#[cfg(feature = "postgres")]
+fn write_enum_definition(
+    mut out: impl Write,
+    t: &ColumnType,
+    variants: &[String],
+    with_docs: bool,
+) -> fmt::Result {
+    use heck::CamelCase;
+
+    let enum_name = format!("{}Enum", t.rust_name);
+    let variants = variants
+        .iter()
+        .map(|label| (rust_name_for_sql_name(label).to_camel_case(), label))
+        .collect::<Vec<_>>();
+
+    if with_docs {
+        writeln!(
+            out,
+            "/// Rust representation of the `{}` SQL enum",
+            t.sql_name
+        )?;
+        writeln!(out, "///")?;
+        writeln!(out, "/// (Automatically generated by Diesel.)")?;
+    }
+    writeln!(
+        out,
+        "#[derive(Debug, Clone, Copy, PartialEq, Eq, diesel::deserialize::FromSqlRow, diesel::expression::AsExpression)]"
+    )?;
+    writeln!(out, "#[sql_type = \"{}\"]", t.rust_name)?;
+    writeln!(out, "pub enum {} {{", enum_name)?;
+    for (variant, label) in &variants {
+        if with_docs {
+            writeln!(out, "    /// The `{}` variant", label)?;
+        }
+        writeln!(out, "    {},", variant)?;
+    }
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "impl diesel::serialize::ToSql<{}, diesel::pg::Pg> for {} {{",
+        t.rust_name, enum_name
+    )?;
+    writeln!(out, "    fn to_sql<W: std::io::Write>(")?;
+    writeln!(out, "        &self,")?;
+    writeln!(
+        out,
+        "        out: &mut diesel::serialize::Output<W, diesel::pg::Pg>,"
+    )?;
+    writeln!(out, "    ) -> diesel::serialize::Result {{")?;
+    writeln!(out, "        let label = match *self {{")?;
+    for (variant, label) in &variants {
+        writeln!(
+            out,
+            "            {}::{} => {:?},",
+            enum_name, variant, label
+        )?;
+    }
+    writeln!(out, "        }};")?;
+    writeln!(
+        out,
+        "        std::io::Write::write_all(out, label.as_bytes())?;"
+    )?;
+    writeln!(out, "        Ok(diesel::serialize::IsNull::No)")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "impl diesel::deserialize::FromSql<{}, diesel::pg::Pg> for {} {{",
+        t.rust_name, enum_name
+    )?;
+    writeln!(
+        out,
+        "    fn from_sql(bytes: diesel::pg::PgValue<'_>) -> diesel::deserialize::Result<Self> {{"
+    )?;
+    writeln!(
+        out,
+        "        match std::str::from_utf8(bytes.as_bytes())? {{"
+    )?;
+    for (variant, label) in &variants {
+        writeln!(
+            out,
+            "            {:?} => Ok({}::{}),",
+            label, enum_name, variant
+        )?;
+    }
+    writeln!(
+        out,
+        "            label => Err(format!(\"Unrecognized `{}` variant: {{}}\", label).into()),",
+        t.sql_name
+    )?;
+    writeln!(out, "        }}")?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+    Ok(())
+}
+
 struct ModuleDefinition<'a>(&'a str, TableDefinitions<'a>);
 
 impl<'a> Display for ModuleDefinition<'a> {
@@ -336,6 +474,8 @@ impl<'a> Display for ModuleDefinition<'a> {
 struct TableDefinitions<'a> {
     tables: Vec<TableData>,
     fk_constraints: Vec<ForeignKeyConstraint>,
+    view_names: HashSet<String>,
+    materialized_view_names: HashSet<String>,
     include_docs: bool,
     import_types: Option<&'a [String]>,
     custom_type_defs: CustomTypeList,
@@ -355,6 +495,10 @@ impl<'a> Display for TableDefinitions<'a> {
                 "{}",
                 TableDefinition {
                     table,
+                    is_view: self.view_names.contains(&table.name.sql_name),
+                    is_materialized_view: self
+                        .materialized_view_names
+                        .contains(&table.name.sql_name),
                     include_docs: self.include_docs,
                     import_types: self.import_types,
                     custom_type_defs: &self.custom_type_defs
@@ -392,6 +536,8 @@ impl<'a> Display for TableDefinitions<'a> {
 
 struct TableDefinition<'a> {
     table: &'a TableData,
+    is_view: bool,
+    is_materialized_view: bool,
     include_docs: bool,
     import_types: Option<&'a [String]>,
     custom_type_defs: &'a CustomTypeList,
@@ -399,6 +545,19 @@ struct TableDefinition<'a> {
 
 impl<'a> Display for TableDefinition<'a> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.is_materialized_view {
+            writeln!(
+                f,
+                "// This definition corresponds to a materialized view and should \
+                 only be used for querying, not for inserts or updates."
+            )?;
+        } else if self.is_view {
+            writeln!(
+                f,
+                "// This definition corresponds to a database view and should \
+                 only be used for querying, not for inserts or updates."
+            )?;
+        }
         write!(f, "diesel::table! {{")?;
         {
             let mut out = PadAdapter::new(f);
@@ -615,3 +774,199 @@ impl<'de> Deserialize<'de> for Filtering {
         deserializer.deserialize_map(FilteringVisitor)
     }
 }
+
+/// A single table parsed out of a generated `schema.rs`
+#[derive(Debug, PartialEq)]
+struct ParsedTable {
+    name: String,
+    columns: Vec<(String, String)>,
+}
+
+/// Extracts the `table!` definitions from a generated schema file.
+///
+/// This intentionally only understands the output of `print-schema`
+/// (and hand-edited files of the same shape); it is not a Rust parser.
+fn parse_tables(schema: &str) -> Result<Vec<ParsedTable>, String> {
+    let mut tables = Vec::new();
+    let mut current: Option<ParsedTable> = None;
+    let mut depth = 0i64;
+
+    for line in schema.lines() {
+        let trimmed = line.trim();
+        if current.is_none() {
+            if trimmed.ends_with("table! {")
+                && (trimmed.starts_with("table!") || trimmed.starts_with("diesel::table!"))
+            {
+                current = Some(ParsedTable {
+                    name: String::new(),
+                    columns: Vec::new(),
+                });
+                depth = 1;
+            }
+            continue;
+        }
+
+        depth += trimmed.matches('{').count() as i64;
+        depth -= trimmed.matches('}').count() as i64;
+        if depth < 0 {
+            return Err("unbalanced braces: unexpected `}`".into());
+        }
+
+        if let Some(ref mut table) = current {
+            if table.name.is_empty() {
+                if let Some(open_paren) = trimmed.find(" (") {
+                    if trimmed.ends_with('{') {
+                        table.name = trimmed[..open_paren].to_owned();
+                    }
+                }
+            } else if let Some(arrow) = trimmed.find(" -> ") {
+                let name = trimmed[..arrow].to_owned();
+                let ty = trimmed[arrow + 4..].trim_end_matches(',').to_owned();
+                table.columns.push((name, ty));
+            }
+        }
+
+        if depth == 0 {
+            tables.extend(current.take());
+        }
+    }
+
+    if current.is_some() {
+        return Err("unbalanced braces: unclosed `table!` block".into());
+    }
+
+    Ok(tables)
+}
+
+/// Renders a report of the differences between a saved schema file and a
+/// freshly generated one. Returns `Ok(None)` if the two schemas define the
+/// same tables and columns.
+pub fn schema_diff(old: &str, new: &str) -> Result<Option<String>, String> {
+    let old_tables = parse_tables(old).map_err(|e| format!("Error parsing schema file: {}", e))?;
+    let new_tables =
+        parse_tables(new).map_err(|e| format!("Error parsing generated schema: {}", e))?;
+    let mut out = String::new();
+
+    for table in &old_tables {
+        if !new_tables.iter().any(|t| t.name == table.name) {
+            writeln!(out, "removed table `{}`", table.name).expect("writing to a String");
+        }
+    }
+
+    for table in &new_tables {
+        let old_table = match old_tables.iter().find(|t| t.name == table.name) {
+            Some(old_table) => old_table,
+            None => {
+                writeln!(out, "added table `{}`", table.name).expect("writing to a String");
+                continue;
+            }
+        };
+        if old_table.columns == table.columns {
+            continue;
+        }
+
+        writeln!(out, "changed table `{}`:", table.name).expect("writing to a String");
+        for (name, ty) in &old_table.columns {
+            match table.columns.iter().find(|(n, _)| n == name) {
+                Some((_, new_ty)) if new_ty != ty => {
+                    writeln!(out, "    changed column `{}`: {} -> {}", name, ty, new_ty)
+                        .expect("writing to a String");
+                }
+                Some(_) => {}
+                None => {
+                    writeln!(out, "    removed column `{}` ({})", name, ty)
+                        .expect("writing to a String");
+                }
+            }
+        }
+        for (name, ty) in &table.columns {
+            if !old_table.columns.iter().any(|(n, _)| n == name) {
+                writeln!(out, "    added column `{}` ({})", name, ty).expect("writing to a String");
+            }
+        }
+    }
+
+    if out.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(out))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_tables, schema_diff};
+
+    const SCHEMA: &str = r#"
+        diesel::table! {
+            users (id) {
+                id -> Integer,
+                name -> Text,
+            }
+        }
+
+        diesel::table! {
+            posts (id) {
+                id -> Integer,
+                user_id -> Integer,
+            }
+        }
+    "#;
+
+    #[test]
+    fn parses_generated_schema() {
+        let tables = parse_tables(SCHEMA).unwrap();
+        assert_eq!(tables.len(), 2);
+        assert_eq!(tables[0].name, "users");
+        assert_eq!(
+            tables[0].columns,
+            vec![
+                ("id".into(), "Integer".into()),
+                ("name".into(), "Text".into())
+            ]
+        );
+        assert_eq!(tables[1].name, "posts");
+    }
+
+    #[test]
+    fn unbalanced_braces_are_an_error() {
+        let excess_close = "diesel::table! {\n    users (id) {\n        id -> Integer,\n    }}}\n";
+        assert!(parse_tables(excess_close).is_err());
+
+        let unclosed = "diesel::table! {\n    users (id) {\n        id -> Integer,\n";
+        assert!(parse_tables(unclosed).is_err());
+    }
+
+    #[test]
+    fn identical_schemas_have_no_diff() {
+        assert_eq!(schema_diff(SCHEMA, SCHEMA), Ok(None));
+    }
+
+    #[test]
+    fn reports_table_and_column_changes() {
+        let new_schema = r#"
+            diesel::table! {
+                users (id) {
+                    id -> Integer,
+                    name -> Nullable<Text>,
+                    email -> Text,
+                }
+            }
+
+            diesel::table! {
+                comments (id) {
+                    id -> Integer,
+                }
+            }
+        "#;
+        let diff = schema_diff(SCHEMA, new_schema).unwrap().unwrap();
+        assert_eq!(
+            diff,
+            "removed table `posts`\n\
+             changed table `users`:\n    \
+             changed column `name`: Text -> Nullable<Text>\n    \
+             added column `email` (Text)\n\
+             added table `comments`\n"
+        );
+    }
+}
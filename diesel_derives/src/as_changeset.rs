@@ -11,7 +11,7 @@ use util::*;
 pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagnostic> {
     let treat_none_as_null = MetaItem::with_name(&item.attrs, "changeset_options")
         .map(|meta| {
-            meta.warn_if_other_options(&["treat_none_as_null"]);
+            meta.warn_if_other_options(&["treat_none_as_null", "column_prefix", "rename_all"]);
             meta.required_nested_item("treat_none_as_null")
                 .map(|m| m.expect_bool_value())
         })
@@ -29,6 +29,7 @@ pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagno
         .fields()
         .iter()
         .filter(|f| !model.primary_key_names.contains(&f.column_name_ident()))
+        .filter(|f| !f.has_flag("ignore"))
         .collect::<Vec<_>>();
     let ref_changeset_ty = fields_for_update.iter().map(|field| {
         field_changeset_ty(
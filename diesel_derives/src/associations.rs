@@ -5,6 +5,7 @@ use syn::fold::Fold;
 use syn::spanned::Spanned;
 
 use diagnostic_shim::*;
+use field::*;
 use meta::*;
 use model::*;
 use util::*;
@@ -22,8 +23,347 @@ pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagno
                 }
             },
         );
+    let joins = derive_foreign_key_joins(&model);
+    let has_many = MetaItem::all_with_name(&item.attrs, "diesel")
+        .into_iter()
+        .filter_map(|m| m.nested().ok().map(Iterator::collect::<Vec<_>>))
+        .flatten()
+        .filter(|m| m.name().is_ident("has_many"))
+        .filter_map(|attr| match derive_has_many(&model, &item.generics, attr) {
+            Ok(t) => Some(t),
+            Err(e) => {
+                e.emit();
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    // The generated enum must be visible at the derive site, so polymorphic
+    // associations cannot be wrapped in the dummy mod
+    let poly = MetaItem::all_with_name(&item.attrs, "diesel")
+        .into_iter()
+        .filter_map(|m| m.nested().ok().map(Iterator::collect::<Vec<_>>))
+        .flatten()
+        .filter(|m| m.name().is_ident("belongs_to_poly"))
+        .filter_map(
+            |attr| match derive_belongs_to_poly(&model, &item.generics, attr) {
+                Ok(t) => Some(t),
+                Err(e) => {
+                    e.emit();
+                    None
+                }
+            },
+        )
+        .collect::<Vec<_>>();
+
+    let wrapped = wrap_in_dummy_mod(quote!(#(#tokens)* #(#joins)* #(#has_many)*));
+    Ok(quote!(#(#poly)* #wrapped))
+}
+
+fn derive_belongs_to_poly(
+    model: &Model,
+    generics: &syn::Generics,
+    meta: MetaItem,
+) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let parents = meta
+        .nested()?
+        .filter_map(|m| m.path().ok())
+        .collect::<Vec<_>>();
+    if parents.is_empty() {
+        return Err(meta
+            .span()
+            .error("Expected at least one parent struct")
+            .help(
+                "e.g. `#[diesel(belongs_to_poly(Post, Video, \
+             type_column = \"commentable_type\", id_column = \"commentable_id\"))]`",
+            ));
+    }
+    let type_column = meta.required_nested_item("type_column")?.ident_value()?;
+    let id_column = meta.required_nested_item("id_column")?.ident_value()?;
+
+    let type_field = model.find_column(&type_column)?;
+    let type_field_access = type_field.name.access();
+    model.find_column(&id_column)?;
+
+    let enum_base = type_column.to_string();
+    let enum_base = enum_base.strip_suffix("_type").unwrap_or(&enum_base);
+    let camel_case_base = snake_to_camel(enum_base, true);
+    if camel_case_base.is_empty() {
+        return Err(type_column
+            .span()
+            .error(format!(
+                "Cannot derive an enum name from the type column `{}`",
+                type_column,
+            ))
+            .help("Use a type column named like `commentable_type`"));
+    }
+    let enum_name = syn::Ident::new(&camel_case_base, type_column.span());
+    let accessor_name = syn::Ident::new(enum_base, type_column.span());
+
+    let variants = parents
+        .iter()
+        .map(|parent| {
+            parent
+                .segments
+                .last()
+                .expect("paths always have at least one segment")
+                .ident
+                .clone()
+        })
+        .collect::<Vec<_>>();
+    let variant_strings = variants.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+    let table_name = model.table_name();
+    let struct_name = &model.name;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    Ok(wrap_in_dummy_mod(quote!(#(#tokens)*)))
+    let enum_doc = format!(
+        "The possible parent types of a `{}`, as stored in its `{}` column",
+        struct_name, type_column,
+    );
+    let accessor_doc = format!(
+        "Returns the parent type of `self`, or `None` if its `{}` column \
+         holds an unknown value",
+        type_column,
+    );
+
+    let query_fns = parents
+        .iter()
+        .zip(&variant_strings)
+        .map(|(parent, variant)| {
+            let fn_name = syn::Ident::new(
+                &format!("belonging_to_{}", camel_to_snake(variant)),
+                meta.span(),
+            );
+            let fn_doc = format!(
+                "Returns a query loading the `{}` belonging to the given `{}`\n\n\
+             The query is returned in boxed form, so further conditions \
+             can be chained onto it.",
+                table_name
+                    .segments
+                    .last()
+                    .expect("paths always have at least one segment")
+                    .ident,
+                variant,
+            );
+            quote! {
+                #[doc = #fn_doc]
+                pub fn #fn_name<'__a, __ST, __FK, __DB>(
+                    parent: &'__a #parent,
+                ) -> #table_name::BoxedQuery<'__a, __DB>
+                where
+                    __DB: diesel::backend::Backend,
+                    __FK: '__a,
+                    for<'__b> &'__b #parent:
+                        diesel::associations::Identifiable<Id = &'__b __FK>,
+                    __ST: diesel::sql_types::SqlType + diesel::sql_types::SingleValue,
+                    #table_name::#id_column: diesel::Expression<SqlType = __ST>,
+                    &'__a __FK: diesel::expression::AsExpression<__ST>,
+                    #table_name::table: diesel::query_dsl::methods::BoxedDsl<
+                        '__a,
+                        __DB,
+                        Output = #table_name::BoxedQuery<'__a, __DB>,
+                    >,
+                    #table_name::BoxedQuery<'__a, __DB>: diesel::query_dsl::methods::FilterDsl<
+                        diesel::dsl::Eq<#table_name::#type_column, &'static str>,
+                        Output = #table_name::BoxedQuery<'__a, __DB>,
+                    >,
+                    #table_name::BoxedQuery<'__a, __DB>: diesel::query_dsl::methods::FilterDsl<
+                        diesel::dsl::Eq<#table_name::#id_column, &'__a __FK>,
+                        Output = #table_name::BoxedQuery<'__a, __DB>,
+                    >,
+                {
+                    use diesel::{ExpressionMethods, QueryDsl};
+
+                    #table_name::table
+                        .into_boxed()
+                        .filter(#table_name::#type_column.eq(#variant))
+                        .filter(#table_name::#id_column.eq(
+                            diesel::associations::Identifiable::id(parent),
+                        ))
+                }
+            }
+        });
+
+    Ok(quote! {
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum #enum_name {
+            #(#variants,)*
+        }
+
+        impl #enum_name {
+            /// Returns the value stored in the type column for this parent type
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    #(#enum_name::#variants => #variant_strings,)*
+                }
+            }
+
+            /// Parses a value of the type column, returning `None` for
+            /// unknown values
+            pub fn from_str(value: &str) -> std::option::Option<Self> {
+                match value {
+                    #(#variant_strings => std::option::Option::Some(#enum_name::#variants),)*
+                    _ => std::option::Option::None,
+                }
+            }
+        }
+
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[doc = #accessor_doc]
+            pub fn #accessor_name(&self) -> std::option::Option<#enum_name> {
+                #enum_name::from_str(&self#type_field_access)
+            }
+
+            #(#query_fns)*
+        }
+    })
+}
+
+fn derive_has_many(
+    model: &Model,
+    generics: &syn::Generics,
+    meta: MetaItem,
+) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let HasManyOptions {
+        child_struct,
+        foreign_key,
+        table_name,
+    } = HasManyOptions::from_meta(&meta)?;
+    let foreign_key = foreign_key.unwrap_or_else(|| infer_foreign_key(&model.name));
+
+    let child_table = table_name.unwrap_or_else(|| {
+        let child_name = &child_struct
+            .segments
+            .last()
+            .expect("paths always have at least one segment")
+            .ident;
+        syn::Ident::new(
+            &infer_table_name(&child_name.to_string()),
+            child_name.span(),
+        )
+        .into()
+    });
+    let method_name = child_table
+        .segments
+        .last()
+        .expect("paths always have at least one segment")
+        .ident
+        .clone();
+
+    if model.primary_key_names.len() != 1 {
+        return Err(meta
+            .span()
+            .error("has_many requires a primary key consisting of a single column"));
+    }
+    let primary_key_field = model.find_column(&model.primary_key_names[0])?;
+    let primary_key_access = primary_key_field.name.access();
+    let primary_key_ty = &primary_key_field.ty;
+
+    let struct_name = &model.name;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let doc = format!(
+        "Returns a query loading the `{}` belonging to `self`\n\n\
+         The query is returned in boxed form, so further conditions \
+         can be chained onto it.",
+        method_name,
+    );
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[doc = #doc]
+            pub fn #method_name<'__a, __DB>(&'__a self) -> #child_table::BoxedQuery<'__a, __DB>
+            where
+                __DB: diesel::backend::Backend,
+                #child_table::table: diesel::query_dsl::methods::BoxedDsl<
+                    '__a,
+                    __DB,
+                    Output = #child_table::BoxedQuery<'__a, __DB>,
+                >,
+                #child_table::BoxedQuery<'__a, __DB>: diesel::query_dsl::methods::FilterDsl<
+                    diesel::dsl::Eq<#child_table::#foreign_key, &'__a #primary_key_ty>,
+                    Output = #child_table::BoxedQuery<'__a, __DB>,
+                >,
+            {
+                use diesel::{ExpressionMethods, QueryDsl};
+
+                #child_table::table
+                    .into_boxed()
+                    .filter(#child_table::#foreign_key.eq(&self#primary_key_access))
+            }
+        }
+    })
+}
+
+fn derive_foreign_key_joins(model: &Model) -> Vec<proc_macro2::TokenStream> {
+    model
+        .fields()
+        .iter()
+        .filter_map(|field| {
+            let meta = match field.flags.nested_item("foreign_key") {
+                Ok(m) => m?,
+                Err(e) => {
+                    e.emit();
+                    return None;
+                }
+            };
+            match derive_foreign_key_join(model, field, &meta) {
+                Ok(t) => Some(t),
+                Err(e) => {
+                    e.emit();
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+fn derive_foreign_key_join(
+    model: &Model,
+    field: &Field,
+    meta: &MetaItem,
+) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let referenced = meta.path_value()?;
+    if referenced.segments.len() < 2 {
+        return Err(meta.span().error(
+            "Expected a fully qualified column, e.g. \
+             `#[diesel(foreign_key = \"users::id\")]`",
+        ));
+    }
+    let (parent_table, parent_column) = split_column_reference(&referenced);
+    let child_table = model.table_name();
+    let foreign_key = field.column_name_ident();
+
+    Ok(quote! {
+        diesel::joinable_inner!(
+            left_table_ty = #child_table::table,
+            right_table_ty = #parent_table::table,
+            right_table_expr = #parent_table::table,
+            foreign_key = #child_table::#foreign_key,
+            primary_key_ty = #parent_table::#parent_column,
+            primary_key_expr = #parent_table::#parent_column,
+        );
+        diesel::joinable_inner!(
+            left_table_ty = #parent_table::table,
+            right_table_ty = #child_table::table,
+            right_table_expr = #child_table::table,
+            foreign_key = #child_table::#foreign_key,
+            primary_key_ty = #parent_table::#parent_column,
+            primary_key_expr = #parent_table::#parent_column,
+        );
+
+        impl diesel::query_source::AppearsInFromClause<#parent_table::table>
+            for #child_table::table
+        {
+            type Count = diesel::query_source::Never;
+        }
+
+        impl diesel::query_source::AppearsInFromClause<#child_table::table>
+            for #parent_table::table
+        {
+            type Count = diesel::query_source::Never;
+        }
+    })
 }
 
 fn derive_belongs_to(
@@ -37,11 +377,17 @@ fn derive_belongs_to(
     } = AssociationOptions::from_meta(meta)?;
     let (_, ty_generics, _) = generics.split_for_impl();
 
-    let foreign_key_field = model.find_column(&foreign_key)?;
+    let (foreign_key_table, foreign_key_column) = if foreign_key.segments.len() == 1 {
+        let column = foreign_key.segments.first().unwrap().ident.clone();
+        (model.table_name(), column)
+    } else {
+        split_column_reference(&foreign_key)
+    };
+
+    let foreign_key_field = model.find_column(&field_name_for_column_reference(&foreign_key))?;
     let struct_name = &model.name;
     let foreign_key_access = foreign_key_field.name.access();
     let foreign_key_ty = &foreign_key_field.ty;
-    let table_name = model.table_name();
 
     let mut generics = generics.clone();
 
@@ -78,22 +424,59 @@ fn derive_belongs_to(
         #where_clause
         {
             type ForeignKey = #foreign_key_ty;
-            type ForeignKeyColumn = #table_name::#foreign_key;
+            type ForeignKeyColumn = #foreign_key_table::#foreign_key_column;
 
             fn foreign_key(&self) -> std::option::Option<&Self::ForeignKey> {
                 #foreign_key_expr
             }
 
             fn foreign_key_column() -> Self::ForeignKeyColumn {
-                #table_name::#foreign_key
+                #foreign_key_table::#foreign_key_column
             }
         }
     })
 }
 
+struct HasManyOptions {
+    child_struct: syn::Path,
+    foreign_key: Option<syn::Ident>,
+    table_name: Option<syn::Path>,
+}
+
+impl HasManyOptions {
+    fn from_meta(meta: &MetaItem) -> Result<Self, Diagnostic> {
+        let child_struct = meta
+            .nested()?
+            .find(|m| m.path().is_ok())
+            .ok_or_else(|| {
+                meta.span()
+                    .error("Expected a struct name")
+                    .help("e.g. `#[diesel(has_many(Post))]`")
+            })
+            .and_then(|m| {
+                m.path()
+                    .map_err(|_| m.span().error("Expected a struct name"))
+            })?;
+        let foreign_key = meta
+            .nested_item("foreign_key")?
+            .map(|i| i.ident_value())
+            .transpose()?;
+        let table_name = meta
+            .nested_item("table_name")?
+            .map(|i| i.path_value())
+            .transpose()?;
+
+        Ok(Self {
+            child_struct,
+            foreign_key,
+            table_name,
+        })
+    }
+}
+
 struct AssociationOptions {
     parent_struct: syn::TypePath,
-    foreign_key: syn::Ident,
+    foreign_key: syn::Path,
 }
 
 impl AssociationOptions {
@@ -123,8 +506,8 @@ impl AssociationOptions {
                 .last()
                 .expect("paths always have at least one segment");
             meta.nested_item("foreign_key")?
-                .map(|i| i.ident_value())
-                .unwrap_or_else(|| Ok(infer_foreign_key(&parent_struct_name.ident)))?
+                .map(|i| i.path_value())
+                .unwrap_or_else(|| Ok(infer_foreign_key(&parent_struct_name.ident).into()))?
         };
 
         let (unrecognized_paths, unrecognized_options): (Vec<_>, Vec<_>) = meta
@@ -176,6 +559,18 @@ impl AssociationOptions {
     }
 }
 
+/// Splits a qualified column reference like `users::id` into the path of
+/// the table (`users`) and the column name (`id`)
+fn split_column_reference(reference: &syn::Path) -> (syn::Path, syn::Ident) {
+    let column = reference.segments.last().unwrap().ident.clone();
+    let mut table = reference.clone();
+    table.segments.pop();
+    if let Some(last) = table.segments.pop() {
+        table.segments.push_value(last.into_value());
+    }
+    (table, column)
+}
+
 fn infer_foreign_key(name: &syn::Ident) -> syn::Ident {
     let snake_case = camel_to_snake(&name.to_string());
     syn::Ident::new(&format!("{}_id", snake_case), name.span())
@@ -14,10 +14,17 @@ pub struct Field {
     pub sql_type: Option<syn::Type>,
     pub flags: MetaItem,
     column_name_from_attribute: Option<MetaItem>,
+    column_prefix: Option<String>,
+    rename_all: Option<RenameRule>,
 }
 
 impl Field {
-    pub fn from_struct_field(field: &syn::Field, index: usize) -> Self {
+    pub fn from_struct_field(
+        field: &syn::Field,
+        index: usize,
+        column_prefix: Option<&str>,
+        rename_all: Option<RenameRule>,
+    ) -> Self {
         let column_name_from_attribute = MetaItem::with_name(&field.attrs, "column_name");
         let name = match field.ident.clone() {
             Some(mut x) => {
@@ -45,6 +52,21 @@ impl Field {
             sql_type,
             flags,
             span,
+            column_prefix: column_prefix.map(String::from),
+            rename_all,
+        }
+    }
+
+    /// The column name of this field without an explicit `#[column_name]`,
+    /// with the column prefix and rename rule of the struct applied
+    fn default_column_name(&self, field_name: &syn::Ident) -> String {
+        let name = match self.column_prefix {
+            Some(ref prefix) => format!("{}{}", prefix, field_name),
+            None => field_name.to_string(),
+        };
+        match self.rename_all {
+            Some(rule) => rule.apply(&name),
+            None => name,
         }
     }
 
@@ -53,7 +75,13 @@ impl Field {
             .as_ref()
             .map(|m| m.expect_ident_value())
             .unwrap_or_else(|| match self.name {
-                FieldName::Named(ref x) => x.clone(),
+                FieldName::Named(ref x) => {
+                    if self.column_prefix.is_none() && self.rename_all.is_none() {
+                        x.clone()
+                    } else {
+                        Ident::new(&self.default_column_name(x), x.span())
+                    }
+                }
                 _ => {
                     self.span
                         .error(
@@ -75,7 +103,7 @@ impl Field {
                 })
             })
             .unwrap_or_else(|| match self.name {
-                FieldName::Named(ref x) => x.to_string(),
+                FieldName::Named(ref x) => self.default_column_name(x),
                 _ => {
                     self.span
                         .error(
@@ -145,3 +173,52 @@ impl ToTokens for FieldName {
         }
     }
 }
+
+/// A naming convention the `snake_case` field names of a struct are mapped
+/// to, set via `#[diesel(rename_all = "...")]`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RenameRule {
+    CamelCase,
+    PascalCase,
+    ScreamingSnakeCase,
+}
+
+impl RenameRule {
+    pub fn from_meta(meta: &MetaItem) -> Result<Self, Diagnostic> {
+        let value = meta.str_value()?;
+        match &*value {
+            "camelCase" => Ok(RenameRule::CamelCase),
+            "PascalCase" => Ok(RenameRule::PascalCase),
+            "SCREAMING_SNAKE_CASE" => Ok(RenameRule::ScreamingSnakeCase),
+            _ => Err(meta.span().error(format!(
+                "Invalid value `{}` for `rename_all`. \
+                 Expected `camelCase`, `PascalCase` or `SCREAMING_SNAKE_CASE`",
+                value,
+            ))),
+        }
+    }
+
+    pub fn apply(&self, name: &str) -> String {
+        match *self {
+            RenameRule::CamelCase => snake_to_camel(name, false),
+            RenameRule::PascalCase => snake_to_camel(name, true),
+            RenameRule::ScreamingSnakeCase => name.to_uppercase(),
+        }
+    }
+}
+
+pub fn snake_to_camel(name: &str, capitalize_first: bool) -> String {
+    let mut result = String::with_capacity(name.len());
+    let mut capitalize = capitalize_first;
+    for character in name.chars() {
+        if character == '_' {
+            capitalize = true;
+        } else if capitalize {
+            result.extend(character.to_uppercase());
+            capitalize = false;
+        } else {
+            result.push(character);
+        }
+    }
+    result
+}
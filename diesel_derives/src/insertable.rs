@@ -12,9 +12,13 @@ use crate::meta::MetaItem;
 pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagnostic> {
     let treat_none_as_default_value = MetaItem::with_name(&item.attrs, "diesel")
         .map(|meta| {
-            meta.warn_if_other_options(&["treat_none_as_default_value"]);
-            meta.required_nested_item("treat_none_as_default_value")
-                .map(|m| m.expect_bool_value())
+            meta.warn_if_other_options(&[
+                "treat_none_as_default_value",
+                "column_prefix",
+                "rename_all",
+            ]);
+            meta.nested_item("treat_none_as_default_value")
+                .map(|m| m.map(|m| m.expect_bool_value()).unwrap_or(true))
         })
         .unwrap_or(Ok(true))?;
 
@@ -45,6 +49,9 @@ pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagno
     let mut ref_field_assign = Vec::with_capacity(model.fields().len());
 
     for field in model.fields() {
+        if field.has_flag("ignore") {
+            continue;
+        }
         let serialize_as = field.ty_for_serialize()?;
         let embed = field.has_flag("embed");
 
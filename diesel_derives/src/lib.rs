@@ -34,6 +34,7 @@ mod meta;
 mod model;
 mod resolved_at_shim;
 mod util;
+mod versioned_model;
 
 mod as_changeset;
 mod as_expression;
@@ -45,10 +46,16 @@ mod insertable;
 mod query_id;
 mod queryable;
 mod queryable_by_name;
+mod scopes;
 mod selectable;
+mod soft_deletable;
 mod sql_function;
 mod sql_type;
+mod test_attribute;
+mod touchable;
 mod valid_grouping;
+#[cfg(feature = "compile-time-verify")]
+mod verified_sql;
 
 use diagnostic_shim::*;
 
@@ -80,15 +87,24 @@ use diagnostic_shim::*;
 /// current type is a changeset. The path is relative to the current module.
 /// If this attribute is not used, the type name converted to
 /// `snake_case` with an added `s` is used as table name.
+/// * `#[diesel(column_prefix = "some_prefix_")]`, prepends `some_prefix_`
+/// to the column name of every field. `#[column_name]` on a field takes
+/// precedence and is used as given.
+/// * `#[diesel(rename_all = "camelCase")]`, maps the `snake_case` field
+/// names to the given naming convention. `camelCase`, `PascalCase` and
+/// `SCREAMING_SNAKE_CASE` are supported. `#[column_name]` on a field
+/// takes precedence and is used as given.
 ///
 /// ## Optional field attributes
 ///
 /// * `#[column_name = "some_column_name"]`, overrides the column name
 /// of the current field to `some_column_name`. By default the field
 /// name is used as column name.
+/// * `#[diesel(ignore)]`, specifies that the current field is not a
+/// database column and should be skipped entirely by this derive
 #[proc_macro_derive(
     AsChangeset,
-    attributes(table_name, primary_key, column_name, changeset_options)
+    attributes(table_name, primary_key, column_name, changeset_options, diesel)
 )]
 pub fn derive_as_changeset(input: TokenStream) -> TokenStream {
     expand_proc_macro(input, as_changeset::derive)
@@ -144,6 +160,14 @@ pub fn derive_as_expression(input: TokenStream) -> TokenStream {
 /// is not specified explicitly, the remote lower case type name with an
 /// appended `_id` is used as foreign key name. (`user_id` in this example
 /// case)
+/// * `#[belongs_to(User, foreign_key = "posts::user_id")]`, variant of the
+/// attribute above with a qualified column reference, for models which
+/// store columns of several tables, such as join results or junction
+/// models. The foreign key column then belongs to the given table instead
+/// of the table of the current type, and the reference resolves to the
+/// struct field named after the singular table name and the column
+/// (`post_user_id` in this example case), like for
+/// `#[diesel(primary_key)]` on `#[derive(Identifiable)]`
 ///
 /// # Optional container attributes
 ///
@@ -151,14 +175,39 @@ pub fn derive_as_expression(input: TokenStream) -> TokenStream {
 ///    type belongs to. The path is relative to the current module.
 ///    If this attribute is not used, the type name converted to
 ///    `snake_case` with an added `s` is used as table name.
+/// * `#[diesel(has_many(Post))]`, generates a method on the current type
+/// named after the child table (`posts()` in this example case), which
+/// returns a boxed query loading the children belonging to `self`. The
+/// child table is inferred from the child type name by the usual
+/// pluralization rules, or can be given via a nested
+/// `table_name = "path::to::table"`. The foreign key on the child table
+/// defaults to the lower case name of the current type with an appended
+/// `_id` and can be overridden with a nested `foreign_key = "mykey"`.
+/// * `#[diesel(belongs_to_poly(Post, Video, type_column = "commentable_type",
+/// id_column = "commentable_id"))]`, declares a polymorphic association,
+/// where the parent is one of several types and the type column stores
+/// which one (by the parent type name). This generates an enum named after
+/// the type column with the `_type` suffix stripped (`Commentable` in this
+/// example case) listing the possible parent types, an accessor of the
+/// same name returning the parsed type column, and a
+/// `belonging_to_post(&post)`/`belonging_to_video(&video)` constructor per
+/// parent returning a boxed query loading the children of that parent.
 ///
 /// # Optional field attributes
 ///
 /// * `#[column_name = "some_column_name"]`, overrides the column the current
 /// field maps to to `some_column_name`. By default the field name is used
 /// as column name. Only useful for the foreign key field.
-///
-#[proc_macro_derive(Associations, attributes(belongs_to, column_name, table_name))]
+/// * `#[diesel(foreign_key = "other_table::id")]`, marks the current field
+/// as a foreign key pointing to the given column of another table. This
+/// generates the same code as calling
+/// `joinable!(current_table -> other_table (field_name))` and
+/// `allow_tables_to_appear_in_same_query!(current_table, other_table)`,
+/// so the two tables can be joined without invoking those macros by hand.
+/// Remove any manual invocations for the same pair of tables when adding
+/// this attribute, as the generated impls would otherwise conflict.
+///
+#[proc_macro_derive(Associations, attributes(belongs_to, column_name, table_name, diesel))]
 pub fn derive_associations(input: TokenStream) -> TokenStream {
     expand_proc_macro(input, associations::derive)
 }
@@ -174,6 +223,11 @@ pub fn derive_diesel_numeric_ops(input: TokenStream) -> TokenStream {
 /// This derive is mostly useful to implement support deserializing
 /// into rust types not supported by diesel itself.
 ///
+/// Types using this derive are deserialized from a single column. They
+/// compose with the existing tuple impls, so a subset of a query's columns
+/// can be loaded as e.g. `(i32, String, YourType)` with each element
+/// delegating to its own `FromSql` impl.
+///
 /// There are no options or special considerations needed for this derive.
 #[proc_macro_derive(FromSqlRow, attributes(diesel))]
 pub fn derive_from_sql_row(input: TokenStream) -> TokenStream {
@@ -206,11 +260,83 @@ pub fn derive_from_sql_row(input: TokenStream) -> TokenStream {
 /// * `#[primary_key(id1, id2)]` to specify the struct field that
 ///    that corresponds to the primary key. If not used, `id` will be
 ///    assumed as primary key field
-#[proc_macro_derive(Identifiable, attributes(table_name, primary_key, column_name))]
+/// * `#[diesel(primary_key(table1::id, table2::id))]` to specify a compound
+///    primary key via qualified column references, for models which store
+///    columns of several tables, such as join results or junction models.
+///    A qualified reference like `users::id` resolves to the struct field
+///    named after the singular table name and the column, e.g. `user_id`.
+///    Table names are singularized by the inverse of the pluralization
+///    rules used to infer table names
+#[proc_macro_derive(Identifiable, attributes(table_name, primary_key, column_name, diesel))]
 pub fn derive_identifiable(input: TokenStream) -> TokenStream {
     expand_proc_macro(input, identifiable::derive)
 }
 
+/// Implements named query scopes
+///
+/// For every `#[diesel(scope(name = "expression"))]` attribute this derive
+/// adds an associated `name()` function to the type, returning a boxed
+/// query over the table the type belongs to, pre-filtered by the given
+/// expression. The expression is parsed as ordinary Rust code with the
+/// diesel prelude in scope, and bare identifiers in it are resolved as
+/// columns of the table. As the query is boxed, scopes can be
+/// freely combined with further conditions via `.filter(...)`. The table
+/// is inferred from the type name, or can be given via
+/// `#[table_name = "..."]` like for `#[derive(Identifiable)]`.
+///
+/// Several scopes may be given, either as separate attributes or as
+/// multiple entries in a single `scope(...)`:
+///
+/// ```ignore
+/// #[derive(Scopes)]
+/// #[diesel(scope(active = r#"status.eq("active")"#))]
+/// #[diesel(scope(adults = "age.ge(18)"))]
+/// pub struct User;
+/// ```
+#[proc_macro_derive(Scopes, attributes(table_name, column_name, diesel))]
+pub fn derive_scopes(input: TokenStream) -> TokenStream {
+    expand_proc_macro(input, scopes::derive)
+}
+
+/// Implements [`SoftDeletable`]
+///
+/// The derive requires the struct to have a `deleted_at` field whose
+/// corresponding column is a nullable timestamp. The table is inferred
+/// from the type name, or can be given via `#[table_name = "..."]` like
+/// for `#[derive(Identifiable)]`.
+///
+/// [`SoftDeletable`]: trait.SoftDeletable.html
+#[proc_macro_derive(SoftDeletable, attributes(table_name, column_name))]
+pub fn derive_soft_deletable(input: TokenStream) -> TokenStream {
+    expand_proc_macro(input, soft_deletable::derive)
+}
+
+/// Implements [`Touchable`]
+///
+/// The derive requires the struct to have an `updated_at` field whose
+/// corresponding column is a timestamp. The table is inferred from the
+/// type name, or can be given via `#[table_name = "..."]` like for
+/// `#[derive(Identifiable)]`.
+///
+/// [`Touchable`]: trait.Touchable.html
+#[proc_macro_derive(Touchable, attributes(table_name, column_name))]
+pub fn derive_touchable(input: TokenStream) -> TokenStream {
+    expand_proc_macro(input, touchable::derive)
+}
+
+/// Implements [`VersionedModel`]
+///
+/// The derive requires the struct to have a `version: i32` field whose
+/// corresponding column is an integer. The table is inferred from the
+/// type name, or can be given via `#[table_name = "..."]` like for
+/// `#[derive(Identifiable)]`.
+///
+/// [`VersionedModel`]: trait.VersionedModel.html
+#[proc_macro_derive(VersionedModel, attributes(table_name, column_name))]
+pub fn derive_versioned_model(input: TokenStream) -> TokenStream {
+    expand_proc_macro(input, versioned_model::derive)
+}
+
 /// Implements `Insertable`
 ///
 /// To implement `Insertable` this derive needs to know the corresponding table
@@ -251,6 +377,13 @@ pub fn derive_identifiable(input: TokenStream) -> TokenStream {
 /// should be converted to `NULL` values on SQL side or treated as `DEFAULT` value primitive
 /// *Note: This option may control if your query is stored in the
 /// prepared statement cache or not*
+/// * `#[diesel(column_prefix = "some_prefix_")]`, prepends `some_prefix_`
+/// to the column name of every field. `#[column_name]` on a field takes
+/// precedence and is used as given.
+/// * `#[diesel(rename_all = "camelCase")]`, maps the `snake_case` field
+/// names to the given naming convention. `camelCase`, `PascalCase` and
+/// `SCREAMING_SNAKE_CASE` are supported. `#[column_name]` on a field
+/// takes precedence and is used as given.
 ///
 /// ## Optional field attributes
 ///
@@ -263,6 +396,8 @@ pub fn derive_identifiable(input: TokenStream) -> TokenStream {
 /// field type, Diesel will convert the field into `SomeType` using `.into` and
 /// serialize that instead. By default this derive will serialize directly using
 /// the actual field type.
+/// * `#[diesel(ignore)]`, specifies that the current field is not a
+/// database column and should be skipped entirely by this derive
 ///
 /// # Examples
 ///
@@ -413,6 +548,9 @@ pub fn derive_query_id(input: TokenStream) -> TokenStream {
 ///   Then `Type` is converted via
 ///   [`.try_into`](https://doc.rust-lang.org/stable/std/convert/trait.TryInto.html#tymethod.try_into)
 ///   into the field type. By default this derive will deserialize directly into the field type
+/// * `#[diesel(ignore)]`, specifies that the current field is not a
+///   database column and is not part of the queried row. The field is
+///   initialized via `Default::default()` instead
 ///
 /// # Examples
 ///
@@ -498,6 +636,36 @@ pub fn derive_query_id(input: TokenStream) -> TokenStream {
 /// # }
 /// ```
 ///
+/// If our struct mixes database backed and computed fields, we can skip
+/// the latter with `#[diesel(ignore)]`.
+///
+/// ```rust
+/// # extern crate diesel;
+/// # extern crate dotenv;
+/// # include!("../../diesel/src/doctest_setup.rs");
+/// #
+/// #[derive(Queryable, PartialEq, Debug)]
+/// struct User {
+///     id: i32,
+///     name: String,
+///     #[diesel(ignore)]
+///     greeting: Option<String>,
+/// }
+///
+/// # fn main() {
+/// #     run_test();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::users::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let first_user: User = users.first(connection)?;
+/// let expected = User { id: 1, name: "Sean".into(), greeting: None };
+/// assert_eq!(expected, first_user);
+/// #     Ok(())
+/// # }
+/// ```
+///
 /// Alternatively, we can implement the trait for our struct manually.
 ///
 /// ```rust
@@ -582,6 +750,13 @@ pub fn derive_queryable(input: TokenStream) -> TokenStream {
 ///   columns for the specified table. The path is relative to the current module.
 ///   If no field attributes are specified the derive will use the sql type of
 ///   the corresponding column.
+/// * `#[diesel(column_prefix = "some_prefix_")]`, prepends `some_prefix_`
+///   to the column name of every field. `#[column_name]` on a field takes
+///   precedence and is used as given.
+/// * `#[diesel(rename_all = "camelCase")]`, maps the `snake_case` field
+///   names to the given naming convention. `camelCase`, `PascalCase` and
+///   `SCREAMING_SNAKE_CASE` are supported. `#[column_name]` on a field
+///   takes precedence and is used as given.
 ///
 /// ## Field attributes
 ///
@@ -599,6 +774,14 @@ pub fn derive_queryable(input: TokenStream) -> TokenStream {
 /// * `#[diesel(embed)]`, specifies that the current field maps not only
 ///   single database column, but is a type that implements
 ///   `QueryableByName` on it's own
+/// * `#[diesel(ignore)]`, specifies that the current field is not a
+///   database column and is not part of the queried row. The field is
+///   initialized via `Default::default()` instead
+/// * `#[diesel(table_name = "some_table")]`, qualifies the expected column
+///   name with the given table, so the field is looked up as
+///   `some_table.column_name`. This disambiguates columns with the same
+///   name coming from different tables of a join, and the sql type is
+///   taken from the given table instead of the type level `#[table_name]`
 ///
 /// # Examples
 ///
@@ -815,6 +998,19 @@ pub fn derive_selectable(input: TokenStream) -> TokenStream {
 /// * `#[mysql_type = "TypeName"]`, specifies support for a mysql type
 /// with the given name. `TypeName` needs to be one of the possible values
 /// in `MysqlType`
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate diesel;
+/// # use diesel::sql_types::SqlType;
+/// # use diesel::query_builder::QueryId;
+/// #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+/// #[cfg_attr(feature = "postgres", postgres(type_name = "my_custom_type"))]
+/// #[cfg_attr(feature = "sqlite", sqlite_type = "Text")]
+/// #[cfg_attr(feature = "mysql", mysql_type = "String")]
+/// pub struct MyCustomType;
+/// ```
 #[proc_macro_derive(SqlType, attributes(postgres, sqlite_type, mysql_type))]
 pub fn derive_sql_type(input: TokenStream) -> TokenStream {
     expand_proc_macro(input, sql_type::derive)
@@ -1207,6 +1403,62 @@ pub fn sql_function_proc(input: TokenStream) -> TokenStream {
     expand_proc_macro(input, sql_function::expand)
 }
 
+/// Checks a raw SQL statement against a live database at compile time
+///
+/// The macro sends `PREPARE` for the given statement to a PostgreSQL
+/// database, so syntax errors and references to missing tables or columns
+/// are reported while compiling instead of at runtime. It expands to the
+/// given string literal, so it can be used anywhere a `&str` with SQL is
+/// expected:
+///
+/// ```ignore
+/// diesel::sql_query(verified_sql!("SELECT id, name FROM users WHERE id = $1"))
+/// ```
+///
+/// The database to check against is taken from the `DATABASE_URL`
+/// environment variable at compile time, or from an explicit
+/// `conn_url = "..."` given as first argument. If no database url is
+/// available the check is skipped with a warning, so that builds without
+/// database access keep working.
+///
+/// This macro is only available when the `compile-time-verify` feature is
+/// enabled.
+#[cfg(feature = "compile-time-verify")]
+#[proc_macro]
+pub fn verified_sql(input: TokenStream) -> TokenStream {
+    expand_proc_macro(input, verified_sql::expand)
+}
+
+/// Wraps a test function in a database transaction which is rolled back
+///
+/// The annotated function must take exactly one argument of the form
+/// `conn: &mut SomeConnection`. The macro generates a `#[test]` function
+/// which establishes a connection to the database given by the
+/// `DATABASE_URL` environment variable, starts a test transaction which
+/// is never committed, and passes the connection to the annotated
+/// function. Transactions opened inside the test become savepoints, so
+/// nested transactions behave as usual while everything is still rolled
+/// back when the test finishes.
+///
+/// This macro is exported from diesel as `diesel::testing::test`.
+/// It is deliberately not named `test` here, as that would make the
+/// built-in `#[test]` attribute ambiguous for anyone writing
+/// `use diesel::*;`.
+///
+/// ```ignore
+/// #[diesel::testing::test]
+/// fn inserts_user(conn: &mut PgConnection) {
+///     diesel::insert_into(users::table)
+///         .values(users::name.eq("Sean"))
+///         .execute(conn)
+///         .unwrap();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn test_proc(_attr: TokenStream, input: TokenStream) -> TokenStream {
+    expand_proc_macro(input, test_attribute::expand)
+}
+
 fn expand_proc_macro<T: syn::parse::Parse>(
     input: TokenStream,
     f: fn(T) -> Result<proc_macro2::TokenStream, Diagnostic>,
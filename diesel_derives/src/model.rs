@@ -18,14 +18,33 @@ impl Model {
         let table_name_from_attribute = MetaItem::with_name(&item.attrs, "table_name")
             .map(|m| m.path_value())
             .transpose()?;
-        let primary_key_names = MetaItem::with_name(&item.attrs, "primary_key")
+        let primary_key_meta = match MetaItem::with_name(&item.attrs, "primary_key") {
+            Some(m) => Some(m),
+            None => MetaItem::with_name(&item.attrs, "diesel")
+                .map(|m| m.nested_item("primary_key"))
+                .transpose()?
+                .and_then(|m| m),
+        };
+        let primary_key_names = primary_key_meta
             .map(|m| {
                 Ok(m.nested()?
-                    .map(|m| m.expect_path().segments.first().unwrap().ident.clone())
+                    .map(|m| field_name_for_column_reference(&m.expect_path()))
                     .collect())
             })
             .unwrap_or_else(|| Ok(vec![Ident::new("id", Span::call_site())]))?;
-        let fields = fields_from_item_data(&item.data)?;
+        let column_prefix = MetaItem::with_name(&item.attrs, "diesel")
+            .map(|m| m.nested_item("column_prefix"))
+            .transpose()?
+            .and_then(|m| m)
+            .map(|m| m.str_value())
+            .transpose()?;
+        let rename_all = MetaItem::with_name(&item.attrs, "diesel")
+            .map(|m| m.nested_item("rename_all"))
+            .transpose()?
+            .and_then(|m| m)
+            .map(|m| RenameRule::from_meta(&m))
+            .transpose()?;
+        let fields = fields_from_item_data(&item.data, column_prefix.as_deref(), rename_all)?;
         Ok(Self {
             name: item.ident.clone(),
             table_name_from_attribute,
@@ -80,13 +99,36 @@ pub fn camel_to_snake(name: &str) -> String {
     result
 }
 
-fn infer_table_name(name: &str) -> String {
+/// Resolves a column reference to the name of the struct field storing it.
+///
+/// Unqualified references like `foo_id` name the field directly. Qualified
+/// references like `users::id` name a column of another table, as stored in a
+/// join result or junction model, and resolve to the field named after the
+/// singular table name and the column, e.g. `user_id`. Table names are
+/// singularized by the inverse of the pluralization rules used by
+/// `infer_table_name`.
+pub fn field_name_for_column_reference(path: &syn::Path) -> Ident {
+    if path.segments.len() == 1 {
+        path.segments.first().unwrap().ident.clone()
+    } else {
+        let table = path.segments.first().unwrap().ident.to_string();
+        let table = table.strip_suffix('s').unwrap_or(&table);
+        let column = &path.segments.last().unwrap().ident;
+        Ident::new(&format!("{}_{}", table, column), column.span())
+    }
+}
+
+pub fn infer_table_name(name: &str) -> String {
     let mut result = camel_to_snake(name);
     result.push('s');
     result
 }
 
-fn fields_from_item_data(data: &syn::Data) -> Result<Vec<Field>, Diagnostic> {
+fn fields_from_item_data(
+    data: &syn::Data,
+    column_prefix: Option<&str>,
+    rename_all: Option<RenameRule>,
+) -> Result<Vec<Field>, Diagnostic> {
     use syn::Data::*;
 
     let struct_data = match *data {
@@ -97,7 +139,7 @@ fn fields_from_item_data(data: &syn::Data) -> Result<Vec<Field>, Diagnostic> {
         .fields
         .iter()
         .enumerate()
-        .map(|(i, f)| Field::from_struct_field(f, i))
+        .map(|(i, f)| Field::from_struct_field(f, i, column_prefix, rename_all))
         .collect())
 }
 
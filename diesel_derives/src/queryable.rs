@@ -9,17 +9,32 @@ pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagno
     let model = Model::from_item(&item)?;
 
     let struct_name = &item.ident;
-    let field_ty = model
+    let queryable_fields = model
         .fields()
         .iter()
-        .map(Field::ty_for_deserialize)
+        .filter(|f| !f.has_flag("ignore"))
+        .collect::<Vec<_>>();
+    let field_ty = queryable_fields
+        .iter()
+        .map(|f| Field::ty_for_deserialize(f))
         .collect::<Result<Vec<_>, _>>()?;
     let field_ty = &field_ty;
-    let build_expr = model.fields().iter().enumerate().map(|(i, f)| {
-        let i = syn::Index::from(i);
-        f.name.assign(parse_quote!(row.#i.try_into()?))
-    });
-    let sql_type = (0..model.fields().len())
+    let mut row_index = 0;
+    let build_expr = model
+        .fields()
+        .iter()
+        .map(|f| {
+            if f.has_flag("ignore") {
+                f.name
+                    .assign(parse_quote!(std::default::Default::default()))
+            } else {
+                let i = syn::Index::from(row_index);
+                row_index += 1;
+                f.name.assign(parse_quote!(row.#i.try_into()?))
+            }
+        })
+        .collect::<Vec<_>>();
+    let sql_type = (0..queryable_fields.len())
         .map(|i| {
             let i = syn::Ident::new(&format!("__ST{}", i), proc_macro2::Span::call_site());
             quote!(#i)
@@ -32,7 +47,7 @@ pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagno
     generics
         .params
         .push(parse_quote!(__DB: diesel::backend::Backend));
-    for id in 0..model.fields().len() {
+    for id in 0..queryable_fields.len() {
         let ident = syn::Ident::new(&format!("__ST{}", id), proc_macro2::Span::call_site());
         generics.params.push(parse_quote!(#ident));
     }
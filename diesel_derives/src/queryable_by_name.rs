@@ -18,13 +18,18 @@ pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagno
         .map(|f| {
             let field_ty = &f.ty;
 
-            if f.has_flag("embed") {
+            if f.has_flag("ignore") {
+                Ok(quote!(std::default::Default::default()))
+            } else if f.has_flag("embed") {
                 Ok(quote!(<#field_ty as QueryableByName<__DB>>::build(
                     row,
                 )?))
             } else {
                 let deserialize_ty = f.ty_for_deserialize()?;
-                let name = f.column_name_str();
+                let name = match f.flags.nested_item("table_name")? {
+                    Some(table) => format!("{}.{}", table.str_value()?, f.column_name_str()),
+                    None => f.column_name_str(),
+                };
                 Ok(quote!(
                    {
                        let field = diesel::row::NamedRow::get(row, #name)?;
@@ -42,6 +47,9 @@ pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagno
         .push(parse_quote!(__DB: diesel::backend::Backend));
 
     for field in model.fields() {
+        if field.has_flag("ignore") {
+            continue;
+        }
         let where_clause = generics.where_clause.get_or_insert(parse_quote!(where));
         let field_ty = field.ty_for_deserialize()?;
         if field.has_flag("embed") {
@@ -94,10 +102,19 @@ fn get_ident(field: &Field) -> Ident {
 fn sql_type(field: &Field, model: &Model) -> syn::Type {
     let table_name = model.table_name();
 
+    let field_table_name = field
+        .flags
+        .nested_item("table_name")
+        .unwrap_or(None)
+        .and_then(|m| m.path_value().map_err(Diagnostic::emit).ok());
+
     match field.sql_type {
         Some(ref st) => st.clone(),
         None => {
-            if model.has_table_name_attribute() {
+            if let Some(field_table_name) = field_table_name {
+                let column_name = field.column_name_ident();
+                parse_quote!(diesel::dsl::SqlTypeOf<#field_table_name::#column_name>)
+            } else if model.has_table_name_attribute() {
                 let column_name = field.column_name_ident();
                 parse_quote!(diesel::dsl::SqlTypeOf<#table_name::#column_name>)
             } else {
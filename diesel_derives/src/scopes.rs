@@ -0,0 +1,123 @@
+use proc_macro2;
+use syn;
+use syn::fold::Fold;
+use syn::spanned::Spanned;
+
+use diagnostic_shim::*;
+use meta::*;
+use model::*;
+use util::*;
+
+pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let model = Model::from_item(&item)?;
+
+    let mut methods = Vec::new();
+    let scopes = MetaItem::all_with_name(&item.attrs, "diesel")
+        .into_iter()
+        .filter_map(|m| m.nested().ok().map(Iterator::collect::<Vec<_>>))
+        .flatten()
+        .filter(|m| m.name().is_ident("scope"))
+        .collect::<Vec<_>>();
+    if scopes.is_empty() {
+        return Err(item
+            .span()
+            .error("At least one scope is required")
+            .help("e.g. `#[diesel(scope(active = \"status.eq(\\\"active\\\")\"))]`"));
+    }
+    for scope in scopes {
+        let entries = match scope.nested() {
+            Ok(n) => n.collect::<Vec<_>>(),
+            Err(e) => {
+                e.emit();
+                continue;
+            }
+        };
+        for entry in entries {
+            match derive_scope(&model, &item.generics, &entry) {
+                Ok(t) => methods.push(t),
+                Err(e) => e.emit(),
+            }
+        }
+    }
+
+    Ok(wrap_in_dummy_mod(quote!(#(#methods)*)))
+}
+
+fn derive_scope(
+    model: &Model,
+    generics: &syn::Generics,
+    entry: &MetaItem,
+) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let scope_name = entry.name();
+    let scope_name = scope_name.get_ident().cloned().ok_or_else(|| {
+        entry
+            .span()
+            .error("Expected a scope name, e.g. `scope(active = \"...\")`")
+    })?;
+    let expression_string = entry.str_value()?;
+    let predicate = syn::parse_str::<syn::Expr>(&expression_string).map_err(|e| {
+        entry
+            .span()
+            .error(format!("Invalid scope expression: {}", e))
+    })?;
+
+    let table_name = model.table_name();
+    let predicate = QualifyColumns {
+        table_name: &table_name,
+    }
+    .fold_expr(predicate);
+    let struct_name = &model.name;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let doc = format!(
+        "Returns a query for `{}` filtered by the `{}` scope (`{}`)\n\n\
+         The query is returned in boxed form, so further conditions \
+         can be chained onto it.",
+        table_name
+            .segments
+            .last()
+            .expect("paths always have at least one segment")
+            .ident,
+        scope_name,
+        expression_string,
+    );
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[doc = #doc]
+            pub fn #scope_name<'__a, __DB>() -> #table_name::BoxedQuery<'__a, __DB>
+            where
+                __DB: diesel::backend::Backend + '__a,
+                #table_name::table: diesel::query_dsl::methods::BoxedDsl<
+                    '__a,
+                    __DB,
+                    Output = #table_name::BoxedQuery<'__a, __DB>,
+                >,
+            {
+                use diesel::prelude::*;
+
+                #table_name::table.into_boxed().filter(#predicate)
+            }
+        }
+    })
+}
+
+/// Rewrites bare identifiers in a scope expression, such as `status` in
+/// `status.eq("active")`, into references to the table's columns
+struct QualifyColumns<'a> {
+    table_name: &'a syn::Path,
+}
+
+impl<'a> Fold for QualifyColumns<'a> {
+    fn fold_expr_path(&mut self, expr_path: syn::ExprPath) -> syn::ExprPath {
+        let is_bare_ident = expr_path.qself.is_none()
+            && expr_path.path.leading_colon.is_none()
+            && expr_path.path.segments.len() == 1;
+        if is_bare_ident {
+            let table_name = self.table_name;
+            let column = &expr_path.path.segments[0];
+            parse_quote!(#table_name::#column)
+        } else {
+            expr_path
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use proc_macro2;
+use syn;
+
+use model::*;
+use util::*;
+
+pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let model = Model::from_item(&item)?;
+    let struct_name = &model.name;
+    let table_name = model.table_name();
+
+    let deleted_at = syn::Ident::new("deleted_at", proc_macro2::Span::call_site());
+    model.find_column(&deleted_at)?;
+
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    Ok(wrap_in_dummy_mod(quote! {
+        use diesel::soft_deletes::SoftDeletable;
+
+        impl #impl_generics SoftDeletable for #struct_name #ty_generics
+        #where_clause
+        {
+            type DeletedAt = #table_name::deleted_at;
+        }
+    }))
+}
@@ -0,0 +1,58 @@
+use proc_macro2::TokenStream;
+use syn::spanned::Spanned;
+
+use crate::diagnostic_shim::{Diagnostic, DiagnosticShim};
+
+pub fn expand(item: syn::ItemFn) -> Result<TokenStream, Diagnostic> {
+    let conn_type = connection_type(&item)?;
+    let attrs = &item.attrs;
+    let name = &item.sig.ident;
+    let return_type = &item.sig.output;
+
+    let mut inner = item.clone();
+    inner.attrs = Vec::new();
+    inner.sig.ident = syn::Ident::new("__diesel_test_inner", item.sig.ident.span());
+    let inner_name = &inner.sig.ident;
+
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        fn #name() #return_type {
+            #inner
+
+            use diesel::Connection;
+
+            let database_url = ::std::env::var("DATABASE_URL")
+                .expect("`DATABASE_URL` must be set to run tests");
+            let mut conn = <#conn_type as diesel::Connection>::establish(&database_url)
+                .expect("Failed to establish a database connection");
+            conn.begin_test_transaction()
+                .expect("Failed to start a test transaction");
+            #inner_name(&mut conn)
+        }
+    })
+}
+
+/// Extracts the connection type from the test function's single
+/// `conn: &mut SomeConnection` argument.
+fn connection_type(item: &syn::ItemFn) -> Result<syn::Type, Diagnostic> {
+    let error = || {
+        item.sig.span().error(
+            "`#[diesel::test]` requires exactly one argument of the form \
+             `conn: &mut SomeConnection`",
+        )
+    };
+
+    if item.sig.inputs.len() != 1 {
+        return Err(error());
+    }
+    match item.sig.inputs.first() {
+        Some(syn::FnArg::Typed(pat)) => match &*pat.ty {
+            syn::Type::Reference(reference) if reference.mutability.is_some() => {
+                Ok((*reference.elem).clone())
+            }
+            _ => Err(error()),
+        },
+        _ => Err(error()),
+    }
+}
@@ -0,0 +1,26 @@
+use proc_macro2;
+use syn;
+
+use model::*;
+use util::*;
+
+pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let model = Model::from_item(&item)?;
+    let struct_name = &model.name;
+    let table_name = model.table_name();
+
+    let updated_at = syn::Ident::new("updated_at", proc_macro2::Span::call_site());
+    model.find_column(&updated_at)?;
+
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    Ok(wrap_in_dummy_mod(quote! {
+        use diesel::touchable::Touchable;
+
+        impl #impl_generics Touchable for #struct_name #ty_generics
+        #where_clause
+        {
+            type UpdatedAt = #table_name::updated_at;
+        }
+    }))
+}
@@ -0,0 +1,101 @@
+use proc_macro2::TokenStream;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::{Ident, LitStr, Token};
+
+use crate::diagnostic_shim::{Diagnostic, DiagnosticShim};
+
+pub struct VerifiedSqlInput {
+    conn_url: Option<LitStr>,
+    sql: LitStr,
+}
+
+impl Parse for VerifiedSqlInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let conn_url = if input.peek(Ident) {
+            let ident: Ident = input.parse()?;
+            if ident != "conn_url" {
+                return Err(syn::Error::new(ident.span(), "expected `conn_url`"));
+            }
+            input.parse::<Token![=]>()?;
+            let url = input.parse()?;
+            input.parse::<Token![,]>()?;
+            Some(url)
+        } else {
+            None
+        };
+        let sql = input.parse()?;
+        Ok(VerifiedSqlInput { conn_url, sql })
+    }
+}
+
+pub fn expand(input: VerifiedSqlInput) -> ::std::result::Result<TokenStream, Diagnostic> {
+    let url = input
+        .conn_url
+        .as_ref()
+        .map(|url| url.value())
+        .or_else(|| ::std::env::var("DATABASE_URL").ok());
+
+    match url {
+        None => {
+            input
+                .sql
+                .span()
+                .warning(
+                    "`DATABASE_URL` is not set, the SQL given to `verified_sql!` \
+                     was not checked against a database",
+                )
+                .emit();
+        }
+        Some(url) => {
+            if let Err(msg) = prepare_statement(&url, &input.sql.value()) {
+                return Err(input
+                    .sql
+                    .span()
+                    .error(format!("failed to verify SQL: {}", msg.trim_end())));
+            }
+        }
+    }
+
+    let sql = &input.sql;
+    Ok(quote!(#sql))
+}
+
+/// Sends `PREPARE` for the given statement so that the server checks the
+/// syntax and that all referenced tables and columns exist, without ever
+/// executing it.
+fn prepare_statement(url: &str, sql: &str) -> ::std::result::Result<(), String> {
+    use std::ffi::{CStr, CString};
+
+    let url = CString::new(url).map_err(|_| "database url contains a null byte".to_string())?;
+    let sql = CString::new(sql).map_err(|_| "sql contains a null byte".to_string())?;
+    let stmt_name = CString::new("").expect("no null byte in an empty string");
+
+    unsafe {
+        let conn = pq_sys::PQconnectdb(url.as_ptr());
+        if pq_sys::PQstatus(conn) != pq_sys::CONNECTION_OK {
+            let message = CStr::from_ptr(pq_sys::PQerrorMessage(conn))
+                .to_string_lossy()
+                .into_owned();
+            pq_sys::PQfinish(conn);
+            return Err(message);
+        }
+
+        let result = pq_sys::PQprepare(
+            conn,
+            stmt_name.as_ptr(),
+            sql.as_ptr(),
+            0,
+            ::std::ptr::null(),
+        );
+        let check = if pq_sys::PQresultStatus(result) == pq_sys::PGRES_COMMAND_OK {
+            Ok(())
+        } else {
+            Err(CStr::from_ptr(pq_sys::PQresultErrorMessage(result))
+                .to_string_lossy()
+                .into_owned())
+        };
+        pq_sys::PQclear(result);
+        pq_sys::PQfinish(conn);
+        check
+    }
+}
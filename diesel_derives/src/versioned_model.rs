@@ -0,0 +1,30 @@
+use proc_macro2;
+use syn;
+
+use model::*;
+use util::*;
+
+pub fn derive(item: syn::DeriveInput) -> Result<proc_macro2::TokenStream, Diagnostic> {
+    let model = Model::from_item(&item)?;
+    let struct_name = &model.name;
+    let table_name = model.table_name();
+
+    let version = syn::Ident::new("version", proc_macro2::Span::call_site());
+    let field_access = model.find_column(&version)?.name.access();
+
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    Ok(wrap_in_dummy_mod(quote! {
+        use diesel::versioning::VersionedModel;
+
+        impl #impl_generics VersionedModel for #struct_name #ty_generics
+        #where_clause
+        {
+            type Version = #table_name::version;
+
+            fn version(&self) -> i32 {
+                self#field_access
+            }
+        }
+    }))
+}
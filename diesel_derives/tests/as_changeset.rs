@@ -54,6 +54,35 @@ fn named_struct() {
     assert_eq!(Ok(expected), actual);
 }
 
+#[test]
+fn with_column_prefix() {
+    #[derive(AsChangeset)]
+    #[table_name = "users"]
+    #[diesel(column_prefix = "hair_")]
+    struct User {
+        #[column_name = "name"]
+        name: String,
+        color: String,
+    }
+
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+
+    update(users::table.find(1))
+        .set(User {
+            name: String::from("Jim"),
+            color: String::from("blue"),
+        })
+        .execute(connection)
+        .unwrap();
+
+    let expected = vec![
+        (1, String::from("Jim"), Some(String::from("blue"))),
+        (2, String::from("Tess"), Some(String::from("brown"))),
+    ];
+    let actual = users::table.order(users::id).load(connection);
+    assert_eq!(Ok(expected), actual);
+}
+
 #[test]
 fn with_explicit_table_name() {
     #[derive(AsChangeset)]
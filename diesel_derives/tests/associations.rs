@@ -358,3 +358,232 @@ fn tuple_struct() {
         debug_query::<Backend, _>(&expected).to_string()
     );
 }
+
+#[test]
+fn foreign_key_attribute_generates_join_support() {
+    table! {
+        users {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    table! {
+        posts {
+            id -> Integer,
+            user_id -> Integer,
+            title -> Text,
+        }
+    }
+
+    #[derive(Identifiable)]
+    pub struct User {
+        id: i32,
+    }
+
+    #[derive(Associations, Identifiable)]
+    #[belongs_to(User)]
+    pub struct Post {
+        id: i32,
+        #[diesel(foreign_key = "users::id")]
+        user_id: i32,
+    }
+
+    // No `joinable!` or `allow_tables_to_appear_in_same_query!` invocation,
+    // both are generated from the field attribute.
+    let _can_join_tables = posts::table
+        .inner_join(users::table)
+        .select((users::id, users::name, posts::id))
+        .filter(posts::user_id.eq(2));
+
+    let _can_reverse_join_tables = users::table
+        .inner_join(posts::table)
+        .select((posts::id, posts::user_id, posts::title))
+        .filter(users::name.eq("Sean"));
+
+    let t = User { id: 42 };
+
+    let belong_to = Post::belonging_to(&t);
+    let filter = posts::table.filter(posts::user_id.eq(42));
+
+    assert_eq!(
+        debug_query::<Backend, _>(&belong_to).to_string(),
+        debug_query::<Backend, _>(&filter).to_string()
+    );
+}
+
+#[test]
+fn belongs_to_with_qualified_foreign_key() {
+    table! {
+        users {
+            id -> Integer,
+        }
+    }
+
+    table! {
+        posts {
+            id -> Integer,
+            user_id -> Integer,
+        }
+    }
+
+    #[derive(Identifiable, Clone, Copy)]
+    pub struct User {
+        id: i32,
+    }
+
+    // A model storing columns of the users/posts join. Its own table is
+    // `users`, so the foreign key pointing to `users` lives on `posts`
+    // and has to be qualified.
+    #[derive(Associations, Clone, Copy, PartialEq, Debug)]
+    #[table_name = "users"]
+    #[belongs_to(User, foreign_key = "posts::user_id")]
+    pub struct UserPost {
+        post_id: i32,
+        post_user_id: i32,
+    }
+
+    fn assert_foreign_key_column<T>()
+    where
+        T: diesel::associations::BelongsTo<
+            User,
+            ForeignKey = i32,
+            ForeignKeyColumn = posts::user_id,
+        >,
+    {
+    }
+    assert_foreign_key_column::<UserPost>();
+
+    let user1 = User { id: 1 };
+    let user2 = User { id: 2 };
+    let user_post1 = UserPost {
+        post_id: 1,
+        post_user_id: 2,
+    };
+    let user_post2 = UserPost {
+        post_id: 2,
+        post_user_id: 1,
+    };
+
+    let grouped = vec![user_post1, user_post2].grouped_by(&[user1, user2]);
+    assert_eq!(vec![vec![user_post2], vec![user_post1]], grouped);
+}
+
+#[test]
+fn has_many_generates_query_method() {
+    table! {
+        users {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    table! {
+        posts {
+            id -> Integer,
+            user_id -> Integer,
+            title -> Text,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub struct Post {
+        id: i32,
+        user_id: i32,
+        title: String,
+    }
+
+    #[derive(Associations, Identifiable)]
+    #[diesel(has_many(Post, foreign_key = "user_id"))]
+    pub struct User {
+        id: i32,
+    }
+
+    let user = User { id: 42 };
+
+    let query = user.posts();
+    let expected = posts::table
+        .into_boxed::<Backend>()
+        .filter(posts::user_id.eq(42));
+
+    assert_eq!(
+        debug_query::<Backend, _>(&query).to_string(),
+        debug_query::<Backend, _>(&expected).to_string()
+    );
+
+    // The returned query is still composable
+    let _ = user.posts::<Backend>().filter(posts::title.eq("Bar"));
+}
+
+#[test]
+fn polymorphic_belongs_to() {
+    table! {
+        comments {
+            id -> Integer,
+            commentable_type -> Text,
+            commentable_id -> Integer,
+        }
+    }
+
+    table! {
+        posts {
+            id -> Integer,
+        }
+    }
+
+    table! {
+        videos {
+            id -> Integer,
+        }
+    }
+
+    #[derive(Identifiable)]
+    pub struct Post {
+        id: i32,
+    }
+
+    #[derive(Identifiable)]
+    pub struct Video {
+        id: i32,
+    }
+
+    #[derive(Associations, Identifiable)]
+    #[diesel(belongs_to_poly(
+        Post,
+        Video,
+        type_column = "commentable_type",
+        id_column = "commentable_id"
+    ))]
+    pub struct Comment {
+        id: i32,
+        commentable_type: String,
+        commentable_id: i32,
+    }
+
+    assert_eq!("Post", Commentable::Post.as_str());
+    assert_eq!(Some(Commentable::Video), Commentable::from_str("Video"));
+    assert_eq!(None, Commentable::from_str("Image"));
+
+    let comment = Comment {
+        id: 1,
+        commentable_type: "Post".into(),
+        commentable_id: 7,
+    };
+    assert_eq!(Some(Commentable::Post), comment.commentable());
+
+    let post = Post { id: 7 };
+    let query = Comment::belonging_to_post(&post);
+    let expected = comments::table
+        .into_boxed::<Backend>()
+        .filter(comments::commentable_type.eq("Post"))
+        .filter(comments::commentable_id.eq(7));
+
+    assert_eq!(
+        debug_query::<Backend, _>(&query).to_string(),
+        debug_query::<Backend, _>(&expected).to_string()
+    );
+
+    let video = Video { id: 3 };
+    let query = Comment::belonging_to_video(&video);
+    let _ = debug_query::<Backend, _>(&query).to_string();
+}
@@ -150,3 +150,29 @@ fn derive_identifiable_with_composite_pk() {
     assert_eq!((&2, &3), foo1.id());
     assert_eq!((&6, &7), foo2.id());
 }
+
+#[test]
+fn derive_identifiable_with_qualified_compound_pk() {
+    #[allow(dead_code)]
+    #[derive(Identifiable)]
+    #[table_name = "bars"]
+    #[diesel(primary_key(foos::id, bars::id))]
+    struct FooBar {
+        foo_id: i32,
+        bar_id: i32,
+        baz: i32,
+    }
+
+    let foo_bar1 = FooBar {
+        foo_id: 1,
+        bar_id: 2,
+        baz: 3,
+    };
+    let foo_bar2 = FooBar {
+        foo_id: 4,
+        bar_id: 5,
+        baz: 6,
+    };
+    assert_eq!((&1, &2), foo_bar1.id());
+    assert_eq!((&4, &5), foo_bar2.id());
+}
@@ -79,6 +79,77 @@ fn with_path_in_table_name() {
     assert_eq!(Ok(expected), saved);
 }
 
+#[test]
+fn struct_with_column_prefix() {
+    #[derive(Insertable)]
+    #[table_name = "users"]
+    #[diesel(column_prefix = "hair_")]
+    struct NewUser {
+        #[column_name = "name"]
+        name: String,
+        color: String,
+    }
+
+    let conn = &mut connection();
+    let new_user = NewUser {
+        name: "Sean".into(),
+        color: "Black".into(),
+    };
+    insert_into(users::table)
+        .values(new_user)
+        .execute(conn)
+        .unwrap();
+
+    let saved = users::table
+        .select((users::name, users::hair_color))
+        .load::<(String, Option<String>)>(conn);
+    let expected = vec![("Sean".to_string(), Some("Black".to_string()))];
+    assert_eq!(Ok(expected), saved);
+}
+
+#[test]
+fn struct_with_rename_all() {
+    table! {
+        users_camel (id) {
+            id -> Integer,
+            firstName -> Text,
+            hairColor -> Nullable<Text>,
+        }
+    }
+
+    #[derive(Insertable)]
+    #[table_name = "users_camel"]
+    #[diesel(rename_all = "camelCase")]
+    struct NewUser {
+        first_name: String,
+        hair_color: String,
+    }
+
+    let conn = &mut connection();
+    diesel::sql_query(
+        "CREATE TABLE users_camel (\
+         id INTEGER PRIMARY KEY, \
+         firstName VARCHAR NOT NULL, \
+         hairColor VARCHAR)",
+    )
+    .execute(conn)
+    .unwrap();
+    let new_user = NewUser {
+        first_name: "Sean".into(),
+        hair_color: "Black".into(),
+    };
+    insert_into(users_camel::table)
+        .values(new_user)
+        .execute(conn)
+        .unwrap();
+
+    let saved = users_camel::table
+        .select((users_camel::firstName, users_camel::hairColor))
+        .load::<(String, Option<String>)>(conn);
+    let expected = vec![("Sean".to_string(), Some("Black".to_string()))];
+    assert_eq!(Ok(expected), saved);
+}
+
 #[test]
 fn simple_reference_definition() {
     #[derive(Insertable)]
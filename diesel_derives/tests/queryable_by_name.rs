@@ -85,6 +85,21 @@ fn struct_with_non_ident_column_name() {
     );
 }
 
+#[test]
+fn struct_with_table_name_on_field() {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, QueryableByName)]
+    struct MyStruct {
+        #[diesel(table_name = "my_structs")]
+        foo: i32,
+        #[sql_type = "Integer"]
+        bar: i32,
+    }
+
+    let conn = &mut connection();
+    let data = sql_query("SELECT 1 AS \"my_structs.foo\", 2 AS bar").get_result(conn);
+    assert_eq!(Ok(MyStruct { foo: 1, bar: 2 }), data);
+}
+
 #[test]
 fn embedded_struct() {
     #[derive(Debug, Clone, Copy, PartialEq, Eq, QueryableByName)]
@@ -0,0 +1,65 @@
+use diesel::*;
+use helpers::*;
+
+type Backend = <TestConnection as Connection>::Backend;
+
+#[test]
+fn scopes_generate_filtered_queries() {
+    table! {
+        users {
+            id -> Integer,
+            name -> Text,
+            status -> Text,
+            age -> Integer,
+        }
+    }
+
+    #[derive(Scopes)]
+    #[diesel(scope(active = r#"status.eq("active")"#))]
+    #[diesel(scope(adults = "age.ge(18)"))]
+    pub struct User;
+
+    let query = User::active();
+    let expected = users::table
+        .into_boxed::<Backend>()
+        .filter(users::status.eq("active"));
+    assert_eq!(
+        debug_query::<Backend, _>(&query).to_string(),
+        debug_query::<Backend, _>(&expected).to_string()
+    );
+
+    let query = User::adults();
+    let expected = users::table
+        .into_boxed::<Backend>()
+        .filter(users::age.ge(18));
+    assert_eq!(
+        debug_query::<Backend, _>(&query).to_string(),
+        debug_query::<Backend, _>(&expected).to_string()
+    );
+}
+
+#[test]
+fn scopes_compose_with_further_conditions() {
+    table! {
+        users {
+            id -> Integer,
+            name -> Text,
+            status -> Text,
+            age -> Integer,
+        }
+    }
+
+    #[derive(Scopes)]
+    #[diesel(scope(active = r#"status.eq("active")"#, adults = "age.ge(18)"))]
+    pub struct User;
+
+    let query = User::active::<Backend>().filter(users::age.ge(18));
+    let expected = users::table
+        .into_boxed::<Backend>()
+        .filter(users::status.eq("active"))
+        .filter(users::age.ge(18));
+    assert_eq!(
+        debug_query::<Backend, _>(&query).to_string(),
+        debug_query::<Backend, _>(&expected).to_string()
+    );
+}
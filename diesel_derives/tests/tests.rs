@@ -15,4 +15,5 @@ mod identifiable;
 mod insertable;
 mod queryable;
 mod queryable_by_name;
+mod scopes;
 mod selectable;
@@ -24,6 +24,7 @@
 extern crate proc_macro;
 
 mod embed_migrations;
+mod migration_test;
 mod migrations;
 
 use proc_macro::TokenStream;
@@ -102,3 +103,45 @@ pub fn embed_migrations(input: TokenStream) -> TokenStream {
         .parse()
         .expect("Failed create embedded migrations instance")
 }
+
+/// Generates a test which checks that a migration reverts itself cleanly
+///
+/// The attribute takes the name of a migration, either the full directory
+/// name (e.g. `"20240101_add_users"`) or just its version, and must be
+/// applied to a function taking exactly one argument of the form
+/// `conn: &mut SomeConnection`. The generated `#[test]` function
+/// establishes a connection to the database given by the `DATABASE_URL`
+/// environment variable, starts a test transaction which is never
+/// committed, and then:
+///
+/// 1. runs all migrations preceding the named one, so its preconditions
+///    are in place,
+/// 2. runs the named migration up,
+/// 3. passes the connection to the annotated function for assertions
+///    against the migrated schema,
+/// 4. runs the named migration down, and
+/// 5. runs it up once more, which fails if `down.sql` did not restore
+///    the previous schema (e.g. because a table was left behind).
+///
+/// Migrations are searched for in the `migrations` directory of the
+/// current crate, like with
+/// [`FileBasedMigrations::find_migrations_directory`][find].
+///
+/// [find]: https://docs.rs/diesel_migrations/2/diesel_migrations/struct.FileBasedMigrations.html#method.find_migrations_directory
+///
+/// Note that on backends without transactional DDL, such as MySQL, the
+/// schema changes made by this test are not rolled back.
+///
+/// ```ignore
+/// #[diesel_migrations::migration_test("20240101_add_users")]
+/// fn adds_users_table(conn: &mut PgConnection) {
+///     users::table.count().get_result::<i64>(conn).unwrap();
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn migration_test(attr: TokenStream, input: TokenStream) -> TokenStream {
+    match migration_test::expand(attr.into(), input.into()) {
+        Ok(expanded) => expanded.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
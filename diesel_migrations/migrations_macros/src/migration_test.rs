@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+
+pub fn expand(attr: TokenStream, input: TokenStream) -> Result<TokenStream, syn::Error> {
+    let migration_name: syn::LitStr = syn::parse2(attr)?;
+    let item: syn::ItemFn = syn::parse2(input)?;
+
+    let conn_type = connection_type(&item)?;
+    let attrs = &item.attrs;
+    let name = &item.sig.ident;
+
+    let mut inner = item.clone();
+    inner.attrs = Vec::new();
+    inner.sig.ident = syn::Ident::new("__diesel_migration_test_inner", item.sig.ident.span());
+    let inner_name = &inner.sig.ident;
+
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        fn #name() {
+            #inner
+
+            use ::diesel::migration::{Migration, MigrationSource};
+            use ::diesel::Connection;
+
+            let database_url = ::std::env::var("DATABASE_URL")
+                .expect("`DATABASE_URL` must be set to run tests");
+            let mut conn = <#conn_type as ::diesel::Connection>::establish(&database_url)
+                .expect("Failed to establish a database connection");
+            conn.begin_test_transaction()
+                .expect("Failed to start a test transaction");
+
+            let source = ::diesel_migrations::FileBasedMigrations::find_migrations_directory()
+                .expect("Failed to find the migration directory");
+            let mut migrations = MigrationSource::migrations(&source)
+                .expect("Failed to read the migrations");
+            migrations.sort_unstable_by(|a, b| a.name().version().cmp(&b.name().version()));
+
+            let position = migrations
+                .iter()
+                .position(|m| {
+                    m.name().to_string() == #migration_name
+                        || m.name().version() == #migration_name.into()
+                })
+                .unwrap_or_else(|| panic!("No migration named `{}`", #migration_name));
+            let (earlier, rest) = migrations.split_at(position);
+            let migration = &rest[0];
+
+            for earlier_migration in earlier {
+                earlier_migration.run(&mut conn).unwrap_or_else(|e| {
+                    panic!(
+                        "Failed to run preceding migration `{}`: {}",
+                        earlier_migration.name(),
+                        e,
+                    )
+                });
+            }
+
+            migration
+                .run(&mut conn)
+                .unwrap_or_else(|e| panic!("Failed to run `{}` up: {}", #migration_name, e));
+
+            #inner_name(&mut conn);
+
+            migration
+                .revert(&mut conn)
+                .unwrap_or_else(|e| panic!("Failed to run `{}` down: {}", #migration_name, e));
+            migration.run(&mut conn).unwrap_or_else(|e| {
+                panic!(
+                    "Running `{0}` up after `{0}` down failed, \
+                     the migration does not revert itself cleanly: {1}",
+                    #migration_name, e,
+                )
+            });
+        }
+    })
+}
+
+/// Extracts the connection type from the test function's single
+/// `conn: &mut SomeConnection` argument.
+fn connection_type(item: &syn::ItemFn) -> Result<syn::Type, syn::Error> {
+    let error = || {
+        syn::Error::new(
+            item.sig.span(),
+            "`#[migration_test]` requires exactly one argument of the form \
+             `conn: &mut SomeConnection`",
+        )
+    };
+
+    if item.sig.inputs.len() != 1 {
+        return Err(error());
+    }
+    match item.sig.inputs.first() {
+        Some(syn::FnArg::Typed(pat)) => match &*pat.ty {
+            syn::Type::Reference(reference) if reference.mutability.is_some() => {
+                Ok((*reference.elem).clone())
+            }
+            _ => Err(error()),
+        },
+        _ => Err(error()),
+    }
+}
@@ -39,11 +39,16 @@ mod embedded_migrations;
 mod errors;
 mod file_based_migrations;
 mod migration_harness;
+#[cfg(feature = "postgres")]
+mod test_database;
 
 pub use crate::embedded_migrations::EmbeddedMigrations;
 pub use crate::file_based_migrations::FileBasedMigrations;
 pub use crate::migration_harness::{HarnessWithOutput, MigrationHarness};
+#[cfg(feature = "postgres")]
+pub use crate::test_database::TestDatabase;
 pub use migrations_macros::embed_migrations;
+pub use migrations_macros::migration_test;
 
 #[doc(hidden)]
 pub use crate::embedded_migrations::{EmbeddedMigration, EmbeddedName};
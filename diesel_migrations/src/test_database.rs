@@ -0,0 +1,121 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use diesel::connection::SimpleConnection;
+use diesel::{Connection, PgConnection};
+
+use crate::{FileBasedMigrations, MigrationHarness};
+
+static TEST_DATABASE_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+/// A fully isolated database for a single test
+///
+/// Unlike wrapping a test in a transaction which is rolled back, this
+/// creates a uniquely named database on the server given by the base url,
+/// runs all migrations from the migration directory of the current crate
+/// against it, and drops the database again when the value goes out of
+/// scope. This is useful for tests which cannot run inside a transaction,
+/// for example because they test DDL or triggers.
+///
+/// `TestDatabase` dereferences to [`PgConnection`], so it can be passed
+/// directly to any function expecting a connection.
+///
+/// ```no_run
+/// # use diesel_migrations::TestDatabase;
+/// # use diesel::prelude::*;
+/// # fn main() -> diesel::migration::Result<()> {
+/// let mut database = TestDatabase::new("postgres://localhost/postgres")?;
+/// diesel::sql_query("CREATE TRIGGER ...").execute(&mut *database)?;
+/// # Ok(())
+/// # }
+/// ```
+#[allow(missing_copy_implementations)]
+pub struct TestDatabase {
+    conn: Option<PgConnection>,
+    base_url: String,
+    name: String,
+}
+
+impl TestDatabase {
+    /// Creates a new uniquely named database on the server behind
+    /// `base_url` and runs all migrations against it
+    ///
+    /// The base url must point to an existing database (e.g. `postgres`),
+    /// which is used to issue the `CREATE DATABASE` statement. Migrations
+    /// are searched for in the `migrations` directory of the current
+    /// crate, like with [`FileBasedMigrations::find_migrations_directory`].
+    pub fn new(base_url: &str) -> diesel::migration::Result<Self> {
+        let name = format!(
+            "diesel_test_{}_{}",
+            std::process::id(),
+            TEST_DATABASE_COUNTER.fetch_add(1, Ordering::SeqCst),
+        );
+
+        let mut conn = PgConnection::establish(base_url)?;
+        conn.batch_execute(&format!(r#"CREATE DATABASE "{}""#, name))?;
+
+        let mut conn = PgConnection::establish(&url_for_database(base_url, &name))?;
+        let migrations = FileBasedMigrations::find_migrations_directory()?;
+        conn.run_pending_migrations(migrations)?;
+
+        Ok(TestDatabase {
+            conn: Some(conn),
+            base_url: base_url.to_owned(),
+            name,
+        })
+    }
+
+    /// The name of the created database
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Deref for TestDatabase {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn
+            .as_ref()
+            .expect("The connection is only dropped in `Drop`")
+    }
+}
+
+impl DerefMut for TestDatabase {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.conn
+            .as_mut()
+            .expect("The connection is only dropped in `Drop`")
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        // The connection to the test database needs to be closed before
+        // the database can be dropped.
+        self.conn.take();
+        let result = PgConnection::establish(&self.base_url)
+            .map_err(|e| e.to_string())
+            .and_then(|mut conn| {
+                conn.batch_execute(&format!(r#"DROP DATABASE IF EXISTS "{}""#, self.name))
+                    .map_err(|e| e.to_string())
+            });
+        if let Err(e) = result {
+            eprintln!("Failed to drop test database {}: {}", self.name, e);
+        }
+    }
+}
+
+/// Replaces the database of the given url with `database`, keeping any
+/// query parameters intact.
+fn url_for_database(base_url: &str, database: &str) -> String {
+    let (url, params) = match base_url.find('?') {
+        Some(idx) => (&base_url[..idx], &base_url[idx..]),
+        None => (base_url, ""),
+    };
+    let without_database = match url.rfind('/') {
+        Some(idx) if idx > url.find("://").map_or(0, |i| i + 2) => &url[..idx],
+        _ => url,
+    };
+    format!("{}/{}{}", without_database, database, params)
+}